@@ -0,0 +1,145 @@
+#[macro_use]
+extern crate criterion;
+extern crate drawille;
+
+use criterion::{black_box, Criterion};
+
+use drawille::{Canvas, Density, ImportMode, Plasma};
+
+/// The size of a full-screen canvas on a 200×50 terminal, in pixels.
+const WIDTH: u32 = 400;
+const HEIGHT: u32 = 200;
+
+fn filled() -> Canvas {
+    let mut canvas = Canvas::new(WIDTH, HEIGHT);
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            if (x * 7 + y * 3) % 5 != 0 {
+                canvas.set(x, y);
+            }
+        }
+    }
+    canvas
+}
+
+fn set(c: &mut Criterion) {
+    c.bench_function("set full screen", |b| b.iter(filled));
+    c.bench_function("get full screen", |b| {
+        let canvas = filled();
+        b.iter(|| {
+            let mut n = 0;
+            for y in 0..HEIGHT {
+                for x in 0..WIDTH {
+                    n += canvas.get(x, y) as u32;
+                }
+            }
+            black_box(n)
+        })
+    });
+}
+
+fn line(c: &mut Criterion) {
+    c.bench_function("line 100 diagonals", |b| {
+        let mut canvas = Canvas::new(WIDTH, HEIGHT);
+        b.iter(|| {
+            for i in 0..100 {
+                canvas.line(i, 0, WIDTH - 1 - i, HEIGHT - 1);
+            }
+        })
+    });
+    c.bench_function("vline full screen", |b| {
+        b.iter(|| {
+            let mut canvas = Canvas::new(WIDTH, HEIGHT);
+            for x in 0..WIDTH {
+                canvas.vline(x, 0, HEIGHT - 1);
+            }
+            canvas
+        })
+    });
+}
+
+fn frame(c: &mut Criterion) {
+    let canvas = filled();
+    c.bench_function("frame full screen", |b| b.iter(|| black_box(canvas.frame())));
+    c.bench_function("frame_into full screen", |b| {
+        let mut buf = String::new();
+        b.iter(|| {
+            canvas.frame_into(&mut buf);
+            black_box(buf.len())
+        })
+    });
+    let mut sparse = Canvas::new(WIDTH, HEIGHT);
+    sparse.line(0, 0, WIDTH - 1, HEIGHT - 1);
+    c.bench_function("frame single line", |b| b.iter(|| black_box(sparse.frame())));
+}
+
+fn import(c: &mut Criterion) {
+    let luma: Vec<u8> = (0..WIDTH * HEIGHT).map(|i| (i * 31 % 256) as u8).collect();
+    c.bench_function("from_luma threshold", |b| {
+        b.iter(|| Canvas::from_luma(WIDTH, HEIGHT, &luma, ImportMode::Threshold(128)))
+    });
+    let bitmap: Vec<u8> = (0..WIDTH / 8 * HEIGHT).map(|i| (i * 37 % 256) as u8).collect();
+    c.bench_function("from_bitmap full screen", |b| {
+        b.iter(|| Canvas::from_bitmap(WIDTH, HEIGHT, &bitmap))
+    });
+    c.bench_function("from_bitmap per-pixel reference", |b| {
+        b.iter(|| {
+            let mut canvas = Canvas::new(WIDTH, HEIGHT);
+            for y in 0..HEIGHT {
+                for x in 0..WIDTH {
+                    if bitmap[(y * WIDTH / 8 + x / 8) as usize] & (0x80 >> (x % 8)) != 0 {
+                        canvas.set(x, y);
+                    }
+                }
+            }
+            canvas
+        })
+    });
+    c.bench_function("from_luma edges", |b| {
+        b.iter(|| Canvas::from_luma(WIDTH, HEIGHT, &luma, ImportMode::Edges(128)))
+    });
+}
+
+fn plasma(c: &mut Criterion) {
+    let plasma = Plasma::new();
+    let mut canvas = Canvas::new(WIDTH, HEIGHT);
+    c.bench_function("plasma full screen", |b| {
+        b.iter(|| plasma.draw(&mut canvas, 0, 0, WIDTH, HEIGHT, 1.0))
+    });
+    let density = Density::default();
+    c.bench_function("plasma per-pixel reference", |b| {
+        b.iter(|| {
+            for y in 0..HEIGHT {
+                for x in 0..WIDTH {
+                    if density.is_set(x, y, plasma.level(x as f64, y as f64, 1.0)) {
+                        canvas.set(x, y);
+                    } else {
+                        canvas.unset(x, y);
+                    }
+                }
+            }
+        })
+    });
+}
+
+/// A dense grid of cell bits, as a reference point for what the sparse `Canvas` storage costs.
+fn dense(c: &mut Criterion) {
+    static PIXEL_MAP: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+    let (cols, rows) = ((WIDTH / 2) as usize, (HEIGHT / 4) as usize);
+    c.bench_function("dense reference set full screen", |b| {
+        b.iter(|| {
+            let mut cells = vec![0u8; cols * rows];
+            for y in 0..HEIGHT as usize {
+                for x in 0..WIDTH as usize {
+                    if (x * 7 + y * 3) % 5 != 0 {
+                        cells[y / 4 * cols + x / 2] |= PIXEL_MAP[y % 4][x % 2];
+                    }
+                }
+            }
+            black_box(cells)
+        })
+    });
+}
+
+criterion_group!(benches, set, line, frame, import, plasma, dense);
+criterion_main!(benches);
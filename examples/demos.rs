@@ -0,0 +1,18 @@
+//! Cycles through the built-in demos until interrupted.
+//!
+//! Pass a number of seconds to change how long each demo plays for.
+
+extern crate drawille;
+
+use std::env;
+use std::io;
+use std::time::Duration;
+
+use drawille::demo::Runner;
+
+fn main() -> io::Result<()> {
+    let seconds = env::args().nth(1).and_then(|s| s.parse().ok()).unwrap_or(10);
+    drawille::enable_ansi()?;
+    let mut runner = Runner::all(160, 96).length(Duration::from_secs(seconds));
+    runner.run(&mut io::stdout().lock(), None)
+}
@@ -0,0 +1,120 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use canvas::Canvas;
+
+/// A recorded sequence of frames, each shown for its own duration.
+///
+/// ```
+/// use std::time::Duration;
+/// use drawille::{Animation, Canvas};
+///
+/// let mut animation = Animation::new();
+/// let mut canvas = Canvas::new(20, 8);
+/// for x in 0..20 {
+///     canvas.set(x, 4);
+///     animation.push(&canvas, Duration::from_millis(50));
+/// }
+/// assert_eq!(animation.len(), 20);
+/// assert_eq!(animation.duration(), Duration::from_secs(1));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Animation {
+    pub(crate) frames: Vec<(String, Duration)>,
+    size: (u32, u32),
+}
+
+impl Animation {
+    /// Creates a new, empty `Animation`.
+    pub fn new() -> Animation {
+        Animation::default()
+    }
+
+    /// Records the current state of the `Canvas` as the next frame, shown for `duration`.
+    pub fn push(&mut self, canvas: &Canvas, duration: Duration) {
+        let (columns, rows) = canvas.extents();
+        self.size = (self.size.0.max(columns), self.size.1.max(rows));
+        self.frames.push((canvas.frame(), duration));
+    }
+
+    /// Returns the recorded frames and how long each is shown for, in order.
+    pub fn frames(&self) -> &[(String, Duration)] {
+        &self.frames
+    }
+
+    /// Returns the number of recorded frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Detects whether no frames have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the total time that the frames are shown for.
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|f| f.1).sum()
+    }
+
+    /// Returns the number of columns and rows of cells needed to show every frame.
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    /// Exports the `Animation` as an asciicast v2 recording, as played by asciinema.
+    ///
+    /// Each frame clears the screen and is drawn from the top-left corner. A final event after
+    /// the last frame’s duration keeps it on screen for as long as the others.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use drawille::{Animation, Canvas};
+    ///
+    /// let mut canvas = Canvas::new(2, 4);
+    /// canvas.set(0, 0);
+    /// let mut animation = Animation::new();
+    /// animation.push(&canvas, Duration::from_millis(500));
+    /// assert_eq!(animation.to_asciicast(), "\
+    /// {\"version\": 2, \"width\": 2, \"height\": 2}
+    /// [0.000000, \"o\", \"\\u001b[H\\u001b[2J⠁ \\r\\n  \"]
+    /// [0.500000, \"o\", \"\"]
+    /// ");
+    /// ```
+    pub fn to_asciicast(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "{{\"version\": 2, \"width\": {}, \"height\": {}}}",
+                         self.size.0, self.size.1);
+        let mut time = Duration::from_secs(0);
+        for &(ref frame, duration) in &self.frames {
+            let data = format!("\x1b[H\x1b[2J{}", frame.replace('\n', "\r\n"));
+            let _ = writeln!(out, "[{:.6}, \"o\", {}]", time.as_secs_f64(), json_string(&data));
+            time += duration;
+        }
+        if !self.frames.is_empty() {
+            let _ = writeln!(out, "[{:.6}, \"o\", \"\"]", time.as_secs_f64());
+        }
+        out
+    }
+}
+
+/// Quotes and escapes a string for JSON.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 || c == '\u{7f}' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
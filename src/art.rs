@@ -0,0 +1,309 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use canvas::Canvas;
+use style::{Color, Style};
+
+/// An error produced while loading Braille art.
+#[derive(Debug)]
+pub enum ArtError {
+    /// The art or its colours could not be read.
+    Io(io::Error),
+    /// A character that can’t be drawn in a single cell, such as a tab, was found at the given
+    /// row and column of the art.
+    InvalidChar(usize, usize, char),
+    /// An unknown colour code was found at the given row and column of the colours.
+    InvalidColor(usize, usize, char),
+    /// The colours have more rows, or a row has more columns, than the art, at the given row.
+    ColorMismatch(usize),
+}
+
+impl fmt::Display for ArtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ArtError::Io(ref e) => write!(f, "could not read Braille art: {}", e),
+            ArtError::InvalidChar(row, col, c) => {
+                write!(f, "invalid character {:?} in Braille art at {}:{}", c, row + 1, col + 1)
+            }
+            ArtError::InvalidColor(row, col, c) => {
+                write!(f, "invalid colour code {:?} at {}:{}", c, row + 1, col + 1)
+            }
+            ArtError::ColorMismatch(row) => {
+                write!(f, "colours don’t match the shape of the Braille art at row {}", row + 1)
+            }
+        }
+    }
+}
+
+impl Error for ArtError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ArtError::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ArtError {
+    fn from(e: io::Error) -> ArtError {
+        ArtError::Io(e)
+    }
+}
+
+/// Braille art, such as a sprite written in a text editor, ready to be drawn onto a `Canvas`.
+///
+/// Braille characters become pixels, spaces and blank Braille cells are empty, and any other
+/// character becomes a letter.
+///
+/// Colours can be given as a second grid of the same shape, with one code per cell: `k`, `r`,
+/// `g`, `y`, `b`, `m`, `c` and `w` for the eight basic colours, their capitals for the bright
+/// ones, and a space or `.` for none.
+///
+/// ```
+/// use drawille::{Art, Canvas};
+///
+/// let art = Art::parse_colored("⣿⣿\n⠉⠉", "rr\n..").unwrap();
+/// assert_eq!(art.size(), (2, 2));
+///
+/// let mut canvas = Canvas::new(8, 8);
+/// art.draw(&mut canvas, 2, 0);
+/// assert!(canvas.get(2, 0) && canvas.get(5, 4) && !canvas.get(5, 5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Art {
+    canvas: Canvas,
+    size: (u32, u32),
+}
+
+impl Art {
+    /// Parses uncoloured Braille art from text.
+    pub fn parse(art: &str) -> Result<Art, ArtError> {
+        Art::parse_with(art, None)
+    }
+
+    /// Parses Braille art from text, with its colours from a second grid of colour codes.
+    ///
+    /// The colours may have fewer rows and fewer columns than the art, leaving the rest
+    /// uncoloured, but not more.
+    pub fn parse_colored(art: &str, colors: &str) -> Result<Art, ArtError> {
+        Art::parse_with(art, Some(colors))
+    }
+
+    /// Loads Braille art from a text file, such as an `.brl` file.
+    ///
+    /// If a file with the same name but the extension `.colors` sits beside it, the colours are
+    /// loaded from that.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Art, ArtError> {
+        let path = path.as_ref();
+        let art = fs::read_to_string(path)?;
+        match fs::read_to_string(path.with_extension("colors")) {
+            Ok(colors) => Art::parse_colored(&art, &colors),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Art::parse(&art),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn parse_with(art: &str, colors: Option<&str>) -> Result<Art, ArtError> {
+        let art = trim_final_newline(art);
+        for (row, line) in art.split('\n').enumerate() {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if let Some((col, c)) = line.chars().enumerate().find(|&(_, c)| c.is_control()) {
+                return Err(ArtError::InvalidChar(row, col, c));
+            }
+        }
+        let mut canvas = Canvas::from_frame(&art.replace("\r\n", "\n"));
+        let (columns, rows) = canvas.extents();
+        let size = if art.is_empty() { (0, 0) } else { (columns, rows) };
+
+        if let Some(colors) = colors {
+            let colors = trim_final_newline(colors);
+            for (row, line) in colors.split('\n').enumerate() {
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                if row as u32 >= size.1 || line.chars().count() as u32 > size.0 {
+                    return Err(ArtError::ColorMismatch(row));
+                }
+                for (col, code) in line.chars().enumerate() {
+                    let color = match code {
+                        ' ' | '.' => continue,
+                        c => color(c).ok_or(ArtError::InvalidColor(row, col, c))?,
+                    };
+                    canvas.set_cell_style(col as u32 * 2, row as u32 * 4, Style::new().fg(color));
+                }
+            }
+        }
+        Ok(Art { canvas, size })
+    }
+
+    /// Returns the width and height of the art, in cells.
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    /// Returns the width and height of the art, in pixels.
+    pub fn pixel_size(&self) -> (u32, u32) {
+        (self.size.0 * 2, self.size.1 * 4)
+    }
+
+    /// Returns the art as a `Canvas`.
+    pub fn canvas(&self) -> &Canvas {
+        &self.canvas
+    }
+
+    /// Converts the art into a `Canvas`.
+    pub fn into_canvas(self) -> Canvas {
+        self.canvas
+    }
+
+    /// Draws the art onto the `Canvas`, with its top-left corner at the given pixel.
+    ///
+    /// Pixels are added to those already there, and letters land in the cell holding their
+    /// position. A cell has a single style, so each coloured pixel recolours its whole cell.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32) {
+        // Rows of art always run down the screen, whichever way the y axis points.
+        let y_up = canvas.is_y_up();
+        let below = |dy: u32| if y_up { y.checked_sub(dy) } else { Some(y + dy) };
+        for (px, py) in self.canvas.pixels() {
+            let (_, style) = self.canvas.cell((px / 2) as u16, (py / 4) as u16);
+            match below(py) {
+                Some(y) if style.is_plain() => canvas.set(x + px, y),
+                Some(y) => canvas.set_styled(x + px, y, style),
+                None => {}
+            }
+        }
+        for row in 0..self.size.1 {
+            for col in 0..self.size.0 {
+                let (c, style) = self.canvas.cell(col as u16, row as u16);
+                if let (false, Some(y)) = (is_braille(c) || c == ' ', below(row * 4)) {
+                    canvas.set_styled_char(x + col * 2, y, c, style);
+                }
+            }
+        }
+    }
+}
+
+/// Parses inline art into a `&'static [(u32, u32)]` of the pixels it sets, while compiling.
+///
+/// Art drawn with Braille characters sets their dots, one character per cell. Otherwise, each
+/// `#` sets a pixel and anything else leaves one empty. A newline straight after the opening
+/// quote is skipped, so the art can start on a line of its own.
+///
+/// ```
+/// #[macro_use]
+/// extern crate drawille;
+///
+/// use drawille::Canvas;
+///
+/// static PLUS: &[(u32, u32)] = braille_art!("
+/// ..#.
+/// .###
+/// ..#.");
+///
+/// fn main() {
+///     assert_eq!(PLUS.len(), 5);
+///     let canvas = Canvas::from_pixels(PLUS);
+///     assert_eq!(canvas.frame(), Canvas::from_pixels(braille_art!("⠐⠗")).frame());
+/// }
+/// ```
+#[macro_export]
+macro_rules! braille_art {
+    ($art:expr) => {{
+        static PIXELS: [(u32, u32); $crate::__art_pixels::<0>($art).1] =
+            $crate::__art_pixels($art).0;
+        &PIXELS
+    }};
+}
+
+/// Finds the pixels that inline art sets, for `braille_art!`, returning the first `N` of them
+/// and how many there are in all.
+#[doc(hidden)]
+pub const fn __art_pixels<const N: usize>(art: &str) -> ([(u32, u32); N], usize) {
+    const DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+    let bytes = art.as_bytes();
+    let mut pixels = [(0, 0); N];
+    let mut count = 0;
+    let mut braille = false;
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        // Braille patterns are encoded as 0xe2, then 0xa0 to 0xa3, then a continuation byte.
+        braille |= bytes[i] == 0xe2 && bytes[i + 1] & 0xfc == 0xa0;
+        i += 1;
+    }
+    i = if !bytes.is_empty() && bytes[0] == b'\n' { 1 } else { 0 };
+    let (mut x, mut y) = (0, 0);
+    while i < bytes.len() {
+        let byte = bytes[i];
+        let len = match byte {
+            0..=0x7f => 1,
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            _ => 4,
+        };
+        if byte == b'\n' {
+            x = 0;
+            y += 1;
+        } else if byte == b'\r' {
+            // Carriage returns count for nothing, so that Windows line endings work too.
+        } else if braille {
+            if len == 3 && bytes[i + 1] & 0xfc == 0xa0 {
+                let bits = (bytes[i + 1] & 0x03) << 6 | bytes[i + 2] & 0x3f;
+                let mut dot = 0;
+                while dot < 8 {
+                    let (dx, dy) = (dot % 2, dot / 2);
+                    if bits & DOTS[dy][dx] != 0 {
+                        if count < N {
+                            pixels[count] = (x * 2 + dx as u32, y * 4 + dy as u32);
+                        }
+                        count += 1;
+                    }
+                    dot += 1;
+                }
+            }
+            x += 1;
+        } else {
+            if byte == b'#' {
+                if count < N {
+                    pixels[count] = (x, y);
+                }
+                count += 1;
+            }
+            x += 1;
+        }
+        i += len;
+    }
+    (pixels, count)
+}
+
+/// Removes the newline, if any, that ends the last line of a text file.
+fn trim_final_newline(text: &str) -> &str {
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    text.strip_suffix('\r').unwrap_or(text)
+}
+
+/// Detects whether a character is one of the Braille patterns.
+fn is_braille(c: char) -> bool {
+    ('\u{2800}'..='\u{28ff}').contains(&c)
+}
+
+/// Returns the colour for a colour code.
+fn color(code: char) -> Option<Color> {
+    let base = match code.to_ascii_lowercase() {
+        'k' => 0,
+        'r' => 1,
+        'g' => 2,
+        'y' => 3,
+        'b' => 4,
+        'm' => 5,
+        'c' => 6,
+        'w' => 7,
+        _ => return None,
+    };
+    Some(if code.is_ascii_uppercase() {
+        Color::Ansi256(base + 8)
+    } else {
+        [Color::Black, Color::Red, Color::Green, Color::Yellow, Color::Blue, Color::Magenta,
+         Color::Cyan, Color::White][base as usize]
+    })
+}
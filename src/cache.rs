@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use canvas::Canvas;
+
+/// Remembers the last frame rendered, so that drawing an unchanged `Canvas` again costs a hash
+/// of its contents rather than a fresh render.
+///
+/// This suits dashboards, whose static panels are drawn again every tick. Each frame is shared
+/// as an `Arc<str>`, so handing out the cached one copies nothing.
+///
+/// ```
+/// use std::sync::Arc;
+/// use drawille::{Canvas, FrameCache};
+///
+/// let mut cache = FrameCache::new();
+/// let mut canvas = Canvas::new(10, 4);
+/// canvas.line(0, 0, 9, 3);
+/// let first = cache.frame(&canvas);
+/// assert!(Arc::ptr_eq(&first, &cache.frame(&canvas.clone())));
+///
+/// canvas.set(0, 3);
+/// assert_eq!(*cache.frame(&canvas), canvas.frame());
+/// assert_eq!((cache.hits(), cache.misses()), (1, 2));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FrameCache {
+    last: Option<(u64, Arc<str>)>,
+    hits: u64,
+    misses: u64,
+}
+
+impl FrameCache {
+    /// Creates a new, empty `FrameCache`.
+    pub fn new() -> FrameCache {
+        FrameCache::default()
+    }
+
+    /// Returns the frame of the `Canvas`, rendering it only if its contents have changed since
+    /// the last call.
+    pub fn frame(&mut self, canvas: &Canvas) -> Arc<str> {
+        let hash = canvas.content_hash();
+        if let Some((last, ref frame)) = self.last {
+            if last == hash {
+                self.hits += 1;
+                return frame.clone();
+            }
+        }
+        self.misses += 1;
+        let frame: Arc<str> = canvas.frame().into();
+        self.last = Some((hash, frame.clone()));
+        frame
+    }
+
+    /// Forgets the cached frame, so that the next call to `frame` renders afresh.
+    pub fn clear(&mut self) {
+        self.last = None;
+    }
+
+    /// Returns how many frames were served from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns how many frames had to be rendered.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
@@ -1,17 +1,84 @@
 use std::char;
 use std::cmp;
 
-use fnv::FnvHashMap;
+use coords::{self, CellRect, Size};
+use cow::{CowMap, DotMap, CHUNK_SIZE};
+use damage::Damage;
+use journal::{Edit, Journal, Recorder};
+use style::{Span, Style, StyledCell};
+use world::{Bits, World, DEFAULT_CELL_ASPECT};
 
-static PIXEL_MAP: [[u8; 2]; 4] = [[0x01, 0x08],
-                                   [0x02, 0x10],
-                                   [0x04, 0x20],
-                                   [0x40, 0x80]];
+/// The dot bit of each pixel in a cell, by row and column.
+pub(crate) static PIXEL_MAP: [[u8; 2]; 4] = [[0x01, 0x08],
+                                              [0x02, 0x10],
+                                              [0x04, 0x20],
+                                              [0x40, 0x80]];
+
+/// The direction in which `Canvas::text_directed` lays out text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextDirection {
+    /// Left to right, as with `Canvas::text`.
+    Horizontal,
+    /// Top to bottom (rotated by 90°).
+    Down,
+    /// Bottom to top (rotated by 270°).
+    Up,
+}
+
+/// What a `Canvas` does with points drawn outside its given dimensions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OutOfBounds {
+    /// Draw them anyway, growing the `Canvas` to fit.
+    #[default]
+    Expand,
+    /// Leave them out.
+    Clip,
+    /// Wrap them round to the opposite edge, as on a torus.
+    Wrap,
+    /// Panic, to catch drawing mistakes.
+    Error,
+}
+
+/// What a `Canvas` holds and roughly how much memory it uses, as returned by `Canvas::stats`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CanvasStats {
+    /// The number of cells holding any pixels or letters, including on the overlay.
+    pub cells: usize,
+    /// The number of pixels that are set.
+    pub dots: usize,
+    /// The number of cells holding a letter, not counting the overlay.
+    pub text_cells: usize,
+    /// The number of cells with a style.
+    pub styled_cells: usize,
+    /// The number of letters on the overlay.
+    pub overlay_cells: usize,
+    /// An estimate of the bytes allocated on the heap to hold all of the above.
+    pub heap_bytes: usize,
+}
 
 /// A canvas object that can be used to draw to the terminal using Braille characters.
+///
+/// Cloning a `Canvas` is cheap: the clone shares its cells with the original, and a region is
+/// only copied when one of them draws to it.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Canvas {
-    chars: FnvHashMap<(u16, u16), (u8, char)>,
+    /// The dot bits of every cell that has been drawn to. This is the only storage a cell that
+    /// only holds pixels needs.
+    dots: DotMap,
+    /// Letters, kept apart from `dots` since far fewer cells hold one.
+    text: CowMap<char>,
+    styles: CowMap<Style>,
+    overlay: CowMap<(char, Style)>,
+    /// The largest column and row of any cell in `dots`, `text` or `overlay`, kept up to date
+    /// as cells are added so that rendering doesn’t need to search for it.
+    max_cell: Option<(u16, u16)>,
+    /// Whether y coordinates count upwards from the bottom of the given height.
+    y_up: bool,
+    out_of_bounds: OutOfBounds,
+    journal: Recorder,
+    damage: Damage,
+    pub(crate) world: Option<World>,
+    pub(crate) cell_aspect: Bits,
     pub(crate) width: u16,
     pub(crate) height: u16,
 }
@@ -22,94 +89,832 @@ impl Canvas {
     /// Note that the `Canvas` can still draw outside the given dimensions (expanding the canvas)
     /// if a pixel is set outside the dimensions.
     pub fn new(width: u32, height: u32) -> Canvas {
+        let (columns, rows) = coords::pixels_to_cells(width, height);
         Canvas {
-            chars: FnvHashMap::default(),
-            width: (width / 2) as u16,
-            height: (height / 4) as u16,
+            dots: DotMap::default(),
+            text: CowMap::default(),
+            styles: CowMap::default(),
+            overlay: CowMap::default(),
+            max_cell: None,
+            y_up: false,
+            out_of_bounds: OutOfBounds::Expand,
+            journal: Recorder::default(),
+            damage: Damage::default(),
+            world: None,
+            cell_aspect: Bits(DEFAULT_CELL_ASPECT),
+            width: columns as u16,
+            height: rows as u16,
         }
     }
 
-    /// Clears the canvas.
+    /// Creates a new `Canvas` of the given `Size`, which makes clear whether it is in pixels or
+    /// cells.
+    pub fn with_size(size: Size) -> Canvas {
+        let (width, height) = size.in_pixels();
+        Canvas::new(width, height)
+    }
+
+    /// Returns the dimensions the `Canvas` was created with, rounded down to whole cells.
+    ///
+    /// ```
+    /// use drawille::{Canvas, Size};
+    ///
+    /// assert_eq!(Canvas::new(81, 40).size(), Size::cells(40, 10));
+    /// ```
+    pub fn size(&self) -> Size {
+        Size::cells(self.width as u32, self.height as u32)
+    }
+
+    /// Clears the canvas, including the text overlay.
     pub fn clear(&mut self) {
-        self.chars.clear();
+        let (columns, rows) = self.extents();
+        self.damage.mark_rect(CellRect::new(0, 0, columns, rows));
+        self.dots.clear();
+        self.text.clear();
+        self.styles.clear();
+        self.overlay.clear();
+        self.max_cell = None;
+        self.journal.record(Edit::Clear);
+    }
+
+    /// Clears only the text overlay, revealing whatever was drawn underneath it.
+    pub fn clear_overlay(&mut self) {
+        for &(col, row) in self.overlay.keys() {
+            self.damage.mark(row, col, col);
+        }
+        self.overlay.clear();
+        self.recompute_max_cell();
+        self.journal.record(Edit::ClearOverlay);
+    }
+
+    /// Starts recording every change made to the `Canvas` in a `Journal`, replacing any journal
+    /// already being recorded.
+    pub fn start_journal(&mut self) {
+        let (width, height) = (self.width as u32 * 2, self.height as u32 * 4);
+        self.journal = Recorder(Some(Box::new(Journal::start(width, height))));
+    }
+
+    /// Returns the journal being recorded, if any.
+    pub fn journal(&self) -> Option<&Journal> {
+        self.journal.0.as_deref()
+    }
+
+    /// Stops recording changes, and returns the journal recorded so far.
+    pub fn take_journal(&mut self) -> Option<Journal> {
+        self.journal.0.take().map(|journal| *journal)
+    }
+
+    /// Marks a rectangle of cells as changed, so that `is_dirty` reports it, such as when the
+    /// host drew over that part of the screen itself.
+    ///
+    /// Every method that draws marks the cells it changes already, and `clear_dirty` marks
+    /// everything as unchanged again, which lets a TUI framework redraw only what changed:
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    /// use drawille::coords::CellRect;
+    ///
+    /// let mut canvas = Canvas::new(40, 40);
+    /// canvas.clear_dirty();
+    /// canvas.line(0, 12, 39, 12);
+    /// assert!(canvas.is_dirty(CellRect::new(0, 3, 20, 1)));
+    /// assert!(!canvas.is_dirty(CellRect::new(0, 0, 20, 3)));
+    /// assert_eq!(canvas.dirty_rows().collect::<Vec<_>>(), [(3, 0, 19)]);
+    /// ```
+    pub fn mark_dirty(&mut self, rect: CellRect) {
+        self.damage.mark_rect(rect);
+    }
+
+    /// Detects whether any cell in the rectangle has changed since the last `clear_dirty`.
+    ///
+    /// The check is per row, so a rectangle between two changed cells of the same row counts
+    /// as changed too.
+    pub fn is_dirty(&self, rect: CellRect) -> bool {
+        self.damage.is_dirty(rect)
+    }
+
+    /// Returns each row of cells that has changed since the last `clear_dirty`, from the top,
+    /// as the row with its first and last changed column.
+    pub fn dirty_rows(&self) -> impl Iterator<Item = (u32, u32, u32)> + '_ {
+        self.damage.spans()
+    }
+
+    /// Marks every cell as unchanged, once the host has redrawn the changed ones.
+    pub fn clear_dirty(&mut self) {
+        self.damage.clear();
+    }
+
+    /// Puts the origin at the bottom-left corner of the `Canvas`’s given dimensions, with y
+    /// increasing upwards as is usual in mathematics, or back at the top-left corner with y
+    /// increasing downwards.
+    ///
+    /// Every method taking coordinates follows the setting, as does `pixels`. Since the origin
+    /// is fixed to the given height, anything above it is not drawn.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(2, 8);
+    /// canvas.set_y_up(true);
+    /// canvas.line(0, 0, 0, 3);
+    /// assert!(canvas.get(0, 0));
+    /// assert_eq!(canvas.frame(), ["  ", "⡇ ", "  "].join("\n"));
+    /// ```
+    pub fn set_y_up(&mut self, y_up: bool) {
+        self.y_up = y_up;
+    }
+
+    /// Detects whether y coordinates increase upwards from the bottom of the `Canvas`.
+    pub fn is_y_up(&self) -> bool {
+        self.y_up
+    }
+
+    /// Sets what happens to points drawn outside the `Canvas`’s given dimensions, rounded down
+    /// to whole cells.
+    ///
+    /// ```
+    /// use drawille::{Canvas, OutOfBounds};
+    ///
+    /// let mut canvas = Canvas::new(8, 8);
+    /// canvas.set_out_of_bounds(OutOfBounds::Wrap);
+    /// canvas.set(9, 10);
+    /// assert!(canvas.get(1, 2));
+    /// ```
+    pub fn set_out_of_bounds(&mut self, out_of_bounds: OutOfBounds) {
+        self.out_of_bounds = out_of_bounds;
+    }
+
+    /// Returns what happens to points drawn outside the `Canvas`’s given dimensions.
+    pub fn out_of_bounds(&self) -> OutOfBounds {
+        self.out_of_bounds
+    }
+
+    /// Detects whether a point lies within the `Canvas`’s given dimensions.
+    pub(crate) fn in_bounds(&self, x: u32, y: u32) -> bool {
+        x < self.width as u32 * 2 && y < self.height as u32 * 4
+    }
+
+    /// Detects whether a point lies in a cell whose column and row can be stored.
+    pub(crate) fn in_range(x: u32, y: u32) -> bool {
+        x / 2 <= u16::MAX as u32 && y / 4 <= u16::MAX as u32
+    }
+
+    /// Applies the out-of-bounds policy to a point, returning `None` if it isn’t drawn.
+    ///
+    /// Points too far out to be stored are never drawn, rather than landing in the wrong cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the point is out of bounds and the policy is `OutOfBounds::Error`.
+    fn bound(&self, x: u32, y: u32) -> Option<(u32, u32)> {
+        if self.in_bounds(x, y) {
+            return Some((x, y));
+        }
+        if self.out_of_bounds == OutOfBounds::Expand {
+            return if Canvas::in_range(x, y) { Some((x, y)) } else { None };
+        }
+        let (width, height) = (self.width as u32 * 2, self.height as u32 * 4);
+        match self.out_of_bounds {
+            OutOfBounds::Wrap if width > 0 && height > 0 => Some((x % width, y % height)),
+            OutOfBounds::Error => {
+                panic!("point ({}, {}) is outside the canvas of {}×{} pixels", x, y, width, height)
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts a point to where it is stored, applying the out-of-bounds policy and then
+    /// counting y downwards from the top.
+    fn map_point(&self, x: u32, y: u32) -> Option<(u32, u32)> {
+        let (x, y) = self.bound(x, y)?;
+        Some((x, self.screen_y(y)?))
+    }
+
+    /// Converts a y coordinate into one counting downwards from the top, as cells are stored,
+    /// returning `None` if it lies above a `Canvas` whose y axis points up.
+    fn screen_y(&self, y: u32) -> Option<u32> {
+        let height = self.height as u32 * 4;
+        match self.y_up {
+            false => Some(y),
+            true if y < height => Some(height - 1 - y),
+            true => None,
+        }
     }
 
     /// Sets a pixel at the specified coordinates.
     pub fn set(&mut self, x: u32, y: u32) {
-        let (row, col) = ((x / 2) as u16, (y / 4) as u16);
-        let a = self.chars.entry((row, col)).or_insert((0,' '));
-        a.0 |= PIXEL_MAP[y as usize % 4][x as usize % 2];
-        a.1 = ' ';
+        let (x, y) = match self.map_point(x, y) {
+            Some(p) => p,
+            None => return,
+        };
+        self.journal.record(Edit::Set(x, y));
+        let (col, row) = ((x / 2) as u16, (y / 4) as u16);
+        *self.dots.entry_or((col, row), 0) |= PIXEL_MAP[y as usize % 4][x as usize % 2];
+        self.touch(col, row);
+        if !self.text.is_empty() {
+            self.text.remove(&(col, row));
+        }
+    }
+
+    /// Sets every pixel in the column `x` from `y1` to `y2` inclusive.
+    ///
+    /// This updates each cell once rather than once per pixel, so it is much faster than
+    /// calling `set` repeatedly for long runs.
+    pub fn vline(&mut self, x: u32, y1: u32, y2: u32) {
+        let (top, bottom) = (cmp::min(y1, y2), cmp::max(y1, y2));
+        if self.out_of_bounds != OutOfBounds::Expand {
+            // Any run of the given height covers every row it can land on.
+            let bottom = cmp::min(bottom, top.saturating_add(self.height as u32 * 4));
+            for y in top..=bottom {
+                self.set(x, y);
+            }
+            return;
+        }
+        // Leave out what is too far down to be stored.
+        let last = u16::MAX as u32 * 4 + 3;
+        if !Canvas::in_range(x, top) {
+            return;
+        }
+        // Only points above an upward y axis are missing, so clip them to its top.
+        let (y1, y2) = match (self.screen_y(top), self.screen_y(cmp::min(bottom, last))) {
+            (None, None) => return,
+            (y1, y2) => (y1.unwrap_or(0), y2.unwrap_or(0)),
+        };
+        let (top, bottom) = (cmp::min(y1, y2), cmp::max(y1, y2));
+        self.journal.record(Edit::Vline(x, top, bottom));
+        let col = (x / 2) as u16;
+        for row in top / 4..=bottom / 4 {
+            let first = if row == top / 4 { top % 4 } else { 0 };
+            let last = if row == bottom / 4 { bottom % 4 } else { 3 };
+            let bits = (first..=last)
+                .fold(0, |bits, r| bits | PIXEL_MAP[r as usize][x as usize % 2]);
+            let key = (col, row as u16);
+            *self.dots.entry_or(key, 0) |= bits;
+            self.damage.mark(key.1, col, col);
+            if !self.text.is_empty() {
+                self.text.remove(&key);
+            }
+        }
+        self.touch(col, (bottom / 4) as u16);
     }
 
     /// Sets a letter at the specified coordinates.
     pub fn set_char(&mut self, x: u32, y: u32, c: char) {
-        let (row, col) = ((x / 2) as u16, (y / 4) as u16);
-        let a = self.chars.entry((row, col)).or_insert((0,' '));
-        a.0 = 0;
-        a.1 = c;
+        self.set_styled_char(x, y, c, Style::default());
+    }
+
+    /// Sets a letter at the specified coordinates, rendered with the given style.
+    pub fn set_styled_char(&mut self, x: u32, y: u32, c: char, style: Style) {
+        if let Some((x, y)) = self.map_point(x, y) {
+            self.put_char(x, y, c, style, false);
+        }
+    }
+
+    /// Sets a letter at the specified coordinates from the top, onto either the cell itself or
+    /// the overlay.
+    fn put_char(&mut self, x: u32, y: u32, c: char, style: Style, overlay: bool) {
+        self.journal.record(match overlay {
+            true => Edit::OverlayChar(x, y, c, style),
+            false => Edit::Char(x, y, c, style),
+        });
+        let (col, row) = ((x / 2) as u16, (y / 4) as u16);
+        if overlay {
+            self.overlay.insert((col, row), (c, style));
+        } else {
+            self.dots.remove(&(col, row));
+            self.text.insert((col, row), c);
+            self.style_cell(col, row, style);
+        }
+        self.touch(col, row);
+    }
+
+    /// Sets the style of the cell containing the specified coordinates, without changing what
+    /// it holds.
+    pub fn set_cell_style(&mut self, x: u32, y: u32, style: Style) {
+        if let Some((x, y)) = self.map_point(x, y) {
+            self.journal.record(Edit::CellStyle(x, y, style));
+            self.style_cell((x / 2) as u16, (y / 4) as u16, style);
+        }
+    }
+
+    fn style_cell(&mut self, col: u16, row: u16, style: Style) {
+        self.damage.mark(row, col, col);
+        if style.is_plain() {
+            self.styles.remove(&(col, row));
+        } else {
+            self.styles.insert((col, row), style);
+        }
     }
 
     /// Draws text at the specified coordinates (top-left of the text) up to max_width length
     pub fn text(&mut self, x: u32, y: u32, max_width: u32, text: &str) {
-        for (i, c) in text.chars().enumerate() {
-            let w = i as u32 * 2;
-            if w > max_width {
+        self.text_directed(x, y, max_width, text, TextDirection::Horizontal);
+    }
+
+    /// Draws text starting at the specified coordinates in the given direction, up to
+    /// max_length pixels along that direction.
+    ///
+    /// Vertical text places one letter per cell, so every letter advances four pixels rather
+    /// than two, which makes it suitable for y-axis labels.
+    ///
+    /// ```
+    /// use drawille::{Canvas, TextDirection};
+    ///
+    /// let mut canvas = Canvas::new(2, 8);
+    /// canvas.text_directed(0, 8, 8, "ab", TextDirection::Up);
+    /// assert_eq!(canvas.frame(), ["  ", "b ", "a "].join("\n"));
+    /// ```
+    pub fn text_directed(&mut self, x: u32, y: u32, max_length: u32, text: &str,
+                         direction: TextDirection) {
+        let chars = text.chars().map(|c| (c, Style::default()));
+        self.put_text(x, y, max_length, direction, chars, false);
+    }
+
+    /// Draws styled text at the specified coordinates (top-left of the text) up to max_width
+    /// length.
+    ///
+    /// The spans are laid out one after another, and each is rendered using its own style.
+    ///
+    /// ```
+    /// use drawille::{Canvas, Color, Span, Style};
+    ///
+    /// let mut canvas = Canvas::new(8, 4);
+    /// canvas.text_styled(0, 0, 8, &[Span::new("ab", Style::new().bold()),
+    ///                             Span::new("c", Style::new().fg(Color::Red))]);
+    /// assert_eq!(canvas.rows()[0], "\x1b[1mab\x1b[0m\x1b[31mc\x1b[0m  ");
+    /// ```
+    pub fn text_styled(&mut self, x: u32, y: u32, max_width: u32, spans: &[Span]) {
+        let chars = spans.iter().flat_map(|s| s.text.chars().map(move |c| (c, s.style)));
+        self.put_text(x, y, max_width, TextDirection::Horizontal, chars, false);
+    }
+
+    /// Lays out letters one cell apart in the given direction, onto either the cells themselves
+    /// or the overlay.
+    fn put_text<I>(&mut self, x: u32, y: u32, max_length: u32, direction: TextDirection,
+                   chars: I, overlay: bool)
+        where I: Iterator<Item = (char, Style)>
+    {
+        // Lay the text out on screen, so that its directions don’t depend on the y axis.
+        let (x, y) = match self.map_point(x, y) {
+            Some(p) => p,
+            None => return,
+        };
+        for (i, (c, style)) in chars.enumerate() {
+            let step = match direction {
+                TextDirection::Horizontal => 2,
+                TextDirection::Down | TextDirection::Up => 4,
+            };
+            let d = i as u32 * step;
+            if d > max_length {
                 return;
             }
-            self.set_char(x + w, y, c);
+            // Letters that would run past the largest coordinate are left out.
+            let (x, y) = match direction {
+                TextDirection::Horizontal => match x.checked_add(d) {
+                    Some(x) => (x, y),
+                    None => return,
+                },
+                TextDirection::Down => match y.checked_add(d) {
+                    Some(y) => (x, y),
+                    None => return,
+                },
+                TextDirection::Up => match y.checked_sub(d) {
+                    Some(y) => (x, y),
+                    None => return,
+                },
+            };
+            if let Some((x, y)) = self.bound(x, y) {
+                self.put_char(x, y, c, style, overlay);
+            }
         }
     }
 
+    /// Sets a letter on the text overlay at the specified coordinates.
+    ///
+    /// Unlike `set_char`, this leaves the pixels in that cell untouched; the letter is simply
+    /// drawn on top of them until it is removed again.
+    pub fn set_overlay_char(&mut self, x: u32, y: u32, c: char) {
+        self.set_overlay_styled_char(x, y, c, Style::default());
+    }
+
+    /// Sets a letter on the text overlay at the specified coordinates, rendered with the given
+    /// style.
+    pub fn set_overlay_styled_char(&mut self, x: u32, y: u32, c: char, style: Style) {
+        if let Some((x, y)) = self.map_point(x, y) {
+            self.put_char(x, y, c, style, true);
+        }
+    }
+
+    /// Removes the overlay letter at the specified coordinates, if any.
+    pub fn unset_overlay_char(&mut self, x: u32, y: u32) {
+        let (x, y) = match self.map_point(x, y) {
+            Some(p) => p,
+            None => return,
+        };
+        self.journal.record(Edit::UnsetOverlayChar(x, y));
+        let (col, row) = ((x / 2) as u16, (y / 4) as u16);
+        if self.overlay.remove(&(col, row)).is_some() {
+            self.damage.mark(row, col, col);
+            self.recompute_max_cell();
+        }
+    }
+
+    /// Draws text on the overlay at the specified coordinates (top-left of the text) up to
+    /// max_width length.
+    pub fn overlay_text(&mut self, x: u32, y: u32, max_width: u32, text: &str) {
+        let chars = text.chars().map(|c| (c, Style::default()));
+        self.put_text(x, y, max_width, TextDirection::Horizontal, chars, true);
+    }
+
+    /// Draws styled text on the overlay at the specified coordinates (top-left of the text) up
+    /// to max_width length.
+    pub fn overlay_text_styled(&mut self, x: u32, y: u32, max_width: u32, spans: &[Span]) {
+        let chars = spans.iter().flat_map(|s| s.text.chars().map(move |c| (c, s.style)));
+        self.put_text(x, y, max_width, TextDirection::Horizontal, chars, true);
+    }
+
     /// Deletes a pixel at the specified coordinates.
     pub fn unset(&mut self, x: u32, y: u32) {
-        let (row, col) = ((x / 2) as u16, (y / 4) as u16);
-        let a = self.chars.entry((row, col)).or_insert((0,' '));
-        a.0 &= !PIXEL_MAP[y as usize % 4][x as usize % 2];
+        let (x, y) = match self.map_point(x, y) {
+            Some(p) => p,
+            None => return,
+        };
+        self.journal.record(Edit::Unset(x, y));
+        let (col, row) = ((x / 2) as u16, (y / 4) as u16);
+        *self.dots.entry_or((col, row), 0) &= !PIXEL_MAP[y as usize % 4][x as usize % 2];
+        self.touch(col, row);
     }
 
     /// Toggles a pixel at the specified coordinates.
     pub fn toggle(&mut self, x: u32, y: u32) {
-        let (row, col) = ((x / 2) as u16, (y / 4) as u16);
-        let a = self.chars.entry((row, col)).or_insert((0,' '));
-        a.0 ^= PIXEL_MAP[y as usize % 4][x as usize % 2];
+        let (x, y) = match self.map_point(x, y) {
+            Some(p) => p,
+            None => return,
+        };
+        self.journal.record(Edit::Toggle(x, y));
+        let (col, row) = ((x / 2) as u16, (y / 4) as u16);
+        *self.dots.entry_or((col, row), 0) ^= PIXEL_MAP[y as usize % 4][x as usize % 2];
+        self.touch(col, row);
     }
 
     /// Detects whether the pixel at the given coordinates is set.
     pub fn get(&self, x: u32, y: u32) -> bool {
-        let (row, col) = ((x / 2) as u16, (y / 4) as u16);
-        self.chars.get(&(row, col)).map_or(false, |a| {
+        if self.out_of_bounds == OutOfBounds::Error && !self.in_bounds(x, y) {
+            return false;
+        }
+        let (x, y) = match self.map_point(x, y) {
+            Some(p) => p,
+            None => return false,
+        };
+        let (col, row) = ((x / 2) as u16, (y / 4) as u16);
+        self.dots.get(&(col, row)).is_some_and(|&bits| {
             let dot_index = PIXEL_MAP[y as usize % 4][x as usize % 2];
-            a.0 & dot_index != 0
+            bits & dot_index != 0
+        })
+    }
+
+    /// Returns an iterator over the coordinates of every pixel that is set.
+    pub fn pixels<'a>(&'a self) -> impl Iterator<Item = (u32, u32)> + 'a {
+        let height = self.height as u32 * 4;
+        let y_up = self.y_up;
+        self.screen_pixels().filter_map(move |(x, y)| match y_up {
+            false => Some((x, y)),
+            true if y < height => Some((x, height - 1 - y)),
+            true => None,
+        })
+    }
+
+    /// Returns an iterator over every pixel that is set, with y counting down from the top
+    /// whichever way the `Canvas`’s y axis points.
+    pub(crate) fn screen_pixels<'a>(&'a self) -> impl Iterator<Item = (u32, u32)> + 'a {
+        self.dots.iter().flat_map(|((col, row), bits)| {
+            (0..8).filter_map(move |i| {
+                let (dx, dy) = (i % 2, i / 2);
+                if bits & PIXEL_MAP[dy][dx] != 0 {
+                    Some((col as u32 * 2 + dx as u32, row as u32 * 4 + dy as u32))
+                } else {
+                    None
+                }
+            })
         })
     }
 
     /// Returns a `Vec` of each row of the `Canvas`.
     ///
     /// Note that each row is actually four pixels high due to the fact that a single Braille
-    /// character spans two by four pixels.
+    /// character spans two by four pixels. Styled cells are wrapped in ANSI escape sequences.
     pub fn rows(&self) -> Vec<String> {
-        let mut maxrow = self.width;
-        let mut maxcol = self.height;
-        for &(x, y) in self.chars.keys() {
-            if x > maxrow {maxrow = x;}
-            if y > maxcol {maxcol = y;}
-        }
-
-        let mut result = Vec::with_capacity(maxcol as usize + 1);
-        for y in 0..=maxcol {
-            let mut row = String::with_capacity(maxrow as usize + 1);
-            for x in 0..=maxrow {
-                let cell = self.chars.get(&(x, y)).cloned().unwrap_or((0,' '));
-                row.push(if cell.0 == 0 {
-                    cell.1
-                } else {
-                    char::from_u32(0x2800 + cell.0 as u32).unwrap()
-                })
+        self.rows_iter().collect()
+    }
+
+    /// Returns an iterator over the rows of the `Canvas`, rendering each one only when it is
+    /// reached.
+    ///
+    /// This produces the same rows as `rows`, without holding all of them in memory at once.
+    pub fn rows_iter<'a>(&'a self) -> impl Iterator<Item = String> + 'a {
+        let (columns, rows) = self.extents();
+        (0..rows).map(move |y| {
+            let mut row = String::with_capacity(columns as usize);
+            self.render_row(y as u16, 0, columns, &mut row);
+            row
+        })
+    }
+
+    /// Renders the rows of the `Canvas` into `rows`, reusing the `String`s already in it.
+    ///
+    /// The `Vec` is truncated or extended to the number of rows as needed.
+    pub fn rows_into(&self, rows: &mut Vec<String>) {
+        let (columns, height) = self.extents();
+        rows.truncate(height as usize);
+        for y in 0..height {
+            match rows.get_mut(y as usize) {
+                Some(row) => {
+                    row.clear();
+                    self.render_row(y as u16, 0, columns, row);
+                }
+                None => {
+                    let mut row = String::with_capacity(columns as usize);
+                    self.render_row(y as u16, 0, columns, &mut row);
+                    rows.push(row);
+                }
+            }
+        }
+    }
+
+    /// Returns the rows from `start_row` up to but not including `end_row`, as `rows` would
+    /// render them, rendering only those rows.
+    ///
+    /// The range is clipped to the rows `rows` returns, so a band past the bottom of the
+    /// `Canvas` comes back short or empty. This suits scrolling through a tall `Canvas`, where
+    /// only the visible band needs rendering.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(0, 0);
+    /// for y in (0..400).step_by(4) {
+    ///     canvas.line(0, y, y / 40, y);
+    /// }
+    /// let rows = canvas.rows();
+    /// assert_eq!(canvas.rows_range(50, 53), &rows[50..53]);
+    /// assert_eq!(canvas.rows_range(99, 200), &rows[99..]);
+    /// ```
+    pub fn rows_range(&self, start_row: u32, end_row: u32) -> Vec<String> {
+        let (columns, rows) = self.extents();
+        (start_row..cmp::min(end_row, rows)).map(|y| {
+            let mut row = String::with_capacity(columns as usize);
+            self.render_row(y as u16, 0, columns, &mut row);
+            row
+        }).collect()
+    }
+
+    /// Returns the rows of a rectangle of `columns` by `rows` cells, with its top-left corner
+    /// at the cell `(col, row)`, as `rows` would render them.
+    ///
+    /// Only the cells inside the rectangle are visited, and whole empty chunks of them are
+    /// skipped, so showing a small viewport onto a huge canvas, such as a map of the world,
+    /// costs as much as the viewport rather than the canvas.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(0, 0);
+    /// canvas.line(0, 0, 99_999, 99_999);
+    /// assert_eq!(canvas.rows_region(20_000, 10_000, 4, 2), ["⠑⢄  ", "  ⠑⢄"]);
+    /// ```
+    pub fn rows_region(&self, col: u32, row: u32, columns: u32, rows: u32) -> Vec<String> {
+        (row..row.saturating_add(rows)).map(|y| {
+            let mut out = String::with_capacity(columns as usize);
+            if y <= u16::MAX as u32 {
+                self.render_row(y as u16, col, columns, &mut out);
+            } else {
+                out.extend((0..columns).map(|_| ' '));
+            }
+            out
+        }).collect()
+    }
+
+    /// Appends `columns` cells of the row `y` to `out`, starting at the column `start`.
+    fn render_row(&self, y: u16, start: u32, columns: u32, out: &mut String) {
+        let mut current = Style::default();
+        let end = start.saturating_add(columns);
+        let mut x = start;
+        while x < end {
+            // Cells past the last storable column, and chunks with nothing in them, are blank.
+            let chunk_end = cmp::min((x / CHUNK_SIZE + 1) * CHUNK_SIZE, end);
+            if x > u16::MAX as u32 || !self.has_chunk(x as u16, y) {
+                let blank_end = if x > u16::MAX as u32 { end } else { chunk_end };
+                if !current.is_plain() {
+                    out.push_str("\x1b[0m");
+                    current = Style::default();
+                }
+                out.extend((x..blank_end).map(|_| ' '));
+                x = blank_end;
+                continue;
+            }
+            for x in x..chunk_end {
+                self.render_cell(x as u16, y, &mut current, out);
+            }
+            x = chunk_end;
+        }
+        if !current.is_plain() {
+            out.push_str("\x1b[0m");
+        }
+    }
+
+    /// Detects whether anything has been drawn in the chunk of cells holding a cell.
+    fn has_chunk(&self, x: u16, y: u16) -> bool {
+        self.dots.has_chunk((x, y)) || self.text.has_chunk((x, y))
+            || self.styles.has_chunk((x, y)) || self.overlay.has_chunk((x, y))
+    }
+
+    /// Appends a cell to `out`, switching from the `current` style to its own as needed.
+    fn render_cell(&self, x: u16, y: u16, current: &mut Style, out: &mut String) {
+        let (c, style) = self.cell(x, y);
+        push_cell(c, style, current, out);
+    }
+
+    /// Draws the canvas to a `String` like `frame`, passing every cell through `f` first.
+    ///
+    /// `f` is given the column and row of the cell, its dot bits, and the character and style
+    /// it would be drawn with, and returns what to draw instead. Every cell is visited, empty
+    /// or not, and ANSI styling is handled as usual, which is enough for effects such as
+    /// highlighting the column under a cursor without changing the `Canvas`.
+    ///
+    /// ```
+    /// use drawille::{Canvas, Color, Style, StyledCell};
+    ///
+    /// let mut canvas = Canvas::new(6, 4);
+    /// canvas.line(0, 0, 5, 0);
+    /// let frame = canvas.frame_with(|col, _, bits, cell| match (col, bits) {
+    ///     (1, _) => StyledCell::new(cell.c, Style::new().bg(Color::Blue)),
+    ///     (_, 0) => StyledCell::new('·', cell.style),
+    ///     _ => cell,
+    /// });
+    /// assert_eq!(frame, "⠉\u{1b}[44m⠉\u{1b}[0m⠉·\n·\u{1b}[44m \u{1b}[0m··");
+    /// ```
+    pub fn frame_with<F>(&self, f: F) -> String
+        where F: Fn(u32, u32, u8, StyledCell) -> StyledCell
+    {
+        let (columns, rows) = self.extents();
+        let mut out = String::new();
+        for y in 0..rows {
+            if y > 0 {
+                out.push('\n');
+            }
+            let mut current = Style::default();
+            for x in 0..columns {
+                let (x, y) = (x as u16, y as u16);
+                let (c, style) = self.cell(x, y);
+                let bits = self.dots.get(&(x, y)).cloned().unwrap_or(0);
+                let cell = f(x as u32, y as u32, bits, StyledCell::new(c, style));
+                push_cell(cell.c, cell.style, &mut current, &mut out);
             }
-            result.push(row);
+            if !current.is_plain() {
+                out.push_str("\x1b[0m");
+            }
+        }
+        out
+    }
+
+    /// Returns statistics about what the `Canvas` holds and the memory it uses.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(0, 0);
+    /// canvas.line(0, 0, 9, 0);
+    /// canvas.set_char(0, 4, 'x');
+    /// let stats = canvas.stats();
+    /// assert_eq!((stats.cells, stats.dots, stats.text_cells), (6, 10, 1));
+    /// assert!(stats.heap_bytes > 0);
+    /// ```
+    pub fn stats(&self) -> CanvasStats {
+        let mut cells: Vec<_> = self.dots.iter()
+            .filter(|&(_, bits)| bits != 0)
+            .map(|(key, _)| key)
+            .chain(self.text.keys().cloned())
+            .chain(self.overlay.keys().cloned())
+            .collect();
+        cells.sort_unstable();
+        cells.dedup();
+        CanvasStats {
+            cells: cells.len(),
+            dots: self.dots.values().map(|bits| bits.count_ones() as usize).sum(),
+            text_cells: self.text.len(),
+            styled_cells: self.styles.len(),
+            overlay_cells: self.overlay.len(),
+            heap_bytes: self.dots.heap_bytes() + self.text.heap_bytes()
+                + self.styles.heap_bytes() + self.overlay.heap_bytes(),
+        }
+    }
+
+    /// Frees memory the `Canvas` holds on to but doesn’t need, such as after clearing it.
+    pub fn shrink_to_fit(&mut self) {
+        self.dots.shrink_to_fit();
+        self.text.shrink_to_fit();
+        self.styles.shrink_to_fit();
+        self.overlay.shrink_to_fit();
+    }
+
+    /// Returns the number of columns and rows of cells that the `Canvas` renders.
+    ///
+    /// This covers both the dimensions the `Canvas` was created with and every cell that has
+    /// been drawn to since, and is cheap to call.
+    pub fn extents(&self) -> (u32, u32) {
+        let (mut max_x, mut max_y) = (self.width, self.height);
+        if let Some((x, y)) = self.max_cell {
+            max_x = cmp::max(max_x, x);
+            max_y = cmp::max(max_y, y);
+        }
+        (max_x as u32 + 1, max_y as u32 + 1)
+    }
+
+    /// Adds the given dot bits to the cell at the given column and row.
+    pub(crate) fn or_cell(&mut self, col: u16, row: u16, bits: u8) {
+        if bits != 0 {
+            *self.dots.entry_or((col, row), 0) |= bits;
+            self.touch(col, row);
+        }
+    }
+
+    /// Replaces the dots of the cell at the given column and row, if that can be done directly.
+    ///
+    /// Returns `false` without drawing anything if the y axis points up, a journal is recording
+    /// or the cell is out of bounds under a policy other than `Expand`, since then the pixels
+    /// have to be drawn one at a time.
+    pub(crate) fn put_cell(&mut self, col: u16, row: u16, bits: u8) -> bool {
+        let in_bounds = self.in_bounds(col as u32 * 2, row as u32 * 4)
+            || self.out_of_bounds == OutOfBounds::Expand;
+        if self.y_up || self.journal.0.is_some() || !in_bounds {
+            return false;
+        }
+        *self.dots.entry_or((col, row), 0) = bits;
+        self.touch(col, row);
+        true
+    }
+
+    /// Hashes everything that `frame` renders, so that equal hashes almost surely mean equal
+    /// frames.
+    pub(crate) fn content_hash(&self) -> u64 {
+        let (columns, rows) = self.extents();
+        [self.dots.content_hash(), self.text.content_hash(), self.styles.content_hash(),
+         self.overlay.content_hash(), (columns as u64) << 32 | rows as u64]
+            .iter()
+            .fold(0xcbf2_9ce4_8422_2325, |h: u64, &part| (h ^ part).wrapping_mul(0x100_0000_01b3))
+    }
+
+    /// Records that the cell at the given column and row is in use and has changed.
+    fn touch(&mut self, col: u16, row: u16) {
+        self.damage.mark(row, col, col);
+        self.max_cell = Some(match self.max_cell {
+            Some((x, y)) => (cmp::max(x, col), cmp::max(y, row)),
+            None => (col, row),
+        });
+    }
+
+    /// Finds the largest column and row in use from scratch, after cells have been removed.
+    fn recompute_max_cell(&mut self) {
+        let keys = self.dots.keys()
+            .chain(self.text.keys().cloned())
+            .chain(self.overlay.keys().cloned());
+        self.max_cell = keys.fold(None, |max, (col, row)| Some(match max {
+            Some((x, y)) => (cmp::max(x, col), cmp::max(y, row)),
+            None => (col, row),
+        }));
+    }
+
+    /// Copies every cell of `other` onto this `Canvas`, moved down by `rows` rows of cells,
+    /// adding its pixels to those already there.
+    pub(crate) fn paste_rows(&mut self, other: &Canvas, rows: u16) {
+        for ((col, row), bits) in other.dots.iter() {
+            *self.dots.entry_or((col, row + rows), 0) |= bits;
+            self.touch(col, row + rows);
+        }
+        for (&(col, row), &c) in other.text.iter() {
+            self.text.insert((col, row + rows), c);
+            self.touch(col, row + rows);
+        }
+        for (&(col, row), &style) in other.styles.iter() {
+            self.styles.insert((col, row + rows), style);
+            self.damage.mark(row + rows, col, col);
+        }
+        for (&(col, row), &cell) in other.overlay.iter() {
+            self.overlay.insert((col, row + rows), cell);
+            self.touch(col, row + rows);
+        }
+    }
+
+    /// Returns the character and style a cell is rendered with, taking the overlay into account.
+    pub(crate) fn cell(&self, x: u16, y: u16) -> (char, Style) {
+        if let Some(&cell) = self.overlay.get(&(x, y)) {
+            return cell;
+        }
+        let style = self.styles.get(&(x, y)).cloned().unwrap_or_default();
+        match self.dots.get(&(x, y)) {
+            Some(&bits) if bits != 0 => (char::from_u32(0x2800 + bits as u32).unwrap(), style),
+            _ => (self.text.get(&(x, y)).cloned().unwrap_or(' '), style),
         }
-        result
     }
 
     /// Draws the canvas to a `String` and returns it.
@@ -117,27 +922,174 @@ impl Canvas {
         self.rows().join("\n")
     }
 
+    /// Draws the canvas to a `String` with Windows line endings, and returns it.
+    ///
+    /// Consoles in raw mode, and some Windows tools, only return to the start of the line on a
+    /// carriage return, so frames with bare newlines come out as a staircase.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(2, 8);
+    /// canvas.line(0, 0, 0, 7);
+    /// assert_eq!(canvas.frame_crlf(), "⡇ \r\n⡇ \r\n  ");
+    /// ```
+    pub fn frame_crlf(&self) -> String {
+        self.rows().join("\r\n")
+    }
+
+    /// Draws the canvas into `buf`, replacing its previous contents.
+    ///
+    /// The buffer’s allocation is reused, so rendering a frame of the same size as the last one
+    /// doesn’t allocate.
+    pub fn frame_into(&self, buf: &mut String) {
+        buf.clear();
+        let (columns, rows) = self.extents();
+        for y in 0..rows {
+            if y > 0 {
+                buf.push('\n');
+            }
+            self.render_row(y as u16, 0, columns, buf);
+        }
+    }
+
     /// Draws a line from `(x1, y1)` to `(x2, y2)` onto the `Canvas`.
+    ///
+    /// The line has no gaps (each pixel touches the next, at least diagonally), and swapping its
+    /// endpoints draws exactly the same pixels.
     pub fn line(&mut self, x1: u32, y1: u32, x2: u32, y2: u32) {
-        let xdiff = cmp::max(x1, x2) - cmp::min(x1, x2);
-        let ydiff = cmp::max(y1, y2) - cmp::min(y1, y2);
-        let xdir = if x1 <= x2 { 1 } else { -1 };
-        let ydir = if y1 <= y2 { 1 } else { -1 };
+        for (x, y) in line_points(x1, y1, x2, y2) {
+            self.set(x, y);
+        }
+    }
 
-        let r = cmp::max(xdiff, ydiff);
+    /// Creates a new `Canvas` from a frame previously produced by `frame`, such as Braille art
+    /// stored in a text file.
+    ///
+    /// Braille characters become pixels and any other character except a space becomes a
+    /// letter. ANSI styling is not recognised.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(10, 10);
+    /// canvas.line(2, 2, 8, 8);
+    /// canvas.set_char(0, 8, 'x');
+    /// assert_eq!(Canvas::from_frame(&canvas.frame()).frame(), canvas.frame());
+    /// ```
+    pub fn from_frame(frame: &str) -> Canvas {
+        let mut canvas = Canvas::new(0, 0);
+        let (mut columns, mut rows) = (0, 0);
+        for (row, line) in frame.split('\n').enumerate() {
+            rows = row + 1;
+            for (col, c) in line.chars().enumerate() {
+                columns = cmp::max(columns, col + 1);
+                let (x, y) = (col as u32 * 2, row as u32 * 4);
+                match c as u32 {
+                    0x2800 => {}
+                    b @ 0x2801..=0x28ff => {
+                        canvas.dots.insert((col as u16, row as u16), (b - 0x2800) as u8);
+                        canvas.touch(col as u16, row as u16);
+                    }
+                    _ if c == ' ' => {}
+                    _ => canvas.set_char(x, y, c),
+                }
+            }
+        }
+        canvas.width = columns.saturating_sub(1) as u16;
+        canvas.height = rows.saturating_sub(1) as u16;
+        canvas
+    }
+}
 
-        for i in 0..=r {
-            let mut x = x1 as i32;
-            let mut y = y1 as i32;
+impl Canvas {
+    /// Creates a new `Canvas` just large enough to hold the given pixels, and sets them.
+    pub fn from_pixels(pixels: &[(u32, u32)]) -> Canvas {
+        let mut canvas = Canvas::new(0, 0);
+        for &(x, y) in pixels {
+            canvas.set(x, y);
+        }
+        canvas
+    }
+}
 
-            if ydiff != 0 {
-                y += ((i * ydiff) / r) as i32 * ydir;
-            }
-            if xdiff != 0 {
-                x += ((i * xdiff) / r) as i32 * xdir;
+impl Default for Canvas {
+    /// Creates a new, empty `Canvas` with no given dimensions.
+    fn default() -> Canvas {
+        Canvas::new(0, 0)
+    }
+}
+
+/// Parses a frame with `Canvas::from_frame`.
+impl<'a> From<&'a str> for Canvas {
+    fn from(frame: &'a str) -> Canvas {
+        Canvas::from_frame(frame)
+    }
+}
+
+/// Creates a `Canvas` from rows of pixels, setting those that are `true`.
+///
+/// ```
+/// use drawille::Canvas;
+///
+/// let canvas = Canvas::from(vec![vec![true, false], vec![false, true]]);
+/// assert_eq!(String::from(canvas), "⠑ ");
+/// ```
+impl From<Vec<Vec<bool>>> for Canvas {
+    fn from(rows: Vec<Vec<bool>>) -> Canvas {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut canvas = Canvas::new(width as u32, rows.len() as u32);
+        for (y, row) in rows.iter().enumerate() {
+            for (x, _) in row.iter().enumerate().filter(|&(_, &on)| on) {
+                canvas.set(x as u32, y as u32);
             }
+        }
+        canvas
+    }
+}
+
+/// Draws the `Canvas` with `Canvas::frame`.
+impl From<Canvas> for String {
+    fn from(canvas: Canvas) -> String {
+        canvas.frame()
+    }
+}
 
-            self.set(x as u32, y as u32);
+/// Appends a character to `out`, switching from the `current` style to the given one as needed.
+fn push_cell(c: char, style: Style, current: &mut Style, out: &mut String) {
+    if style != *current {
+        if !current.is_plain() {
+            out.push_str("\x1b[0m");
         }
+        if !style.is_plain() {
+            style.write_sgr(out);
+        }
+        *current = style;
     }
+    out.push(c);
+}
+
+/// Returns the pixels of the line from `(x1, y1)` to `(x2, y2)`, as drawn by `Canvas::line`.
+pub(crate) fn line_points(x1: u32, y1: u32, x2: u32, y2: u32) -> impl Iterator<Item = (u32, u32)> {
+    // Always walk from the same end, so that rounding can’t make the two directions differ.
+    let ((x1, y1), (x2, y2)) = if (x1, y1) <= (x2, y2) {
+        ((x1, y1), (x2, y2))
+    } else {
+        ((x2, y2), (x1, y1))
+    };
+    let xdiff = (cmp::max(x1, x2) - cmp::min(x1, x2)) as u64;
+    let ydiff = (cmp::max(y1, y2) - cmp::min(y1, y2)) as u64;
+    let r = cmp::max(xdiff, ydiff);
+
+    (0..=r).map(move |i| {
+        if r == 0 {
+            return (x1, y1);
+        }
+        // Rounds i * diff / r to the nearest integer.
+        let dx = ((i * xdiff * 2 + r) / (r * 2)) as u32;
+        let dy = ((i * ydiff * 2 + r) / (r * 2)) as u32;
+        let x = if x1 <= x2 { x1 + dx } else { x1 - dx };
+        let y = if y1 <= y2 { y1 + dy } else { y1 - dy };
+        (x, y)
+    })
 }
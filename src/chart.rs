@@ -0,0 +1,2246 @@
+//! Charts that plot data series onto a `Canvas`, with axes and a legend.
+//!
+//! ```
+//! use drawille::chart::{Chart, Series};
+//!
+//! let sin: Vec<_> = (0..100).map(|i| (i as f64 / 10.0, (i as f64 / 10.0).sin())).collect();
+//! let cos: Vec<_> = (0..100).map(|i| (i as f64 / 10.0, (i as f64 / 10.0).cos())).collect();
+//! let mut chart = Chart::new(120, 48);
+//! chart.add_series(Series::line("sin", sin.clone()));
+//! chart.add_series(Series::line("cos", cos.clone()));
+//! assert!(chart.frame().contains("── sin") && chart.frame().contains("── cos"));
+//!
+//! // Every point of both series is drawn where the axes place it.
+//! let canvas = chart.render();
+//! let drawn = |x: f64, y: f64| {
+//!     let (px, py) = chart.data_to_pixel(x, y).unwrap();
+//!     canvas.get(px.round() as u32, py.round() as u32)
+//! };
+//! assert!(sin.iter().chain(&cos).all(|&(x, y)| drawn(x, y)));
+//! ```
+
+use std::cmp::{self, Ordering};
+use std::fmt;
+use std::sync::Arc;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone};
+
+use canvas::Canvas;
+use stroke::LineStyle;
+use style::{Color, Span, Style};
+use world::clip;
+
+/// The colours given to series that don’t choose their own, in order.
+const PALETTE: [Color; 6] = [Color::Blue, Color::Red, Color::Green, Color::Yellow,
+                             Color::Magenta, Color::Cyan];
+
+/// The markers given to scatter series that don’t choose their own, in order.
+const MARKERS: [Marker; 5] = [Marker::Dot, Marker::Cross, Marker::Plus, Marker::Square,
+                              Marker::Diamond];
+
+/// A shape drawn at every data point of a series.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Marker {
+    /// A single pixel.
+    Dot,
+    /// A 3×3 ‘×’.
+    Cross,
+    /// A 3×3 ‘+’.
+    Plus,
+    /// The outline of a 3×3 square.
+    Square,
+    /// A 3×3 diamond.
+    Diamond,
+}
+
+impl Marker {
+    /// Returns the offsets of the pixels making up the marker, relative to its centre.
+    fn offsets(self) -> &'static [(i64, i64)] {
+        match self {
+            Marker::Dot => &[(0, 0)],
+            Marker::Cross => &[(-1, -1), (1, -1), (0, 0), (-1, 1), (1, 1)],
+            Marker::Plus => &[(0, -1), (-1, 0), (0, 0), (1, 0), (0, 1)],
+            Marker::Square => &[(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1),
+                                (1, 1)],
+            Marker::Diamond => &[(0, -1), (-1, 0), (1, 0), (0, 1)],
+        }
+    }
+
+    /// Returns the character that stands for the marker in the legend.
+    fn symbol(self) -> char {
+        match self {
+            Marker::Dot => '•',
+            Marker::Cross => '×',
+            Marker::Plus => '+',
+            Marker::Square => '□',
+            Marker::Diamond => '◇',
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Line,
+    Scatter,
+    Candlestick,
+    BoxPlot,
+    Area,
+    Band,
+}
+
+/// How the region under an area series, or between the bounds of a band, is shaded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Fill {
+    /// Every pixel is set.
+    Solid,
+    /// Every other pixel is set, in a checkerboard.
+    Checker,
+    /// One pixel in four is set.
+    Sparse,
+}
+
+impl Fill {
+    /// Detects whether the fill sets the given pixel.
+    pub(crate) fn covers(self, x: u32, y: u32) -> bool {
+        match self {
+            Fill::Solid => true,
+            Fill::Checker => (x + y) % 2 == 0,
+            Fill::Sparse => x % 2 == 0 && y % 2 == 0,
+        }
+    }
+
+    /// Returns the character that stands for the fill in the legend.
+    fn symbol(self) -> char {
+        match self {
+            Fill::Solid => '█',
+            Fill::Checker => '▒',
+            Fill::Sparse => '░',
+        }
+    }
+}
+
+/// How a line or scatter series with more points than its plot has pixel columns is thinned
+/// out before it is drawn.
+///
+/// Drawing a million points takes far longer than drawing the few hundred a plot can show
+/// apart, and gives the same picture when the right ones are kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Downsample {
+    /// Keeps one point for every pixel column with `lttb`, which follows the overall shape and
+    /// keeps peaks that stand out.
+    Lttb,
+    /// Keeps the lowest and highest points in every pixel column, which draws exactly the same
+    /// vertical extent in each column as the full data, at the cost of two points per column.
+    MinMax,
+}
+
+/// The open, high, low and close values of one period of a candlestick series.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ohlc {
+    /// The position of the period on the x axis.
+    pub x: f64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+impl Ohlc {
+    /// Creates a new `Ohlc` from its values.
+    pub fn new(x: f64, open: f64, high: f64, low: f64, close: f64) -> Ohlc {
+        Ohlc { x, open, high, low, close }
+    }
+
+    /// Detects whether the value rose, or stayed the same, over the period.
+    pub fn is_rising(&self) -> bool {
+        self.close >= self.open
+    }
+}
+
+/// The quartiles, whiskers and outliers of one sample, drawn as a box of a box plot.
+///
+/// The whiskers reach the lowest and highest values within one and a half times the
+/// interquartile range of the box, and any value beyond them is an outlier.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoxStats {
+    /// The position of the box on the x axis.
+    pub x: f64,
+    /// The end of the lower whisker.
+    pub low: f64,
+    /// The first quartile, at the bottom of the box.
+    pub q1: f64,
+    pub median: f64,
+    /// The third quartile, at the top of the box.
+    pub q3: f64,
+    /// The end of the upper whisker.
+    pub high: f64,
+    /// The values beyond the whiskers, in ascending order.
+    pub outliers: Vec<f64>,
+}
+
+impl BoxStats {
+    /// Works out the `BoxStats` of a sample, or returns `None` if it has no finite values.
+    ///
+    /// Values that aren’t finite are left out. Quartiles are interpolated linearly between the
+    /// values either side of them.
+    ///
+    /// ```
+    /// use drawille::chart::BoxStats;
+    ///
+    /// let stats = BoxStats::from_samples(0.0, &[1.0, 2.0, 3.0, 4.0, 5.0, 40.0]).unwrap();
+    /// assert_eq!((stats.q1, stats.median, stats.q3), (2.25, 3.5, 4.75));
+    /// assert_eq!((stats.low, stats.high, stats.outliers), (1.0, 5.0, vec![40.0]));
+    /// ```
+    pub fn from_samples(x: f64, samples: &[f64]) -> Option<BoxStats> {
+        let mut sorted: Vec<f64> = samples.iter().cloned().filter(|v| v.is_finite()).collect();
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let quantile = |q: f64| {
+            let position = q * (sorted.len() - 1) as f64;
+            let (below, above) = (position.floor() as usize, position.ceil() as usize);
+            sorted[below] + (sorted[above] - sorted[below]) * (position - below as f64)
+        };
+        let (q1, median, q3) = (quantile(0.25), quantile(0.5), quantile(0.75));
+        let reach = (q3 - q1) * 1.5;
+        let inside = |v: &&f64| **v >= q1 - reach && **v <= q3 + reach;
+        // With few values, the last one inside can fall within the box, which then has no
+        // whisker on that side.
+        let low = sorted.iter().find(inside).map_or(q1, |&v| v.min(q1));
+        let high = sorted.iter().rev().find(inside).map_or(q3, |&v| v.max(q3));
+        let outliers = sorted.iter().cloned().filter(|v| !inside(&v)).collect();
+        Some(BoxStats { x, low, q1, median, q3, high, outliers })
+    }
+}
+
+/// A named sequence of `(x, y)` data points.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+    name: String,
+    points: Vec<(f64, f64)>,
+    candles: Vec<Ohlc>,
+    boxes: Vec<BoxStats>,
+    error_bars: Vec<(f64, f64)>,
+    confidence: Vec<(f64, f64)>,
+    lower: Vec<(f64, f64)>,
+    kind: Kind,
+    fill: Option<Fill>,
+    color: Option<Color>,
+    line: Option<LineStyle>,
+    marker: Option<Marker>,
+    downsample: Option<Downsample>,
+}
+
+impl Series {
+    /// Creates a new `Series` drawn as lines between consecutive points.
+    pub fn line<S: Into<String>>(name: S, points: Vec<(f64, f64)>) -> Series {
+        Series::new(name.into(), points, Kind::Line)
+    }
+
+    /// Creates a new `Series` drawn as a marker at every point.
+    pub fn scatter<S: Into<String>>(name: S, points: Vec<(f64, f64)>) -> Series {
+        Series::new(name.into(), points, Kind::Scatter)
+    }
+
+    /// Creates a new `Series` drawn as a candlestick for every period.
+    ///
+    /// Each candle is a wick from the low to the high value, and a body between the open and
+    /// close values that is hollow and green for rising periods, and filled and red for falling
+    /// ones. Setting a colour on the series uses it for both.
+    ///
+    /// ```
+    /// use drawille::chart::{Chart, Ohlc, Series};
+    ///
+    /// let mut chart = Chart::new(60, 40);
+    /// chart.add_series(Series::candlestick("ACME", vec![
+    ///     Ohlc::new(1.0, 10.0, 14.0, 9.0, 13.0),
+    ///     Ohlc::new(2.0, 13.0, 13.5, 8.0, 9.0),
+    ///     Ohlc::new(3.0, 9.0, 12.0, 8.5, 11.0),
+    /// ]));
+    /// let canvas = chart.render();
+    /// let drawn = |x: f64, y: f64| {
+    ///     let (px, py) = chart.data_to_pixel(x, y).unwrap();
+    ///     canvas.get(px.round() as u32, py.round() as u32)
+    /// };
+    /// // The wicks reach from each low to each high, and the body of the rising first candle
+    /// // is hollow where that of the falling second one is filled.
+    /// assert!(drawn(1.0, 9.0) && drawn(1.0, 14.0) && drawn(2.0, 8.0) && drawn(2.0, 13.5));
+    /// assert!(!drawn(1.0, 11.0) && drawn(2.0, 11.0));
+    /// ```
+    pub fn candlestick<S: Into<String>>(name: S, candles: Vec<Ohlc>) -> Series {
+        let mut series = Series::new(name.into(), Vec::new(), Kind::Candlestick);
+        series.candles = candles;
+        series
+    }
+
+    /// Creates a new `Series` drawn as a box plot, with a box for each sample worked out by
+    /// `BoxStats::from_samples` at the x value given with it.
+    ///
+    /// Each box spans the first to the third quartile, with a line across it at the median and
+    /// whiskers out to the lowest and highest values that aren’t outliers. Outliers are drawn
+    /// with the series’ marker, a single dot unless another is set. Samples without a finite
+    /// value are left out.
+    ///
+    /// ```
+    /// use drawille::chart::{Chart, Series};
+    ///
+    /// let mut chart = Chart::new(60, 40);
+    /// chart.add_series(Series::boxplot("latency", vec![
+    ///     (1.0, vec![12.0, 15.0, 14.0, 13.0, 18.0, 16.0, 35.0]),
+    ///     (2.0, vec![20.0, 22.0, 19.0, 25.0, 24.0, 21.0, 23.0]),
+    ///     (3.0, vec![9.0, 11.0, 10.0, 14.0, 12.0, 2.0, 13.0]),
+    /// ]));
+    /// let canvas = chart.render();
+    /// let drawn = |x: f64, y: f64| {
+    ///     let (px, py) = chart.data_to_pixel(x, y).unwrap();
+    ///     canvas.get(px.round() as u32, py.round() as u32)
+    /// };
+    /// // The outliers stand apart from the ends of the whiskers.
+    /// assert!(drawn(1.0, 18.0) && !drawn(1.0, 30.0) && drawn(1.0, 35.0));
+    /// assert!(drawn(3.0, 2.0) && !drawn(3.0, 5.0));
+    /// ```
+    pub fn boxplot<S: Into<String>>(name: S, samples: Vec<(f64, Vec<f64>)>) -> Series {
+        let mut series = Series::new(name.into(), Vec::new(), Kind::BoxPlot);
+        series.boxes = samples.iter().filter_map(|s| BoxStats::from_samples(s.0, &s.1)).collect();
+        series
+    }
+
+    /// Creates a new `Series` drawn as a filled area, stacked on top of any area series added
+    /// before it.
+    ///
+    /// Stacked areas should share the same x values; where they don’t, the area below is
+    /// interpolated linearly.
+    ///
+    /// ```
+    /// use drawille::chart::{Chart, Series};
+    ///
+    /// let mut chart = Chart::new(80, 40);
+    /// chart.add_series(Series::area("reads", vec![(0.0, 2.0), (1.0, 3.0), (2.0, 1.0)]));
+    /// chart.add_series(Series::area("writes", vec![(0.0, 1.0), (1.0, 1.0), (2.0, 2.0)]));
+    /// let canvas = chart.render();
+    /// let drawn = |x: f64, y: f64| {
+    ///     let (px, py) = chart.data_to_pixel(x, y).unwrap();
+    ///     canvas.get(px.round() as u32, py.round() as u32)
+    /// };
+    /// // Halfway between the points the areas add up to 3.5 on both sides.
+    /// assert!(drawn(0.5, 3.4) && !drawn(0.5, 3.8));
+    /// assert!(drawn(1.5, 3.4) && !drawn(1.5, 3.8));
+    /// ```
+    pub fn area<S: Into<String>>(name: S, points: Vec<(f64, f64)>) -> Series {
+        Series::new(name.into(), points, Kind::Area)
+    }
+
+    /// Creates a new `Series` drawn as the shaded region between a lower and an upper bound,
+    /// such as the envelope of a set of percentiles.
+    ///
+    /// ```
+    /// use drawille::chart::{Chart, Series};
+    ///
+    /// let p50 = vec![(0.0, 10.0), (1.0, 12.0), (2.0, 11.0)];
+    /// let p99 = vec![(0.0, 30.0), (1.0, 45.0), (2.0, 35.0)];
+    /// let mut chart = Chart::new(80, 40);
+    /// chart.add_series(Series::band("p50–p99", p50.clone(), p99));
+    /// chart.add_series(Series::line("p50", p50));
+    /// let canvas = chart.render();
+    /// let drawn = |x: f64, y: f64| {
+    ///     let (px, py) = chart.data_to_pixel(x, y).unwrap();
+    ///     canvas.get(px.round() as u32, py.round() as u32)
+    /// };
+    /// // The band runs from the line up to p99, shaded with a checkerboard.
+    /// assert!(drawn(1.0, 45.0) && drawn(1.0, 12.0) && !drawn(1.0, 11.0));
+    /// assert!(drawn(0.5, 20.0) && !drawn(0.5, 21.0) && drawn(0.5, 22.0));
+    /// ```
+    pub fn band<S: Into<String>>(name: S, lower: Vec<(f64, f64)>, upper: Vec<(f64, f64)>)
+        -> Series
+    {
+        let mut series = Series::new(name.into(), upper, Kind::Band);
+        series.lower = lower;
+        series
+    }
+
+    fn new(name: String, points: Vec<(f64, f64)>, kind: Kind) -> Series {
+        Series {
+            name,
+            points,
+            candles: Vec::new(),
+            boxes: Vec::new(),
+            error_bars: Vec::new(),
+            confidence: Vec::new(),
+            lower: Vec::new(),
+            kind,
+            fill: None,
+            color: None,
+            line: None,
+            marker: None,
+            downsample: None,
+        }
+    }
+
+    /// Sets the colour of a `Series` instead of picking one automatically, and return it for
+    /// use again.
+    pub fn color(mut self, color: Color) -> Series {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the dash pattern of the lines of a `Series` instead of picking one automatically,
+    /// and return it for use again.
+    ///
+    /// This also connects the points of a scatter series. The colour of the `LineStyle` is
+    /// ignored in favour of the series’ colour.
+    pub fn line_style(mut self, line: LineStyle) -> Series {
+        self.line = Some(line);
+        self
+    }
+
+    /// Sets how an area, band or confidence band `Series` is shaded, and return it for use
+    /// again.
+    ///
+    /// Areas are filled solidly and bands with a checkerboard by default, so that lines drawn
+    /// over a band stay visible.
+    pub fn fill(mut self, fill: Fill) -> Series {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// Sets the marker drawn at each point of a `Series` instead of picking one automatically,
+    /// and return it for use again.
+    ///
+    /// This also adds markers to a line series.
+    pub fn marker(mut self, marker: Marker) -> Series {
+        self.marker = Some(marker);
+        self
+    }
+
+    /// Sets how the points of a line or scatter `Series` are thinned out when there are more of
+    /// them than pixel columns, and return it for use again.
+    ///
+    /// The points are kept in full, for the axes and `Chart::nearest_point`; only drawing skips
+    /// those left out. By default every point is drawn.
+    ///
+    /// ```
+    /// use drawille::chart::{Chart, Downsample, Series};
+    ///
+    /// let mut noise: Vec<_> = (0..100_000).map(|i| (i as f64, (i as f64 * 0.37).sin())).collect();
+    /// noise[54_321].1 = 5.0;
+    /// let mut chart = Chart::new(120, 40);
+    /// chart.add_series(Series::line("noise", noise).downsample(Downsample::MinMax));
+    /// // Only a few points are drawn in each column, but the spike among them is kept.
+    /// let canvas = chart.render();
+    /// let (px, py) = chart.data_to_pixel(54_321.0, 5.0).unwrap();
+    /// assert!(canvas.get(px.round() as u32, py.round() as u32));
+    /// ```
+    pub fn downsample(mut self, downsample: Downsample) -> Series {
+        self.downsample = Some(downsample);
+        self
+    }
+
+    /// Draws an error bar at each point of a line or scatter `Series`, and return it for use
+    /// again.
+    ///
+    /// Each pair gives how far the bar of the point at the same index reaches below and above
+    /// it, such as a standard deviation either side. Points without a pair get no bar.
+    ///
+    /// ```
+    /// use drawille::chart::{Chart, Series};
+    ///
+    /// let means = vec![(1.0, 4.0), (2.0, 6.0), (3.0, 5.0)];
+    /// let mut chart = Chart::new(60, 40);
+    /// chart.add_series(Series::scatter("trial", means)
+    ///     .error_bars(vec![(1.0, 1.0), (2.0, 0.5), (0.5, 2.0)]));
+    /// let canvas = chart.render();
+    /// let drawn = |x: f64, y: f64| {
+    ///     let (px, py) = chart.data_to_pixel(x, y).unwrap();
+    ///     canvas.get(px.round() as u32, py.round() as u32)
+    /// };
+    /// // Each bar reaches as far below and above its point as given.
+    /// assert!(drawn(1.0, 4.5) && drawn(1.0, 5.0) && !drawn(1.0, 5.5));
+    /// assert!(drawn(2.0, 4.0) && drawn(2.0, 6.5) && !drawn(2.0, 3.5));
+    /// assert!(drawn(3.0, 4.5) && drawn(3.0, 7.0) && !drawn(3.0, 4.4));
+    /// ```
+    pub fn error_bars(mut self, errors: Vec<(f64, f64)>) -> Series {
+        self.error_bars = errors;
+        self
+    }
+
+    /// Shades a confidence band around a line or scatter `Series`, and return it for use again.
+    ///
+    /// Each pair gives how far the band reaches below and above the point at the same index,
+    /// and the band runs between those points only. It is shaded with a checkerboard in the
+    /// series’ colour unless another `Fill` is set, and drawn under the line.
+    ///
+    /// ```
+    /// use drawille::chart::{Chart, Fill, Series};
+    ///
+    /// let fit: Vec<_> = (0..20).map(|i| (i as f64, (i as f64 / 3.0).sin())).collect();
+    /// let spread = (0..20).map(|i| (0.1 + i as f64 / 50.0, 0.1 + i as f64 / 50.0)).collect();
+    /// let mut chart = Chart::new(80, 40);
+    /// chart.add_series(Series::line("fit", fit).confidence_band(spread).fill(Fill::Sparse));
+    /// // At x = 9 the band reaches 0.28 either side of the line, and is shaded sparsely.
+    /// let canvas = chart.render();
+    /// let shaded = |x: f64, from: f64, to: f64| {
+    ///     let ((px, top), (_, bottom)) =
+    ///         (chart.data_to_pixel(x, to).unwrap(), chart.data_to_pixel(x, from).unwrap());
+    ///     (top.round() as u32..=bottom.round() as u32)
+    ///         .filter(|&py| canvas.get(px.round() as u32, py)).count()
+    /// };
+    /// let y = 3f64.sin();
+    /// assert_eq!(shaded(9.0, y - 0.28, y + 0.28), 4);
+    /// assert_eq!(shaded(9.0, y + 0.35, 1.0) + shaded(9.0, -1.0, y - 0.35), 0);
+    /// ```
+    pub fn confidence_band(mut self, errors: Vec<(f64, f64)>) -> Series {
+        self.confidence = errors;
+        self
+    }
+
+    /// Returns the name of the `Series`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the data points of the `Series`.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Returns the periods of a candlestick `Series`, which is empty for other kinds.
+    pub fn candles(&self) -> &[Ohlc] {
+        &self.candles
+    }
+
+    /// Returns the boxes of a box plot `Series`, which is empty for other kinds.
+    pub fn boxes(&self) -> &[BoxStats] {
+        &self.boxes
+    }
+
+    /// Appends a period to a candlestick `Series`.
+    pub fn push_candle(&mut self, candle: Ohlc) {
+        self.candles.push(candle);
+    }
+
+    /// Returns how the series is shaded, if it is.
+    fn shading(&self) -> Option<Fill> {
+        match self.kind {
+            Kind::Area => Some(self.fill.unwrap_or(Fill::Solid)),
+            Kind::Band => Some(self.fill.unwrap_or(Fill::Checker)),
+            _ => None,
+        }
+    }
+
+    /// Returns every point that the axes have to fit, including half a period either side of
+    /// candles and boxes so that the outermost ones aren’t cut off.
+    fn extent(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        let gap = smallest_gap(self.candles.iter().map(|c| c.x)
+            .chain(self.boxes.iter().map(|b| b.x)).collect());
+        let pad = if gap.is_finite() { gap / 2.0 } else { 0.5 };
+        let candles = self.candles.iter()
+            .flat_map(move |c| vec![(c.x - pad, c.high), (c.x + pad, c.low)]);
+        let boxes = self.boxes.iter().flat_map(move |b| {
+            let low = b.outliers.first().map_or(b.low, |&v| v.min(b.low));
+            let high = b.outliers.last().map_or(b.high, |&v| v.max(b.high));
+            vec![(b.x - pad, high), (b.x + pad, low)]
+        });
+        let errors = self.points.iter().zip(&self.error_bars)
+            .chain(self.points.iter().zip(&self.confidence))
+            .flat_map(|(&(x, y), &(below, above))| vec![(x, y - below), (x, y + above)]);
+        self.points.iter().chain(&self.lower).cloned().chain(candles).chain(boxes).chain(errors)
+    }
+
+    /// Returns the lowest, highest, mean and last of the y values of the `Series`, or of the
+    /// closing prices of a candlestick `Series` or the medians of a box plot, or `None` if it
+    /// has no finite ones.
+    ///
+    /// Values that aren’t finite are skipped. For a band these are of its upper bound.
+    pub fn stats(&self) -> Option<SeriesStats> {
+        let values: Vec<f64> = match self.kind {
+            Kind::Candlestick => self.candles.iter().map(|c| c.close).collect(),
+            Kind::BoxPlot => self.boxes.iter().map(|b| b.median).collect(),
+            _ => self.points.iter().map(|p| p.1).collect(),
+        };
+        let mut values = values.into_iter().filter(|v| v.is_finite());
+        let first = values.next()?;
+        let mut stats = SeriesStats { min: first, max: first, mean: first, last: first, count: 1 };
+        for v in values {
+            stats.min = stats.min.min(v);
+            stats.max = stats.max.max(v);
+            stats.mean += v;
+            stats.last = v;
+            stats.count += 1;
+        }
+        stats.mean /= stats.count as f64;
+        Some(stats)
+    }
+
+    /// Appends a data point to the `Series`.
+    pub fn push(&mut self, x: f64, y: f64) {
+        self.points.push((x, y));
+    }
+
+    /// Appends a data point to the `Series` at the given time, as a Unix timestamp for use with
+    /// a time axis.
+    #[cfg(feature = "chrono")]
+    pub fn push_time<Tz: TimeZone>(&mut self, time: &DateTime<Tz>, y: f64) {
+        let x = time.timestamp() as f64 + time.timestamp_subsec_nanos() as f64 / 1e9;
+        self.points.push((x, y));
+    }
+
+    /// Simplifies the points of a `Series` with `simplify`, and return it for use again.
+    ///
+    /// Both bounds of a band are simplified.
+    pub fn simplify(mut self, epsilon: f64) -> Series {
+        self.points = simplify(&self.points, epsilon);
+        self.lower = simplify(&self.lower, epsilon);
+        self
+    }
+
+    /// Smooths the points of a `Series` with `moving_average`, and return it for use again.
+    ///
+    /// Both bounds of a band are smoothed.
+    pub fn smooth(mut self, window: usize) -> Series {
+        self.points = moving_average(&self.points, window);
+        self.lower = moving_average(&self.lower, window);
+        self
+    }
+}
+
+/// Simplifies a line through `points` with the Ramer–Douglas–Peucker algorithm, keeping only
+/// the points that stray more than `epsilon` from the line through those kept either side.
+///
+/// Thousands of points squeezed into a few hundred pixels draw little more than a solid
+/// smudge, and most of them can go without changing the shape of the line. The first and last
+/// points are always kept. Distances are measured in data units, across both axes at once, so
+/// `epsilon` should be chosen with the ranges of both in mind.
+///
+/// ```
+/// use drawille::chart;
+///
+/// let points: Vec<_> = (0..=100).map(|i| (i as f64, if i == 50 { 10.0 } else { 0.0 })).collect();
+/// assert_eq!(chart::simplify(&points, 0.5),
+///            [(0.0, 0.0), (49.0, 0.0), (50.0, 10.0), (51.0, 0.0), (100.0, 0.0)]);
+/// ```
+pub fn simplify(points: &[(f64, f64)], epsilon: f64) -> Vec<(f64, f64)> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let ((x1, y1), (x2, y2)) = (points[first], points[last]);
+        let (dx, dy) = (x2 - x1, y2 - y1);
+        let length = dx.hypot(dy);
+        let mut farthest = (0.0, first);
+        for (i, &(x, y)) in points.iter().enumerate().take(last).skip(first + 1) {
+            let distance = if length > 0.0 {
+                (dy * (x - x1) - dx * (y - y1)).abs() / length
+            } else {
+                (x - x1).hypot(y - y1)
+            };
+            if distance > farthest.0 {
+                farthest = (distance, i);
+            }
+        }
+        if farthest.0 > epsilon {
+            keep[farthest.1] = true;
+            stack.push((first, farthest.1));
+            stack.push((farthest.1, last));
+        }
+    }
+    points.iter().zip(keep).filter(|p| p.1).map(|p| *p.0).collect()
+}
+
+/// Downsamples `points` to at most `threshold` of them with the largest-triangle-three-buckets
+/// algorithm, which keeps the points that best preserve the shape of the line through them.
+///
+/// The points between the first and last are split into `threshold - 2` buckets of
+/// consecutive points, and from each the point is kept that makes the largest triangle with
+/// the point kept before it and the mean of the next bucket. The first and last points are
+/// always kept, so a `threshold` below 3 keeps only them.
+///
+/// ```
+/// use drawille::chart;
+///
+/// let mut points: Vec<_> = (0..1000).map(|i| (i as f64, 0.0)).collect();
+/// points[567].1 = 10.0;
+/// let thinned = chart::lttb(&points, 50);
+/// assert_eq!(thinned.len(), 50);
+/// assert!(thinned.contains(&(567.0, 10.0)));
+/// ```
+pub fn lttb(points: &[(f64, f64)], threshold: usize) -> Vec<(f64, f64)> {
+    let len = points.len();
+    if threshold >= len || len < 3 {
+        return points.to_vec();
+    }
+    if threshold < 3 {
+        return vec![points[0], points[len - 1]];
+    }
+    let every = (len - 2) as f64 / (threshold - 2) as f64;
+    let start = |bucket: usize| cmp::min((bucket as f64 * every) as usize + 1, len - 1);
+    let mut kept = Vec::with_capacity(threshold);
+    kept.push(points[0]);
+    let mut previous = points[0];
+    for bucket in 0..threshold - 2 {
+        let next = &points[start(bucket + 1)..cmp::max(start(bucket + 2), start(bucket + 1) + 1)];
+        let mean = next.iter().fold((0.0, 0.0), |m, p| (m.0 + p.0, m.1 + p.1));
+        let mean = (mean.0 / next.len() as f64, mean.1 / next.len() as f64);
+        let (ax, ay) = previous;
+        let area = |&(x, y): &(f64, f64)| {
+            ((ax - mean.0) * (y - ay) - (ax - x) * (mean.1 - ay)).abs()
+        };
+        let candidates = &points[start(bucket)..start(bucket + 1)];
+        let chosen = candidates.iter().fold(candidates[0], |best, p| {
+            if area(p) > area(&best) { *p } else { best }
+        });
+        kept.push(chosen);
+        previous = chosen;
+    }
+    kept.push(points[len - 1]);
+    kept
+}
+
+/// Downsamples `points` by splitting the range of their x values into `buckets` equal parts
+/// and keeping only the lowest and highest point in each, in their original order.
+///
+/// Unlike `lttb`, every spike and dip survives, since a line through the points kept covers
+/// the same values in each bucket as the line through them all. Points that aren’t finite are
+/// left out.
+///
+/// ```
+/// use drawille::chart;
+///
+/// let points: Vec<_> = (0..1000).map(|i| (i as f64, (i % 7) as f64)).collect();
+/// let thinned = chart::min_max(&points, 10);
+/// assert_eq!(thinned.len(), 20);
+/// assert_eq!(&thinned[..2], [(0.0, 0.0), (6.0, 6.0)]);
+/// ```
+pub fn min_max(points: &[(f64, f64)], buckets: usize) -> Vec<(f64, f64)> {
+    let finite = || points.iter().filter(|p| p.0.is_finite() && p.1.is_finite());
+    let (low, high) = finite().fold((f64::INFINITY, f64::NEG_INFINITY),
+                                    |r, p| (r.0.min(p.0), r.1.max(p.0)));
+    let buckets = cmp::max(buckets, 1);
+    let width = (high - low) / buckets as f64;
+    min_max_by(points, |x| {
+        if width > 0.0 { cmp::min(((x - low) / width) as usize, buckets - 1) as i64 } else { 0 }
+    })
+}
+
+/// Keeps the lowest and highest of each run of finite points with the same key, in order.
+fn min_max_by<F: Fn(f64) -> i64>(points: &[(f64, f64)], key: F) -> Vec<(f64, f64)> {
+    let mut kept = Vec::new();
+    // The key of the current run, and the indices of its lowest and highest points.
+    let mut run: Option<(i64, usize, usize)> = None;
+    let flush = |kept: &mut Vec<(f64, f64)>, (_, min, max): (i64, usize, usize)| {
+        match min.cmp(&max) {
+            Ordering::Less => kept.extend_from_slice(&[points[min], points[max]]),
+            Ordering::Greater => kept.extend_from_slice(&[points[max], points[min]]),
+            Ordering::Equal => kept.push(points[min]),
+        }
+    };
+    for (i, &(x, y)) in points.iter().enumerate() {
+        if !(x.is_finite() && y.is_finite()) {
+            continue;
+        }
+        let k = key(x);
+        match run {
+            Some((key, ref mut min, ref mut max)) if key == k => {
+                if y < points[*min].1 {
+                    *min = i;
+                }
+                if y > points[*max].1 {
+                    *max = i;
+                }
+            }
+            _ => {
+                if let Some(r) = run {
+                    flush(&mut kept, r);
+                }
+                run = Some((k, i, i));
+            }
+        }
+    }
+    if let Some(r) = run {
+        flush(&mut kept, r);
+    }
+    kept
+}
+
+/// Smooths out noise in `points` by replacing each y value with the mean of the `window`
+/// values centred on it, keeping the x values as they are.
+///
+/// Near the ends, where the window doesn’t fit, the mean is taken over as many values as
+/// there are. A window of 0 or 1 leaves the points unchanged, and an even window takes one
+/// more value after each point than before it.
+///
+/// ```
+/// use drawille::chart;
+///
+/// let points = [(0.0, 0.0), (1.0, 3.0), (2.0, 0.0), (3.0, 3.0), (4.0, 0.0)];
+/// assert_eq!(chart::moving_average(&points, 3),
+///            [(0.0, 1.5), (1.0, 1.0), (2.0, 2.0), (3.0, 1.0), (4.0, 1.5)]);
+/// ```
+pub fn moving_average(points: &[(f64, f64)], window: usize) -> Vec<(f64, f64)> {
+    if window < 2 {
+        return points.to_vec();
+    }
+    let mut sums = Vec::with_capacity(points.len() + 1);
+    sums.push(0.0);
+    for (i, p) in points.iter().enumerate() {
+        sums.push(sums[i] + p.1);
+    }
+    let (before, after) = ((window - 1) / 2, window / 2);
+    points.iter().enumerate().map(|(i, &(x, _))| {
+        let (from, to) = (i.saturating_sub(before), cmp::min(i + after + 1, points.len()));
+        (x, (sums[to] - sums[from]) / (to - from) as f64)
+    }).collect()
+}
+
+/// A summary of the values of a series, as shown in the statistics box of a `Chart`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SeriesStats {
+    /// The lowest value.
+    pub min: f64,
+    /// The highest value.
+    pub max: f64,
+    /// The mean of the values.
+    pub mean: f64,
+    /// The value of the last point, such as the latest one pushed.
+    pub last: f64,
+    /// How many values there are.
+    pub count: usize,
+}
+
+/// The appearance a `Chart` gives to one of its series.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SeriesStyle {
+    /// The colour of the lines, markers and legend entry.
+    pub color: Color,
+    /// The pattern with which consecutive points are joined, if they are.
+    pub line: Option<LineStyle>,
+    /// The marker drawn at each point, if any.
+    pub marker: Option<Marker>,
+}
+
+/// The colours, markers and axis styles a `Chart` is drawn with.
+///
+/// Besides the default, which uses the eight basic terminal colours, there are palettes that
+/// stay distinguishable with the common kinds of colour blindness. The series of a chart are
+/// given the palette’s colours in order, and candlesticks are coloured as rising or falling.
+///
+/// ```
+/// use drawille::Style;
+/// use drawille::chart::{Chart, Series, Theme};
+///
+/// let theme = Theme::okabe_ito().grid(Style::new());
+/// let mut chart = Chart::new(80, 40).theme(theme);
+/// chart.add_series(Series::line("up", vec![(0.0, 0.0), (1.0, 1.0)]));
+/// chart.add_series(Series::line("down", vec![(0.0, 1.0), (1.0, 0.0)]));
+/// assert_eq!(chart.series_style(1).unwrap().color, Theme::okabe_ito().color(1));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    palette: Vec<Color>,
+    markers: Vec<Marker>,
+    axis: Style,
+    grid: Option<Style>,
+    rising: Color,
+    falling: Color,
+}
+
+impl Theme {
+    /// Creates the default `Theme`: the basic terminal colours, plain axes and no grid, with
+    /// rising candles green and falling ones red.
+    pub fn new() -> Theme {
+        Theme {
+            palette: PALETTE.to_vec(),
+            markers: MARKERS.to_vec(),
+            axis: Style::new(),
+            grid: None,
+            rising: Color::Green,
+            falling: Color::Red,
+        }
+    }
+
+    /// Creates a `Theme` with the palette of Okabe and Ito, designed to be told apart with
+    /// any common kind of colour blindness, in true colour.
+    pub fn okabe_ito() -> Theme {
+        Theme::new()
+            .palette(vec![Color::Rgb(230, 159, 0), Color::Rgb(86, 180, 233),
+                          Color::Rgb(0, 158, 115), Color::Rgb(240, 228, 66),
+                          Color::Rgb(0, 114, 178), Color::Rgb(213, 94, 0),
+                          Color::Rgb(204, 121, 167)])
+            .candles(Color::Rgb(0, 114, 178), Color::Rgb(213, 94, 0))
+    }
+
+    /// Creates a `Theme` with Paul Tol’s bright palette, which is safe for colour blindness
+    /// and reads well on dark backgrounds, in true colour.
+    pub fn tol_bright() -> Theme {
+        Theme::new()
+            .palette(vec![Color::Rgb(68, 119, 170), Color::Rgb(102, 204, 238),
+                          Color::Rgb(34, 136, 51), Color::Rgb(204, 187, 68),
+                          Color::Rgb(238, 102, 119), Color::Rgb(170, 51, 119),
+                          Color::Rgb(187, 187, 187)])
+            .candles(Color::Rgb(68, 119, 170), Color::Rgb(238, 102, 119))
+    }
+
+    /// Creates a `Theme` for terminals with only the basic colours, avoiding pairs of red and
+    /// green, which are the hardest to tell apart.
+    pub fn basic_safe() -> Theme {
+        Theme::new()
+            .palette(vec![Color::Blue, Color::Yellow, Color::Cyan, Color::Magenta,
+                          Color::White])
+            .candles(Color::Blue, Color::Yellow)
+    }
+
+    /// Sets the colours given to series that don’t choose their own, in order, and return it
+    /// for use again. An empty palette leaves the default one in place.
+    pub fn palette(mut self, palette: Vec<Color>) -> Theme {
+        if !palette.is_empty() {
+            self.palette = palette;
+        }
+        self
+    }
+
+    /// Sets the markers given to scatter series that don’t choose their own, in order, and
+    /// return it for use again. An empty list leaves the default one in place.
+    pub fn markers(mut self, markers: Vec<Marker>) -> Theme {
+        if !markers.is_empty() {
+            self.markers = markers;
+        }
+        self
+    }
+
+    /// Sets the style of the axes and their labels, and return it for use again.
+    pub fn axis(mut self, style: Style) -> Theme {
+        self.axis = style;
+        self
+    }
+
+    /// Draws dotted grid lines at the ticks of both axes, under the data, in the given style,
+    /// and return it for use again.
+    pub fn grid(mut self, style: Style) -> Theme {
+        self.grid = Some(style);
+        self
+    }
+
+    /// Sets the colours of rising and falling candlesticks that don’t choose their own, and
+    /// return it for use again.
+    pub fn candles(mut self, rising: Color, falling: Color) -> Theme {
+        self.rising = rising;
+        self.falling = falling;
+        self
+    }
+
+    /// Returns the colour the series at the given index is given, cycling through the
+    /// palette.
+    pub fn color(&self, index: usize) -> Color {
+        self.palette[index % self.palette.len()]
+    }
+
+    /// Returns the marker the scatter series at the given index is given, cycling through the
+    /// markers.
+    pub fn marker(&self, index: usize) -> Marker {
+        self.markers[index % self.markers.len()]
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme::new()
+    }
+}
+
+/// How data values are mapped onto an axis.
+#[derive(Clone)]
+pub enum Scale {
+    /// Equal differences between values are equally far apart.
+    Linear,
+    /// Equal ratios between values are equally far apart. Values that aren’t positive are left
+    /// out.
+    Log10,
+    /// Logarithmic away from zero in both directions, but linear within the given distance of
+    /// zero, so that zero and negative values can still be shown.
+    SymLog(f64),
+    /// Any monotonic function of the value. Ticks are placed at round values, as for `Linear`.
+    Custom(Arc<dyn Fn(f64) -> f64 + Send + Sync>),
+}
+
+impl Scale {
+    /// Creates a new `Custom` scale from a transform.
+    pub fn custom<F: Fn(f64) -> f64 + Send + Sync + 'static>(transform: F) -> Scale {
+        Scale::Custom(Arc::new(transform))
+    }
+
+    /// Applies the scale to a value, returning a non-finite number if it can’t be shown.
+    pub fn apply(&self, v: f64) -> f64 {
+        match *self {
+            Scale::Linear => v,
+            Scale::Log10 => v.log10(),
+            Scale::SymLog(threshold) => v.signum() * (1.0 + v.abs() / threshold).log10(),
+            Scale::Custom(ref transform) => transform(v),
+        }
+    }
+
+    /// Picks about `count` ticks within a range of values.
+    fn ticks(&self, (min, max): (f64, f64), count: usize) -> Vec<f64> {
+        let decades = |lo: f64, hi: f64| {
+            // Without a positive range there are no decades, and ticks fall back to linear.
+            if !(lo > 0.0 && hi >= lo && hi.is_finite()) {
+                return Vec::new();
+            }
+            let (lo, hi) = (lo.log10().ceil() as i32, hi.log10().floor() as i32);
+            let step = cmp::max(1, (hi - lo + 1) / cmp::max(1, count as i32));
+            (lo..=hi).step_by(step as usize).map(|k| 10f64.powi(k)).collect::<Vec<_>>()
+        };
+        let ticks = match *self {
+            Scale::Linear | Scale::Custom(_) => return nice_ticks((min, max), count),
+            Scale::Log10 if count > 0 => decades(min, max),
+            Scale::SymLog(threshold) if count > 0 => {
+                let lo = threshold.max(f64::MIN_POSITIVE);
+                let mut ticks: Vec<f64> = decades(lo, -min).into_iter().rev().map(|v| -v).collect();
+                if min <= 0.0 && max >= 0.0 {
+                    ticks.push(0.0);
+                }
+                ticks.extend(decades(lo, max));
+                ticks
+            }
+            _ => Vec::new(),
+        };
+        if ticks.len() < 2 {
+            nice_ticks((min, max), count)
+        } else {
+            ticks
+        }
+    }
+
+    /// Formats a tick value among the given ticks.
+    fn format(&self, v: f64, ticks: &[f64]) -> String {
+        match *self {
+            Scale::Log10 | Scale::SymLog(_) if ticks.iter().all(|t| is_decade(*t)) => {
+                let exponent = v.abs().log10().round() as i32;
+                match exponent {
+                    _ if v == 0.0 => "0".to_string(),
+                    -3..=3 => format!("{:.*}", cmp::max(0, -exponent) as usize, v),
+                    _ => format!("{}1e{}", if v < 0.0 { "-" } else { "" }, exponent),
+                }
+            }
+            _ => format_tick(v, ticks),
+        }
+    }
+}
+
+impl fmt::Debug for Scale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Scale::Linear => f.write_str("Linear"),
+            Scale::Log10 => f.write_str("Log10"),
+            Scale::SymLog(threshold) => f.debug_tuple("SymLog").field(&threshold).finish(),
+            Scale::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Custom scales are only equal if they share the same transform.
+impl PartialEq for Scale {
+    fn eq(&self, other: &Scale) -> bool {
+        match (self, other) {
+            (Scale::Linear, Scale::Linear) | (Scale::Log10, Scale::Log10) => true,
+            (Scale::SymLog(a), Scale::SymLog(b)) => a == b,
+            (Scale::Custom(a), Scale::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// How the labels of the ticks on an axis are written.
+#[derive(Clone)]
+pub enum TickFormat {
+    /// Plain numbers with just enough decimals to tell neighbouring ticks apart, or powers of
+    /// ten on a logarithmic scale.
+    Auto,
+    /// Numbers with an SI prefix and up to three significant digits, like `1.2k` or `3.4M`.
+    Si,
+    /// Fractions as percentages, so that 0.25 is written as `25%`.
+    Percent,
+    /// Seconds as a duration, like `250ms`, `45s`, `1m30s` or `2h`.
+    Duration,
+    /// Any function of the value.
+    Custom(Arc<dyn Fn(f64) -> String + Send + Sync>),
+}
+
+impl TickFormat {
+    /// Creates a new `Custom` format from a function.
+    ///
+    /// ```
+    /// use drawille::chart::{Axis, Chart, Series, TickFormat};
+    ///
+    /// let prices = vec![(0.0, 10.0), (1.0, 30.0)];
+    /// let mut chart = Chart::new(80, 40)
+    ///     .y_axis(Axis::new().format(TickFormat::custom(|v| format!("${}", v))));
+    /// chart.add_series(Series::line("price", prices));
+    /// assert!(chart.frame().contains("$30"));
+    /// ```
+    pub fn custom<F: Fn(f64) -> String + Send + Sync + 'static>(format: F) -> TickFormat {
+        TickFormat::Custom(Arc::new(format))
+    }
+
+    /// Writes a tick value among the given ticks, as an axis with this format and scale would.
+    fn label(&self, v: f64, ticks: &[f64], scale: &Scale) -> String {
+        match *self {
+            TickFormat::Auto => scale.format(v, ticks),
+            TickFormat::Si => format_si(v),
+            TickFormat::Percent => {
+                let percents: Vec<f64> = ticks.iter().map(|t| t * 100.0).collect();
+                format!("{}%", format_tick(v * 100.0, &percents))
+            }
+            TickFormat::Duration => format_duration(v),
+            TickFormat::Custom(ref format) => format(v),
+        }
+    }
+}
+
+impl fmt::Debug for TickFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TickFormat::Auto => f.write_str("Auto"),
+            TickFormat::Si => f.write_str("Si"),
+            TickFormat::Percent => f.write_str("Percent"),
+            TickFormat::Duration => f.write_str("Duration"),
+            TickFormat::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Custom formats are only equal if they share the same function.
+impl PartialEq for TickFormat {
+    fn eq(&self, other: &TickFormat) -> bool {
+        match (self, other) {
+            (TickFormat::Custom(a), TickFormat::Custom(b)) => Arc::ptr_eq(a, b),
+            (TickFormat::Custom(_), _) | (_, TickFormat::Custom(_)) => false,
+            _ => ::std::mem::discriminant(self) == ::std::mem::discriminant(other),
+        }
+    }
+}
+
+/// How an `Axis` picks its range from the data on every render.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Autoscale {
+    /// Always shows the same range, as set by `Axis::range`.
+    Fixed(f64, f64),
+    /// Fits the range exactly to the data, the default. A streaming chart rescales on every
+    /// frame that a new point changes the lowest or highest value.
+    Fit,
+    /// Fits the range to the data with some headroom, and keeps it until the data outgrows it
+    /// or shrinks well within it, so that a streaming chart doesn’t rescale every frame. The
+    /// range is only moved on by `Chart::update_scale`.
+    ///
+    /// When the range changes, `margin` times the span of the data is added on each side. The
+    /// range then shrinks again only once it is more than `1 + deadband` times as wide as the
+    /// data with its margins would need.
+    Hysteresis {
+        /// The headroom added on each side, as a fraction of the span of the data.
+        margin: f64,
+        /// How much wider than needed the range may get before it shrinks, as a fraction.
+        deadband: f64,
+    },
+}
+
+/// The configuration of one of the axes of a `Chart`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Axis {
+    autoscale: Autoscale,
+    /// The range last picked by `Chart::update_scale`, for `Autoscale::Hysteresis`.
+    memory: Option<(f64, f64)>,
+    ticks: usize,
+    scale: Scale,
+    time: bool,
+    format: TickFormat,
+}
+
+impl Axis {
+    /// Creates a new linear `Axis` that fits the data, with about five labelled ticks.
+    pub fn new() -> Axis {
+        Axis {
+            autoscale: Autoscale::Fit,
+            memory: None,
+            ticks: 5,
+            scale: Scale::Linear,
+            time: false,
+            format: TickFormat::Auto,
+        }
+    }
+
+    /// Fixes the range of an `Axis` instead of fitting it to the data, and return it for use
+    /// again.
+    pub fn range(self, min: f64, max: f64) -> Axis {
+        self.autoscale(Autoscale::Fixed(min, max))
+    }
+
+    /// Sets how an `Axis` picks its range from the data, and return it for use again.
+    ///
+    /// A hysteresis keeps the range picked by the last call to `Chart::update_scale`, and
+    /// until the first one fits the data with its headroom. Any range it has picked is
+    /// forgotten when the `Autoscale` is set again.
+    ///
+    /// ```
+    /// use drawille::chart::{Autoscale, Axis, Chart, Series};
+    ///
+    /// let y = Axis::new().autoscale(Autoscale::Hysteresis { margin: 0.25, deadband: 0.5 });
+    /// let mut chart = Chart::new(80, 40).y_axis(y);
+    /// chart.add_series(Series::line("load", vec![(0.0, 0.0), (1.0, 10.0)]));
+    /// chart.update_scale();
+    /// let height = |chart: &Chart| chart.data_to_pixel(1.0, 10.0).unwrap().1;
+    /// let before = height(&chart);
+    /// // A new point within the headroom leaves the scale where it was.
+    /// chart.series_mut()[0].push(2.0, 12.0);
+    /// chart.update_scale();
+    /// assert_eq!(height(&chart), before);
+    /// // One beyond it rescales.
+    /// chart.series_mut()[0].push(3.0, 20.0);
+    /// chart.update_scale();
+    /// assert_ne!(height(&chart), before);
+    /// ```
+    pub fn autoscale(mut self, autoscale: Autoscale) -> Axis {
+        self.autoscale = autoscale;
+        self.memory = None;
+        self
+    }
+
+    /// Sets roughly how many labelled ticks an `Axis` has, and return it for use again.
+    ///
+    /// Ticks are placed at round numbers, so the actual number may differ slightly. Zero hides
+    /// the labels altogether.
+    pub fn ticks(mut self, ticks: usize) -> Axis {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Sets how values are mapped onto an `Axis`, and return it for use again.
+    ///
+    /// ```
+    /// use drawille::chart::{Axis, Chart, Scale, Series};
+    ///
+    /// let latencies = vec![(1.0, 0.2), (2.0, 3.5), (3.0, 40.0), (4.0, 900.0)];
+    /// let mut chart = Chart::new(80, 40).y_axis(Axis::new().scale(Scale::Log10));
+    /// chart.add_series(Series::line("p99", latencies));
+    /// assert!(chart.frame().contains("100"));
+    /// ```
+    pub fn scale(mut self, scale: Scale) -> Axis {
+        self.scale = scale;
+        self
+    }
+
+    /// Treats the values on an `Axis` as Unix timestamps in seconds, and return it for use again.
+    ///
+    /// Ticks are then placed at round times in UTC, and labelled with the time of day or the
+    /// date depending on how far apart they are.
+    ///
+    /// ```
+    /// use drawille::chart::{Axis, Chart, Series};
+    ///
+    /// let load = (0..60).map(|i| (1_700_000_000.0 + i as f64 * 60.0, (i % 7) as f64)).collect();
+    /// let mut chart = Chart::new(120, 40).x_axis(Axis::new().time(true));
+    /// chart.add_series(Series::line("load", load));
+    /// assert!(chart.frame().contains("22:30"));
+    /// ```
+    pub fn time(mut self, time: bool) -> Axis {
+        self.time = time;
+        self
+    }
+
+    /// Sets how the tick labels of an `Axis` are written, and return it for use again.
+    ///
+    /// This doesn’t apply to a time axis, whose labels are always times or dates.
+    ///
+    /// ```
+    /// use drawille::chart::{Axis, Chart, Series, TickFormat};
+    ///
+    /// let traffic = vec![(0.0, 0.0), (1.0, 2_500_000.0)];
+    /// let mut chart = Chart::new(80, 40).y_axis(Axis::new().format(TickFormat::Si));
+    /// chart.add_series(Series::line("bytes", traffic));
+    /// assert!(chart.frame().contains("2.5M"));
+    /// ```
+    pub fn format(mut self, format: TickFormat) -> Axis {
+        self.format = format;
+        self
+    }
+
+    /// Picks the range of the axis: the fixed one if there is one, otherwise one spanning all
+    /// the values given that the scale can show. Returns it both as values and scaled.
+    fn fit<I: Iterator<Item = f64>>(&self, values: I) -> ((f64, f64), (f64, f64)) {
+        let range = self.pick(values);
+        let scaled = (self.scale.apply(range.0), self.scale.apply(range.1));
+        if scaled.0.is_finite() && scaled.1.is_finite() && scaled.0 != scaled.1 {
+            (range, scaled)
+        } else {
+            ((0.0, 1.0), (0.0, 1.0))
+        }
+    }
+
+    /// Picks the range of the axis in values, reusing the remembered one of a hysteresis where
+    /// the data still fits it.
+    fn pick<I: Iterator<Item = f64>>(&self, values: I) -> (f64, f64) {
+        let scale = &self.scale;
+        let fixed = match self.autoscale {
+            Autoscale::Fixed(min, max) => Some((min, max)),
+            _ => None,
+        };
+        let mut range = fit(fixed, values.filter(|&v| scale.apply(v).is_finite()));
+        if let Autoscale::Hysteresis { margin, deadband } = self.autoscale {
+            let pad = (range.1 - range.0) * margin.max(0.0);
+            // Headroom that would reach values the scale can’t show, such as below zero on a
+            // logarithmic axis, is left out.
+            let padded = (
+                if scale.apply(range.0 - pad).is_finite() { range.0 - pad } else { range.0 },
+                if scale.apply(range.1 + pad).is_finite() { range.1 + pad } else { range.1 },
+            );
+            range = match self.memory {
+                Some(last) if last.0 <= range.0 && last.1 >= range.1
+                    && last.1 - last.0 <= (padded.1 - padded.0) * (1.0 + deadband.max(0.0)) => last,
+                _ => padded,
+            };
+        }
+        range
+    }
+
+    /// Keeps a range picked by `pick` for the next ones, if the axis has a hysteresis.
+    fn remember(&mut self, range: (f64, f64)) {
+        if let Autoscale::Hysteresis { .. } = self.autoscale {
+            self.memory = Some(range);
+        }
+    }
+
+    /// Returns the positions of the labelled ticks within a range, and their labels.
+    fn labels(&self, range: (f64, f64)) -> Vec<(f64, String)> {
+        if self.time {
+            let (ticks, step) = time_ticks(range, self.ticks);
+            return ticks.into_iter().map(|t| (t, format_time(t, step))).collect();
+        }
+        let ticks = self.scale.ticks(range, self.ticks);
+        ticks.iter().map(|&v| (v, self.format.label(v, &ticks, &self.scale))).collect()
+    }
+
+    /// Writes a value anywhere on the axis as its ticks are written, with one more decimal
+    /// than they have.
+    fn value_label(&self, v: f64, range: (f64, f64)) -> String {
+        if self.time {
+            return format_time(v, 1.0);
+        }
+        let ticks = nice_ticks(range, self.ticks);
+        let step = if ticks.len() > 1 { ticks[1] - ticks[0] } else { (range.1 - range.0).abs() };
+        self.format.label(v, &[v, v + step / 10.0], &Scale::Linear)
+    }
+}
+
+impl Default for Axis {
+    fn default() -> Axis {
+        Axis::new()
+    }
+}
+
+/// A chart of one or more data series, with axes and a legend.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Chart {
+    width: u32,
+    height: u32,
+    series: Vec<Series>,
+    x_axis: Axis,
+    y_axis: Axis,
+    legend: bool,
+    stats: bool,
+    theme: Theme,
+}
+
+/// The lower and upper polylines between which a series is shaded.
+type Bounds = (Vec<(f64, f64)>, Vec<(f64, f64)>);
+
+/// The pixel rectangle inside the axes that data is drawn in, and the scaled data ranges mapped
+/// onto it.
+#[derive(Clone, Debug)]
+struct Plot<'a> {
+    left: f64,
+    top: f64,
+    right: f64,
+    bottom: f64,
+    x: (f64, f64),
+    y: (f64, f64),
+    x_scale: &'a Scale,
+    y_scale: &'a Scale,
+}
+
+impl<'a> Plot<'a> {
+    /// Maps a data point to pixel coordinates, which aren’t finite if it can’t be shown.
+    fn project(&self, x: f64, y: f64) -> (f64, f64) {
+        let fx = (self.x_scale.apply(x) - self.x.0) / (self.x.1 - self.x.0);
+        let fy = (self.y_scale.apply(y) - self.y.0) / (self.y.1 - self.y.0);
+        (self.left + fx * (self.right - self.left), self.bottom - fy * (self.bottom - self.top))
+    }
+
+    fn contains(&self, px: f64, py: f64) -> bool {
+        px >= self.left && px <= self.right && py >= self.top && py <= self.bottom
+    }
+}
+
+/// Where the parts of a rendered `Chart` go: the plot, the axes and the labels of the y axis.
+struct Layout<'a> {
+    plot: Plot<'a>,
+    bounds: Vec<Option<Bounds>>,
+    columns: usize,
+    x_range: (f64, f64),
+    y_range: (f64, f64),
+    y_labels: Vec<(f64, String)>,
+    label_columns: usize,
+    axis_x: u32,
+    axis_y: u32,
+    right: u32,
+}
+
+/// The data point of a `Chart` closest to a pixel, as found by `Chart::nearest_point`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NearestPoint {
+    /// The index of the series the point belongs to.
+    pub series: usize,
+    /// The index of the point within its series, or of the candle for a candlestick series.
+    pub index: usize,
+    /// The point itself, or the time and closing price of a candle.
+    pub value: (f64, f64),
+    /// Where the point is drawn, in pixels.
+    pub pixel: (f64, f64),
+}
+
+impl Chart {
+    /// Creates a new, empty `Chart` of the given size in pixels.
+    pub fn new(width: u32, height: u32) -> Chart {
+        Chart {
+            width,
+            height,
+            series: Vec::new(),
+            x_axis: Axis::new(),
+            y_axis: Axis::new(),
+            legend: true,
+            stats: false,
+            theme: Theme::new(),
+        }
+    }
+
+    /// Configures the x axis of a `Chart`, and return it for use again.
+    pub fn x_axis(mut self, axis: Axis) -> Chart {
+        self.x_axis = axis;
+        self
+    }
+
+    /// Configures the y axis of a `Chart`, and return it for use again.
+    pub fn y_axis(mut self, axis: Axis) -> Chart {
+        self.y_axis = axis;
+        self
+    }
+
+    /// Shows or hides the legend of a `Chart`, and return it for use again.
+    ///
+    /// The legend is shown by default.
+    pub fn legend(mut self, legend: bool) -> Chart {
+        self.legend = legend;
+        self
+    }
+
+    /// Shows or hides a box of the lowest, highest, mean and last values of each series, and
+    /// return it for use again.
+    ///
+    /// The box sits in the top-right corner, under the legend, with a line for each series in
+    /// its colour. Its values are worked out again on every render, so they follow data pushed
+    /// onto the series, and are written as the labels of the y axis are. Candlestick series
+    /// give the statistics of their closing prices. The box is hidden by default.
+    ///
+    /// ```
+    /// use drawille::chart::{Chart, Series};
+    ///
+    /// let mut chart = Chart::new(120, 40).stats(true);
+    /// chart.add_series(Series::line("cpu", vec![(0.0, 20.0), (1.0, 80.0), (2.0, 50.0)]));
+    /// chart.series_mut()[0].push(3.0, 30.0);
+    /// assert!(chart.frame().contains("cpu min 20 max 80 avg 45 last 30"));
+    /// ```
+    pub fn stats(mut self, stats: bool) -> Chart {
+        self.stats = stats;
+        self
+    }
+
+    /// Sets the colours, markers and axis styles of a `Chart`, and return it for use again.
+    pub fn theme(mut self, theme: Theme) -> Chart {
+        self.theme = theme;
+        self
+    }
+
+    /// Adds a series to the `Chart`.
+    pub fn add_series(&mut self, series: Series) {
+        self.series.push(series);
+    }
+
+    /// Returns the series of the `Chart`, in the order they were added.
+    pub fn series(&self) -> &[Series] {
+        &self.series
+    }
+
+    /// Returns the series of the `Chart` mutably, for example to stream more data into them.
+    pub fn series_mut(&mut self) -> &mut [Series] {
+        &mut self.series
+    }
+
+    /// Returns the appearance of the series at the given index, or `None` if there isn’t one.
+    ///
+    /// Colours are assigned in order from the palette of the `Theme`. Once it runs out, line
+    /// series switch to dashed and then dotted lines, and scatter series cycle through the
+    /// markers, so that every series stays distinguishable. Anything set on the `Series` itself
+    /// takes precedence.
+    pub fn series_style(&self, index: usize) -> Option<SeriesStyle> {
+        let series = self.series.get(index)?;
+        let round = index / self.theme.palette.len();
+        let auto_line = match round % 3 {
+            0 => LineStyle::solid(),
+            1 => LineStyle::dashed(),
+            _ => LineStyle::dotted(),
+        };
+        let (line, marker) = match series.kind {
+            Kind::Line => (Some(series.line.unwrap_or(auto_line)), series.marker),
+            Kind::Scatter => {
+                (series.line, Some(series.marker.unwrap_or(self.theme.marker(index))))
+            }
+            Kind::Area => (Some(series.line.unwrap_or(auto_line)), series.marker),
+            Kind::Candlestick | Kind::BoxPlot | Kind::Band => (series.line, series.marker),
+        };
+        Some(SeriesStyle {
+            color: series.color.unwrap_or(self.theme.color(index)),
+            line,
+            marker,
+        })
+    }
+
+    /// Moves the ranges of axes with `Autoscale::Hysteresis` on to fit the data, such as after
+    /// pushing new points onto a streaming chart and before rendering it.
+    ///
+    /// Rendering and queries such as `data_to_pixel` never change the scale themselves, so
+    /// they all agree until this is called again.
+    pub fn update_scale(&mut self) {
+        let (x, y) = {
+            let bounds = self.bounds();
+            let stacked = bounds.iter().flatten().flat_map(|b| b.0.iter().chain(&b.1).cloned());
+            let points = || self.series.iter().flat_map(Series::extent).chain(stacked.clone());
+            (self.x_axis.pick(points().map(|p| p.0)), self.y_axis.pick(points().map(|p| p.1)))
+        };
+        self.x_axis.remember(x);
+        self.y_axis.remember(y);
+    }
+
+    /// Works out where everything goes, or returns `None` if the `Chart` is too small to draw
+    /// anything but a blank `Canvas`.
+    fn layout(&self) -> Option<Layout<'_>> {
+        let (columns, rows) = ((self.width / 2) as usize, (self.height / 4) as usize);
+        if columns < 2 || rows < 2 {
+            return None;
+        }
+        let bounds = self.bounds();
+        let stacked = bounds.iter().flatten().flat_map(|b| b.0.iter().chain(&b.1).cloned());
+        let points = || self.series.iter().flat_map(Series::extent).chain(stacked.clone());
+        let (x_range, x_scaled) = self.x_axis.fit(points().map(|p| p.0));
+        let (y_range, y_scaled) = self.y_axis.fit(points().map(|p| p.1));
+
+        let y_labels = self.y_axis.labels(y_range);
+        let label_columns = y_labels.iter().map(|l| l.1.chars().count()).max().unwrap_or(0);
+        let label_columns = cmp::min(label_columns, columns - 1);
+
+        let axis_x = label_columns as u32 * 2;
+        let axis_y = (rows as u32 - 1) * 4 - 1;
+        let right = self.width.saturating_sub(1).max(axis_x + 1);
+        let plot = Plot {
+            left: axis_x as f64 + 1.0,
+            top: 0.0,
+            right: right as f64,
+            bottom: axis_y as f64 - 1.0,
+            x: x_scaled,
+            y: y_scaled,
+            x_scale: &self.x_axis.scale,
+            y_scale: &self.y_axis.scale,
+        };
+        Some(Layout {
+            plot, bounds, columns, x_range, y_range, y_labels, label_columns, axis_x, axis_y, right,
+        })
+    }
+
+    /// Renders the `Chart` onto a new `Canvas`.
+    pub fn render(&self) -> Canvas {
+        let mut canvas = Canvas::new(self.width, self.height);
+        let layout = match self.layout() {
+            Some(layout) => layout,
+            None => return canvas,
+        };
+        let Layout {
+            plot, bounds, columns, x_range, y_range, y_labels, label_columns, axis_x, axis_y, right,
+        } = layout;
+
+        let x_labels = self.x_axis.labels(x_range);
+        if let Some(style) = self.theme.grid {
+            let grid = LineStyle::dotted().style(style);
+            for &(v, _) in &y_labels {
+                let (_, py) = plot.project(x_range.0, v);
+                if py >= plot.top && py < plot.bottom {
+                    canvas.line_styled(axis_x + 2, py.round() as u32, right, py.round() as u32,
+                                       &grid);
+                }
+            }
+            for &(v, _) in &x_labels {
+                let (px, _) = plot.project(v, y_range.0);
+                if px > plot.left && px <= plot.right {
+                    canvas.line_styled(px.round() as u32, 0, px.round() as u32, axis_y - 2, &grid);
+                }
+            }
+        }
+
+        for (i, series) in self.series.iter().enumerate() {
+            if let Some(style) = self.series_style(i) {
+                draw_series(&mut canvas, &plot, series, &style, bounds[i].as_ref(), &self.theme);
+            }
+        }
+
+        let axis = LineStyle::solid().style(self.theme.axis);
+        canvas.line_styled(axis_x, 0, axis_x, axis_y, &axis);
+        canvas.line_styled(axis_x, axis_y, right, axis_y, &axis);
+        let mut used_rows = Vec::new();
+        for &(v, ref label) in &y_labels {
+            let (_, py) = plot.project(x_range.0, v);
+            let len = label.chars().count();
+            let row = py.round() as u32 / 4;
+            if py >= 0.0 && len <= label_columns && !used_rows.contains(&row) {
+                canvas.text_styled(((label_columns - len) * 2) as u32, py.round() as u32,
+                                   u32::MAX, &[Span::new(label, self.theme.axis)]);
+                used_rows.push(row);
+            }
+        }
+        let mut free_from = 0;
+        for (v, label) in x_labels {
+            let (px, _) = plot.project(v, y_range.0);
+            let len = label.chars().count();
+            let start = (px.round() as usize / 2).saturating_sub(len / 2);
+            if start >= free_from && start + len <= columns {
+                canvas.text_styled(start as u32 * 2, axis_y + 1, u32::MAX,
+                                   &[Span::new(&label, self.theme.axis)]);
+                free_from = start + len + 1;
+            }
+        }
+
+        let legend_rows = if self.legend { self.draw_legend(&mut canvas, columns) } else { 0 };
+        if self.stats {
+            self.draw_stats(&mut canvas, columns, legend_rows, y_range);
+        }
+        canvas
+    }
+
+    /// Renders the `Chart` to a `String` and returns it.
+    pub fn frame(&self) -> String {
+        self.render().frame()
+    }
+
+    /// Returns where `render` would draw a data point, in pixels, or `None` if it falls
+    /// outside the plot or the `Chart` is too small to have one.
+    pub fn data_to_pixel(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let layout = self.layout()?;
+        let (px, py) = layout.plot.project(x, y);
+        if layout.plot.contains(px, py) { Some((px, py)) } else { None }
+    }
+
+    /// Finds the data point drawn closest to a pixel, such as the one under the mouse.
+    ///
+    /// Distances are measured in pixels, with a cell taken to be twice as tall as it is wide,
+    /// so a mouse position in cells is `(column * 2, row * 4)` in pixels. Points outside the
+    /// plot are skipped, as are the lower edges of bands. Areas are looked up where they are
+    /// drawn, stacked on top of each other.
+    ///
+    /// ```
+    /// use drawille::chart::{Chart, Series};
+    ///
+    /// let mut chart = Chart::new(80, 40);
+    /// chart.add_series(Series::line("a", vec![(0.0, 0.0), (5.0, 10.0), (10.0, 0.0)]));
+    /// let (px, py) = chart.data_to_pixel(5.0, 10.0).unwrap();
+    /// let nearest = chart.nearest_point(px + 3.0, py + 2.0).unwrap();
+    /// assert_eq!((nearest.series, nearest.index, nearest.value), (0, 1, (5.0, 10.0)));
+    /// ```
+    pub fn nearest_point(&self, px: f64, py: f64) -> Option<NearestPoint> {
+        let layout = self.layout()?;
+        let mut nearest: Option<(f64, NearestPoint)> = None;
+        for (i, series) in self.series.iter().enumerate() {
+            let drawn: Vec<(f64, f64)> = match (series.kind, &layout.bounds[i]) {
+                (Kind::Candlestick, _) => series.candles.iter().map(|c| (c.x, c.close)).collect(),
+                (Kind::BoxPlot, _) => series.boxes.iter().map(|b| (b.x, b.median)).collect(),
+                (Kind::Area, &Some((_, ref upper))) => upper.clone(),
+                _ => series.points.clone(),
+            };
+            for (j, &(x, y)) in drawn.iter().enumerate() {
+                let pixel = layout.plot.project(x, y);
+                if !layout.plot.contains(pixel.0, pixel.1) {
+                    continue;
+                }
+                let distance = (pixel.0 - px).hypot(pixel.1 - py);
+                if nearest.as_ref().map_or(true, |n| distance < n.0) {
+                    let value = match series.kind {
+                        Kind::Candlestick | Kind::BoxPlot => (x, y),
+                        _ => series.points[j],
+                    };
+                    nearest = Some((distance, NearestPoint { series: i, index: j, value, pixel }));
+                }
+            }
+        }
+        nearest.map(|n| n.1)
+    }
+
+    /// Renders the `Chart` with a crosshair on the data point closest to a pixel, as found by
+    /// `nearest_point`, and the point’s series and value written in the top-left corner.
+    ///
+    /// This is the usual way of inspecting a chart with the mouse: render it with the mouse
+    /// position every time the mouse moves. Without any data, the plain chart is rendered.
+    pub fn render_crosshair(&self, px: f64, py: f64) -> Canvas {
+        let mut canvas = self.render();
+        let (layout, nearest) = match (self.layout(), self.nearest_point(px, py)) {
+            (Some(layout), Some(nearest)) => (layout, nearest),
+            _ => return canvas,
+        };
+        let series = &self.series[nearest.series];
+        let color = match series.kind {
+            Kind::Candlestick if series.candles[nearest.index].is_rising() => {
+                series.color.unwrap_or(self.theme.rising)
+            }
+            Kind::Candlestick => series.color.unwrap_or(self.theme.falling),
+            _ => self.series_style(nearest.series).map_or(self.theme.color(0), |s| s.color),
+        };
+        let style = Style::new().fg(color);
+        let line = LineStyle::dotted().style(style);
+        let (x, y) = (nearest.pixel.0.round() as u32, nearest.pixel.1.round() as u32);
+        canvas.line_styled(layout.plot.left as u32, y, layout.right, y, &line);
+        canvas.line_styled(x, layout.plot.top as u32, x, layout.plot.bottom as u32, &line);
+
+        let label = format!("{}: {}, {}", series.name,
+                            self.x_axis.value_label(nearest.value.0, layout.x_range),
+                            self.y_axis.value_label(nearest.value.1, layout.y_range));
+        canvas.overlay_text_styled(layout.axis_x + 2, 0, u32::MAX, &[Span::new(&label, style)]);
+        canvas
+    }
+
+    /// Returns the lower and upper bounds of the shaded region of each area and band series,
+    /// stacking areas on top of each other.
+    fn bounds(&self) -> Vec<Option<Bounds>> {
+        let mut base: Vec<(f64, f64)> = Vec::new();
+        self.series.iter().map(|series| match series.kind {
+            Kind::Area => {
+                let lower = if base.is_empty() {
+                    series.points.iter().map(|&(x, _)| (x, 0.0)).collect()
+                } else {
+                    base.clone()
+                };
+                let upper: Vec<_> = series.points.iter()
+                    .map(|&(x, y)| (x, y + interpolate(&base, x)))
+                    .collect();
+                base = upper.clone();
+                Some((lower, upper))
+            }
+            Kind::Band => Some((series.lower.clone(), series.points.clone())),
+            _ => None,
+        }).collect()
+    }
+
+    /// Draws the legend onto the overlay, in the top-right corner, and returns how many rows it
+    /// takes up.
+    fn draw_legend(&self, canvas: &mut Canvas, columns: usize) -> usize {
+        let entries: Vec<(String, Style)> = (0..self.series.len()).filter_map(|i| {
+            let series = &self.series[i];
+            if series.kind == Kind::Candlestick {
+                let color = series.color.unwrap_or(self.theme.rising);
+                return Some((format!("▯▮ {}", series.name), Style::new().fg(color)));
+            }
+            let style = self.series_style(i)?;
+            if series.kind == Kind::BoxPlot {
+                return Some((format!("├┤ {}", series.name), Style::new().fg(style.color)));
+            }
+            let line = style.line.map(|l| legend_char(&l)).unwrap_or(' ');
+            let mark = style.marker.map(Marker::symbol).unwrap_or(line);
+            let text = match series.shading() {
+                Some(fill) => format!("{0}{0} {1}", fill.symbol(), series.name),
+                None => format!("{}{} {}", line, mark, series.name),
+            };
+            Some((text, Style::new().fg(style.color)))
+        }).collect();
+        let width = entries.iter().map(|e| e.0.chars().count()).max().unwrap_or(0);
+        let left = columns.saturating_sub(width) as u32 * 2;
+        for (row, (text, style)) in entries.iter().enumerate() {
+            canvas.overlay_text_styled(left, row as u32 * 4, u32::MAX, &[Span::new(text, *style)]);
+        }
+        entries.len()
+    }
+
+    /// Draws the statistics of every series onto the overlay, right-aligned from the given row.
+    fn draw_stats(&self, canvas: &mut Canvas, columns: usize, top: usize, y_range: (f64, f64)) {
+        let label = |v: f64| self.y_axis.value_label(v, y_range);
+        let entries: Vec<(String, Style)> = self.series.iter().enumerate().filter_map(|(i, s)| {
+            let stats = s.stats()?;
+            let color = match s.kind {
+                Kind::Candlestick => s.color.unwrap_or(self.theme.rising),
+                _ => self.series_style(i)?.color,
+            };
+            let text = format!("{} min {} max {} avg {} last {}", s.name, label(stats.min),
+                               label(stats.max), label(stats.mean), label(stats.last));
+            Some((text, Style::new().fg(color)))
+        }).collect();
+        // Pad every line to the same width, so the box hides the plot behind it evenly.
+        let width = entries.iter().map(|e| e.0.chars().count()).max().unwrap_or(0);
+        let left = columns.saturating_sub(width) as u32 * 2;
+        for (row, (text, style)) in entries.iter().enumerate() {
+            let text = format!("{:>1$}", text, width);
+            canvas.overlay_text_styled(left, (top + row) as u32 * 4, u32::MAX,
+                                       &[Span::new(&text, *style)]);
+        }
+    }
+}
+
+/// Draws a series within a plot, given the bounds of its shaded region if it has one.
+fn draw_series(canvas: &mut Canvas, plot: &Plot, series: &Series, style: &SeriesStyle,
+               bounds: Option<&Bounds>, theme: &Theme) {
+    if series.kind == Kind::Candlestick {
+        return draw_candles(canvas, plot, series, theme);
+    }
+    if series.kind == Kind::BoxPlot {
+        return draw_boxes(canvas, plot, series, style);
+    }
+    let cell_style = Style::new().fg(style.color);
+    let mut points = &series.points;
+    if let (Some((lower, upper)), Some(fill)) = (bounds, series.shading()) {
+        fill_between(canvas, plot, lower, upper, fill, cell_style);
+        points = upper;
+    }
+    if !series.confidence.is_empty() {
+        let (lower, upper): (Vec<_>, Vec<_>) = series.points.iter().zip(&series.confidence)
+            .map(|(&(x, y), &(below, above))| ((x, y - below), (x, y + above)))
+            .unzip();
+        let fill = series.fill.unwrap_or(Fill::Checker);
+        fill_between(canvas, plot, &lower, &upper, fill, cell_style);
+    }
+    for (&(x, y), &(below, above)) in series.points.iter().zip(&series.error_bars) {
+        let (px, top) = plot.project(x, y + above);
+        let (_, bottom) = plot.project(x, y - below);
+        let (px, top, bottom) = (px.round(), top.round(), bottom.round());
+        if !(px.is_finite() && top.is_finite() && bottom.is_finite()) {
+            continue;
+        }
+        let mut set = |px: f64, py: f64| {
+            if plot.contains(px, py) {
+                canvas.set_styled(px as u32, py as u32, cell_style);
+            }
+        };
+        let mut py = top.min(bottom).max(plot.top);
+        while py <= top.max(bottom).min(plot.bottom) {
+            set(px, py);
+            py += 1.0;
+        }
+        for &dx in &[-1.0, 1.0] {
+            set(px + dx, top);
+            set(px + dx, bottom);
+        }
+    }
+    let columns = (plot.right - plot.left).round() as usize + 1;
+    let thinned;
+    if series.shading().is_none() && points.len() > columns * 2 {
+        thinned = match series.downsample {
+            Some(Downsample::Lttb) => Some(lttb(points, columns)),
+            Some(Downsample::MinMax) => {
+                Some(min_max_by(points, |x| plot.project(x, 0.0).0.floor() as i64))
+            }
+            None => None,
+        };
+        if let Some(ref thinned) = thinned {
+            points = thinned;
+        }
+    }
+    if let Some(line) = style.line {
+        let line = line.style(cell_style);
+        let mut offset = 0;
+        for pair in points.windows(2) {
+            let p1 = plot.project(pair[0].0, pair[0].1);
+            let p2 = plot.project(pair[1].0, pair[1].1);
+            let (w, h) = (plot.right - plot.left, plot.bottom - plot.top);
+            let p1 = (p1.0 - plot.left, p1.1 - plot.top);
+            let p2 = (p2.0 - plot.left, p2.1 - plot.top);
+            if !(p1.0.is_finite() && p1.1.is_finite() && p2.0.is_finite() && p2.1.is_finite()) {
+                continue;
+            }
+            if let Some(((x1, y1), (x2, y2))) = clip(p1, p2, w, h) {
+                let (x1, y1) = ((x1 + plot.left).round() as u32, (y1 + plot.top).round() as u32);
+                let (x2, y2) = ((x2 + plot.left).round() as u32, (y2 + plot.top).round() as u32);
+                offset = canvas.stroke(x1, y1, x2, y2, &line, offset);
+            }
+        }
+    }
+    if let Some(marker) = style.marker {
+        for &(x, y) in points {
+            let (px, py) = plot.project(x, y);
+            let (px, py) = (px.round(), py.round());
+            if !plot.contains(px, py) {
+                continue;
+            }
+            for &(dx, dy) in marker.offsets() {
+                let (mx, my) = (px + dx as f64, py + dy as f64);
+                if plot.contains(mx, my) {
+                    canvas.set_styled(mx as u32, my as u32, cell_style);
+                }
+            }
+        }
+    }
+}
+
+/// Shades the pixels between two polylines. Both are interpolated linearly in pixel space, so
+/// that the edges of the shading match lines drawn along them.
+fn fill_between(canvas: &mut Canvas, plot: &Plot, lower: &[(f64, f64)], upper: &[(f64, f64)],
+                fill: Fill, style: Style) {
+    let project = |points: &[(f64, f64)]| -> Vec<(f64, f64)> {
+        points.iter().map(|&(x, y)| plot.project(x, y))
+            .filter(|p| p.0.is_finite() && p.1.is_finite())
+            .collect()
+    };
+    let (lower, upper) = (project(lower), project(upper));
+    let span = |points: &[(f64, f64)]| {
+        points.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), p| {
+            (lo.min(p.0), hi.max(p.0))
+        })
+    };
+    let ((l0, l1), (u0, u1)) = (span(&lower), span(&upper));
+    let (start, end) = (l0.max(u0).max(plot.left).ceil(), l1.min(u1).min(plot.right).floor());
+    let mut px = start;
+    while px <= end {
+        let (a, b) = (interpolate(&lower, px).round(), interpolate(&upper, px).round());
+        let mut py = a.min(b).max(plot.top);
+        while py <= a.max(b).min(plot.bottom) {
+            if fill.covers(px as u32, py as u32) {
+                canvas.set_styled(px as u32, py as u32, style);
+            }
+            py += 1.0;
+        }
+        px += 1.0;
+    }
+}
+
+/// Interpolates a polyline sorted by x linearly at `x`, extending its ends horizontally. An
+/// empty polyline is zero everywhere.
+fn interpolate(points: &[(f64, f64)], x: f64) -> f64 {
+    match points.iter().position(|p| p.0 >= x) {
+        _ if points.is_empty() => 0.0,
+        Some(0) => points[0].1,
+        Some(i) => {
+            let ((x1, y1), (x2, y2)) = (points[i - 1], points[i]);
+            y1 + (y2 - y1) * (x - x1) / (x2 - x1)
+        }
+        None => points[points.len() - 1].1,
+    }
+}
+
+/// Draws the candles of a candlestick series within a plot, each as wide as about two thirds of
+/// the narrowest gap between periods.
+fn draw_candles(canvas: &mut Canvas, plot: &Plot, series: &Series, theme: &Theme) {
+    let xs: Vec<f64> = series.candles.iter().map(|c| plot.project(c.x, 0.0).0)
+        .filter(|x| x.is_finite()).collect();
+    let gap = smallest_gap(xs);
+    let half = if gap.is_finite() { (gap / 3.0).floor() } else { 1.0 };
+    for candle in &series.candles {
+        let (x, high) = plot.project(candle.x, candle.high);
+        let (_, low) = plot.project(candle.x, candle.low);
+        let (_, open) = plot.project(candle.x, candle.open);
+        let (_, close) = plot.project(candle.x, candle.close);
+        if ![x, high, low, open, close].iter().all(|v| v.is_finite()) {
+            continue;
+        }
+        let rising = candle.is_rising();
+        let color = series.color.unwrap_or(if rising { theme.rising } else { theme.falling });
+        let style = Style::new().fg(color);
+        let x = x.round();
+        let (top, bottom) = (open.min(close).round(), open.max(close).round());
+        // Walk only the part within the plot, as candles far outside a fixed range would
+        // otherwise take forever.
+        let mut py = high.round().max(plot.top);
+        while py <= low.round().min(plot.bottom) {
+            if plot.contains(x, py) && (py < top || py > bottom) {
+                canvas.set_styled(x as u32, py as u32, style);
+            }
+            py += 1.0;
+        }
+        let mut py = top.max(plot.top);
+        while py <= bottom.min(plot.bottom) {
+            let mut px = (x - half).max(plot.left);
+            while px <= (x + half).min(plot.right) {
+                let edge = px == x - half || px == x + half || py == top || py == bottom;
+                if plot.contains(px, py) && (edge || !rising) {
+                    canvas.set_styled(px as u32, py as u32, style);
+                }
+                px += 1.0;
+            }
+            py += 1.0;
+        }
+    }
+}
+
+/// Draws the boxes and whiskers of a box plot series, as wide as the candles of a candlestick
+/// series would be.
+fn draw_boxes(canvas: &mut Canvas, plot: &Plot, series: &Series, style: &SeriesStyle) {
+    let xs: Vec<f64> = series.boxes.iter().map(|b| plot.project(b.x, 0.0).0)
+        .filter(|x| x.is_finite()).collect();
+    let gap = smallest_gap(xs);
+    let half = if gap.is_finite() { (gap / 3.0).floor().max(1.0) } else { 2.0 };
+    let cap = (half / 2.0).round();
+    let cell_style = Style::new().fg(style.color);
+    let mut set = |px: f64, py: f64| {
+        if plot.contains(px, py) {
+            canvas.set_styled(px as u32, py as u32, cell_style);
+        }
+    };
+    for b in &series.boxes {
+        let y = |v: f64| plot.project(b.x, v).1.round();
+        let x = plot.project(b.x, 0.0).0.round();
+        let (low, q1, median, q3, high) = (y(b.low), y(b.q1), y(b.median), y(b.q3), y(b.high));
+        if ![x, low, q1, median, q3, high].iter().all(|v| v.is_finite()) {
+            continue;
+        }
+        // Walk only the part within the plot, as boxes far outside a fixed range would
+        // otherwise take forever.
+        let mut py = high.max(plot.top);
+        while py <= low.min(plot.bottom) {
+            if py < q3 || py > q1 {
+                set(x, py);
+            }
+            py += 1.0;
+        }
+        let mut px = (x - half).max(plot.left);
+        while px <= (x + half).min(plot.right) {
+            for &py in &[q3, median, q1] {
+                set(px, py);
+            }
+            if (px - x).abs() <= cap {
+                set(px, high);
+                set(px, low);
+            }
+            px += 1.0;
+        }
+        let mut py = q3.max(plot.top);
+        while py <= q1.min(plot.bottom) {
+            set(x - half, py);
+            set(x + half, py);
+            py += 1.0;
+        }
+        let marker = style.marker.unwrap_or(Marker::Dot);
+        for &v in &b.outliers {
+            let py = y(v);
+            if py.is_finite() {
+                for &(dx, dy) in marker.offsets() {
+                    set(x + dx as f64, py + dy as f64);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the smallest positive difference between any two of the values, or infinity if there
+/// isn’t one.
+fn smallest_gap(mut values: Vec<f64>) -> f64 {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    values.windows(2).map(|w| w[1] - w[0]).filter(|&d| d > 0.0).fold(f64::INFINITY, f64::min)
+}
+
+/// Returns the character that stands for a line’s dash pattern in the legend.
+fn legend_char(line: &LineStyle) -> char {
+    match line.dash() {
+        (_, 0) => '─',
+        (1, _) => '┈',
+        _ => '╌',
+    }
+}
+
+/// Picks the range of an axis: the fixed one if there is one, otherwise one spanning all the
+/// finite values given.
+pub(crate) fn fit<I: Iterator<Item = f64>>(fixed: Option<(f64, f64)>, values: I) -> (f64, f64) {
+    let (min, max) = fixed.unwrap_or_else(|| {
+        values.filter(|v| v.is_finite()).fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+            (lo.min(v), hi.max(v))
+        })
+    });
+    if !min.is_finite() || !max.is_finite() {
+        (0.0, 1.0)
+    } else if min >= max {
+        let pad = if min == 0.0 { 1.0 } else { min.abs() / 2.0 };
+        (min - pad, max + pad)
+    } else {
+        (min, max)
+    }
+}
+
+/// Picks about `count` ticks at round numbers (multiples of 1, 2 or 5 times a power of ten)
+/// within a range.
+pub(crate) fn nice_ticks((min, max): (f64, f64), count: usize) -> Vec<f64> {
+    if count == 0 || max <= min {
+        return Vec::new();
+    }
+    let raw = (max - min) / count as f64;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let step = magnitude * match raw / magnitude {
+        n if n < 1.5 => 1.0,
+        n if n < 3.0 => 2.0,
+        n if n < 7.0 => 5.0,
+        _ => 10.0,
+    };
+    let mut ticks = Vec::new();
+    let mut i = (min / step).ceil();
+    while i * step <= max + step * 1e-9 {
+        // Multiplying rather than accumulating keeps values like 0.3 exact.
+        let v = i * step;
+        ticks.push(if v.abs() < step * 1e-9 { 0.0 } else { v });
+        i += 1.0;
+    }
+    ticks
+}
+
+/// Detects whether a value is zero or a power of ten, positive or negative.
+fn is_decade(v: f64) -> bool {
+    v == 0.0 || (v.abs().log10() - v.abs().log10().round()).abs() < 1e-9
+}
+
+/// Formats a tick value with just enough decimals to tell it apart from its neighbours.
+pub(crate) fn format_tick(v: f64, ticks: &[f64]) -> String {
+    let step = if ticks.len() > 1 { ticks[1] - ticks[0] } else { v.abs() };
+    let decimals = if step > 0.0 && step < 1.0 { (-step.log10()).ceil() as usize } else { 0 };
+    format!("{:.*}", decimals, v)
+}
+
+/// Formats a value with an SI prefix and up to three significant digits.
+fn format_si(v: f64) -> String {
+    const PREFIXES: [(f64, &str); 10] = [(1e15, "P"), (1e12, "T"), (1e9, "G"), (1e6, "M"),
+                                         (1e3, "k"), (1.0, ""), (1e-3, "m"), (1e-6, "µ"),
+                                         (1e-9, "n"), (1e-12, "p")];
+    if v == 0.0 || !v.is_finite() {
+        return format!("{}", v);
+    }
+    let significant = |m: f64| {
+        let scale = 10f64.powi(2 - m.log10().floor() as i32);
+        (m * scale).round() / scale
+    };
+    // The largest prefix that leaves at least 1 before the point, once rounded.
+    let &(factor, prefix) = PREFIXES.iter()
+        .find(|&&(factor, _)| significant(v.abs() / factor) >= 1.0)
+        .unwrap_or(&PREFIXES[PREFIXES.len() - 1]);
+    let mantissa = v / factor;
+    let decimals = match mantissa.abs() {
+        m if m >= 99.95 => 0,
+        m if m >= 9.995 => 1,
+        _ => 2,
+    };
+    let digits = format!("{:.*}", decimals, mantissa);
+    let digits = if digits.contains('.') {
+        digits.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &digits
+    };
+    format!("{}{}", digits, prefix)
+}
+
+/// Formats a number of seconds as a duration in its two largest units, like `1h30m`, or in
+/// milliseconds if it is shorter than a second.
+fn format_duration(v: f64) -> String {
+    const UNITS: [(f64, &str); 4] = [(86400.0, "d"), (3600.0, "h"), (60.0, "m"), (1.0, "s")];
+    let sign = if v < 0.0 { "-" } else { "" };
+    let v = v.abs();
+    if v == 0.0 || !v.is_finite() {
+        return format!("{}{}s", sign, v);
+    }
+    if v < 1.0 {
+        return format!("{}{}ms", sign, format_si(v * 1000.0));
+    }
+    let i = UNITS.iter().position(|&(unit, _)| v >= unit).unwrap_or(UNITS.len() - 1);
+    let (unit, name) = UNITS[i];
+    let whole = (v / unit).floor();
+    let rest = match UNITS.get(i + 1) {
+        Some(&(next, next_name)) => {
+            let n = ((v - whole * unit) / next).round();
+            // Rounding the remainder up can carry into the larger unit.
+            if n * next >= unit {
+                return format!("{}{}", sign, format_duration((whole + 1.0) * unit));
+            }
+            if n > 0.0 { format!("{}{}", n, next_name) } else { String::new() }
+        }
+        None => {
+            let fraction = v - whole;
+            if fraction >= 0.005 {
+                return format!("{}{}{}", sign, format_si(v), name);
+            }
+            String::new()
+        }
+    };
+    format!("{}{}{}{}", sign, whole, name, rest)
+}
+
+/// The intervals, in seconds, that ticks on a time axis may be spaced by.
+const TIME_STEPS: [f64; 19] = [1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 900.0,
+                               1800.0, 3600.0, 7200.0, 10800.0, 21600.0, 43200.0, 86400.0,
+                               604800.0];
+
+/// Picks about `count` ticks at round times within a range of Unix timestamps, and returns them
+/// with the interval between them.
+fn time_ticks((min, max): (f64, f64), count: usize) -> (Vec<f64>, f64) {
+    if count == 0 || max <= min {
+        return (Vec::new(), 1.0);
+    }
+    let raw = (max - min) / count as f64;
+    let step = match TIME_STEPS.iter().find(|&&s| s >= raw) {
+        Some(&step) => step,
+        // Beyond a week, whole numbers of days are round enough.
+        None => (raw / 86400.0).ceil() * 86400.0,
+    };
+    let ticks = nice_multiples(min, max, step);
+    if ticks.is_empty() && step >= 86400.0 {
+        (nice_ticks((min, max), count), step)
+    } else {
+        (ticks, step)
+    }
+}
+
+/// Returns the multiples of `step` within a range.
+fn nice_multiples(min: f64, max: f64, step: f64) -> Vec<f64> {
+    let mut ticks = Vec::new();
+    let mut i = (min / step).ceil();
+    while i * step <= max {
+        ticks.push(i * step);
+        i += 1.0;
+    }
+    ticks
+}
+
+/// Formats a Unix timestamp in UTC, as `HH:MM:SS`, `HH:MM` or `YYYY-MM-DD` depending on how far
+/// apart ticks are.
+fn format_time(t: f64, step: f64) -> String {
+    let t = t.floor() as i64;
+    let (days, seconds) = (t.div_euclid(86400), t.rem_euclid(86400));
+    let (h, m, s) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if step < 60.0 {
+        format!("{:02}:{:02}:{:02}", h, m, s)
+    } else if step < 86400.0 {
+        format!("{:02}:{:02}", h, m)
+    } else {
+        let (year, month, day) = civil_from_days(days);
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+/// Converts a number of days since 1970-01-01 into a year, month and day of the proleptic
+/// Gregorian calendar, using Howard Hinnant’s algorithm.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
@@ -0,0 +1,156 @@
+use std::error::Error;
+use std::fmt;
+
+use canvas::{Canvas, OutOfBounds};
+
+/// An error produced by the `try_` drawing methods of `Canvas`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DrawError {
+    /// The point is further out than a `Canvas` can store, beyond column 65535 or row 65535
+    /// of cells.
+    Overflow(u32, u32),
+    /// The point is outside the `Canvas`’s given dimensions, and its out-of-bounds policy is
+    /// `OutOfBounds::Error`.
+    OutOfBounds(u32, u32),
+    /// A parameter with the given name had an unusable value.
+    InvalidParameter(&'static str),
+}
+
+impl fmt::Display for DrawError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DrawError::Overflow(x, y) => write!(f, "point ({}, {}) is too far out to draw", x, y),
+            DrawError::OutOfBounds(x, y) => write!(f, "point ({}, {}) is out of bounds", x, y),
+            DrawError::InvalidParameter(name) => write!(f, "invalid value for {}", name),
+        }
+    }
+}
+
+impl Error for DrawError {}
+
+impl Canvas {
+    /// Checks that a point can be drawn, with the `Canvas`’s out-of-bounds policy.
+    fn check(&self, x: u32, y: u32) -> Result<(), DrawError> {
+        match self.out_of_bounds() {
+            _ if self.in_bounds(x, y) => Ok(()),
+            OutOfBounds::Error => Err(DrawError::OutOfBounds(x, y)),
+            OutOfBounds::Expand if !Canvas::in_range(x, y) => Err(DrawError::Overflow(x, y)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Sets a pixel like `set`, or returns an error if it can’t be drawn.
+    ///
+    /// ```
+    /// use drawille::{Canvas, DrawError, OutOfBounds};
+    ///
+    /// let mut canvas = Canvas::new(8, 8);
+    /// assert_eq!(canvas.try_set(1_000_000, 0), Err(DrawError::Overflow(1_000_000, 0)));
+    /// canvas.set_out_of_bounds(OutOfBounds::Error);
+    /// assert_eq!(canvas.try_set(8, 0), Err(DrawError::OutOfBounds(8, 0)));
+    /// assert_eq!(canvas.try_set(7, 0), Ok(()));
+    /// ```
+    pub fn try_set(&mut self, x: u32, y: u32) -> Result<(), DrawError> {
+        self.check(x, y)?;
+        self.set(x, y);
+        Ok(())
+    }
+
+    /// Deletes a pixel like `unset`, or returns an error if it can’t be drawn.
+    pub fn try_unset(&mut self, x: u32, y: u32) -> Result<(), DrawError> {
+        self.check(x, y)?;
+        self.unset(x, y);
+        Ok(())
+    }
+
+    /// Toggles a pixel like `toggle`, or returns an error if it can’t be drawn.
+    pub fn try_toggle(&mut self, x: u32, y: u32) -> Result<(), DrawError> {
+        self.check(x, y)?;
+        self.toggle(x, y);
+        Ok(())
+    }
+
+    /// Sets a letter like `set_char`, or returns an error if it can’t be drawn.
+    pub fn try_set_char(&mut self, x: u32, y: u32, c: char) -> Result<(), DrawError> {
+        self.check(x, y)?;
+        self.set_char(x, y, c);
+        Ok(())
+    }
+
+    /// Draws a line like `line`, or returns an error without drawing anything if any of it
+    /// can’t be drawn.
+    pub fn try_line(&mut self, x1: u32, y1: u32, x2: u32, y2: u32) -> Result<(), DrawError> {
+        // A line lies within the box of its endpoints, so checking them checks every point.
+        self.check(x1, y1)?;
+        self.check(x2, y2)?;
+        self.line(x1, y1, x2, y2);
+        Ok(())
+    }
+
+    /// Draws a vertical line like `vline`, or returns an error without drawing anything if any
+    /// of it can’t be drawn.
+    pub fn try_vline(&mut self, x: u32, y1: u32, y2: u32) -> Result<(), DrawError> {
+        self.check(x, y1)?;
+        self.check(x, y2)?;
+        self.vline(x, y1, y2);
+        Ok(())
+    }
+
+    /// Draws text like `text`, or returns an error without drawing anything if any letter
+    /// that fits in `max_width` can’t be drawn.
+    pub fn try_text(&mut self, x: u32, y: u32, max_width: u32, text: &str)
+        -> Result<(), DrawError>
+    {
+        let letters = text.chars().count() as u32;
+        if letters > 0 {
+            let width = (letters - 1).saturating_mul(2).min(max_width / 2 * 2);
+            let end = x.checked_add(width).ok_or(DrawError::Overflow(x, y))?;
+            self.check(x, y)?;
+            self.check(end, y)?;
+        }
+        self.text(x, y, max_width, text);
+        Ok(())
+    }
+
+    /// Draws a circle like `circle`, or returns an error without drawing anything if any of it
+    /// can’t be drawn.
+    ///
+    /// Only the parts that would lie at negative coordinates may be left out.
+    pub fn try_circle(&mut self, x: u32, y: u32, radius: u32) -> Result<(), DrawError> {
+        let ry = (radius as f64 / self.dot_aspect()).ceil() as u32;
+        let right = x.checked_add(radius).ok_or(DrawError::Overflow(x, y))?;
+        let bottom = y.checked_add(ry).ok_or(DrawError::Overflow(x, y))?;
+        self.check(x.saturating_sub(radius), y.saturating_sub(ry))?;
+        self.check(right, bottom)?;
+        self.circle(x, y, radius);
+        Ok(())
+    }
+
+    /// Sets the ratio of a cell’s height to its width like `set_cell_aspect`, or returns an
+    /// error if it isn’t a positive, finite number.
+    ///
+    /// ```
+    /// use drawille::{Canvas, DrawError};
+    ///
+    /// let mut canvas = Canvas::new(8, 8);
+    /// assert_eq!(canvas.try_set_cell_aspect(0.0), Err(DrawError::InvalidParameter("ratio")));
+    /// ```
+    pub fn try_set_cell_aspect(&mut self, ratio: f64) -> Result<(), DrawError> {
+        if !(ratio.is_finite() && ratio > 0.0) {
+            return Err(DrawError::InvalidParameter("ratio"));
+        }
+        self.set_cell_aspect(ratio);
+        Ok(())
+    }
+
+    /// Sets the world coordinates like `set_world`, or returns an error if the rectangle has
+    /// no area or isn’t finite.
+    pub fn try_set_world(&mut self, min: (f64, f64), max: (f64, f64)) -> Result<(), DrawError> {
+        let finite = [min.0, min.1, max.0, max.1].iter().all(|v| v.is_finite());
+        if !finite || min.0 >= max.0 || min.1 >= max.1 {
+            return Err(DrawError::InvalidParameter("world"));
+        }
+        self.set_world(min, max);
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+
+use canvas::Canvas;
+use dither::Density;
+use style::{Color, Style};
+
+/// A named region of a `Choropleth`, made of one or more closed rings of points.
+#[derive(Clone, Debug, PartialEq)]
+struct Region {
+    name: String,
+    rings: Vec<Vec<(f64, f64)>>,
+    value: f64,
+}
+
+/// A choropleth map: regions shaded by a value each, such as states by population, with their
+/// borders drawn over them.
+///
+/// Regions are given in the world coordinates of the `Canvas` they are drawn onto, set with
+/// `Canvas::set_world` or `fit_world`, or in pixels if it has none. Each is a list of closed
+/// rings, the last point of each joining back to its first; a point inside an odd number of
+/// rings of a region is inside it, so islands and lakes are just more rings.
+///
+/// Values are shaded from a light sprinkling of dots for the lowest to solid for the highest,
+/// and optionally coloured along a gradient.
+///
+/// ```
+/// use drawille::{Canvas, Choropleth};
+///
+/// let square = |x: f64, y: f64| vec![(x, y), (x + 10.0, y), (x + 10.0, y + 10.0), (x, y + 10.0)];
+/// let map = Choropleth::new()
+///     .region("west", vec![square(0.0, 0.0)], 1.0)
+///     .region("east", vec![square(10.0, 0.0)], 9.0);
+/// let mut canvas = Canvas::new(40, 20);
+/// canvas.set_world((0.0, 0.0), (20.0, 10.0));
+/// map.draw(&mut canvas);
+/// assert_eq!(map.region_at(15.0, 5.0), Some("east"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Choropleth {
+    regions: Vec<Region>,
+    range: Option<(f64, f64)>,
+    colors: Option<(Color, Color)>,
+    density: Density,
+    borders: bool,
+}
+
+impl Choropleth {
+    /// Creates a new `Choropleth` without regions, shading the range of their values with an
+    /// ordered dither and drawing borders.
+    pub fn new() -> Choropleth {
+        Choropleth {
+            regions: Vec::new(),
+            range: None,
+            colors: None,
+            density: Density::default(),
+            borders: true,
+        }
+    }
+
+    /// Adds a region with its rings and value, and return it for use again.
+    ///
+    /// A region whose value isn’t finite only has its borders drawn, as for missing data.
+    pub fn region<S: Into<String>>(mut self, name: S, rings: Vec<Vec<(f64, f64)>>, value: f64)
+        -> Choropleth
+    {
+        self.regions.push(Region { name: name.into(), rings, value });
+        self
+    }
+
+    /// Sets the values shaded lightest and solid instead of the lowest and highest of the
+    /// regions, and return it for use again.
+    ///
+    /// This keeps the shading of several maps comparable. Values outside the range are shaded
+    /// as its nearest end.
+    pub fn range(mut self, min: f64, max: f64) -> Choropleth {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Colours the regions along a gradient from `low` for the lowest value to `high` for the
+    /// highest, and return it for use again.
+    ///
+    /// Since a cell only has one colour, cells that regions share take the colour of the region
+    /// drawn last.
+    pub fn colors(mut self, low: Color, high: Color) -> Choropleth {
+        self.colors = Some((low, high));
+        self
+    }
+
+    /// Sets how values are turned into densities of dots, and return it for use again.
+    pub fn density(mut self, density: Density) -> Choropleth {
+        self.density = density;
+        self
+    }
+
+    /// Draws or leaves out the borders of the regions, and return it for use again.
+    pub fn borders(mut self, borders: bool) -> Choropleth {
+        self.borders = borders;
+        self
+    }
+
+    /// Returns the name of the region that a point in world coordinates falls in, such as the
+    /// one under the mouse, or `None` if it is in none of them.
+    pub fn region_at(&self, x: f64, y: f64) -> Option<&str> {
+        self.regions.iter().rev().find(|region| {
+            let crossings = region.rings.iter().map(|ring| crossings(ring, y).iter()
+                .filter(|&&cx| cx < x).count()).sum::<usize>();
+            crossings % 2 == 1
+        }).map(|region| &region.name[..])
+    }
+
+    /// Draws the regions onto the `Canvas`, in the order they were added.
+    pub fn draw(&self, canvas: &mut Canvas) {
+        let (min, max) = self.range.unwrap_or_else(|| {
+            self.regions.iter().map(|r| r.value).filter(|v| v.is_finite())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |r, v| (r.0.min(v), r.1.max(v)))
+        });
+        let (_, height) = canvas.pixel_extent();
+        for region in &self.regions {
+            if !region.value.is_finite() {
+                continue;
+            }
+            let t = match max > min {
+                true => ((region.value - min) / (max - min)).clamp(0.0, 1.0),
+                false => 1.0,
+            };
+            let level = 0.1 + 0.9 * t;
+            let style = match self.colors {
+                Some((low, high)) => Style::new().fg(blend(low, high, t)),
+                None => Style::default(),
+            };
+            let rings: Vec<Vec<(f64, f64)>> = region.rings.iter()
+                .map(|ring| ring.iter().map(|&(x, y)| canvas.world_to_pixel_f64(x, y)).collect())
+                .collect();
+            let (top, bottom) = rings.iter().flatten()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |r, p| (r.0.min(p.1), r.1.max(p.1)));
+            if !(top.is_finite() && bottom.is_finite()) {
+                continue;
+            }
+            let bottom = if height > 0.0 { bottom.min(height) } else { bottom };
+            for py in top.max(0.0).ceil() as u32..=bottom.max(0.0).floor() as u32 {
+                let mut xs: Vec<f64> = rings.iter().flat_map(|ring| crossings(ring, py as f64))
+                    .collect();
+                xs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+                for pair in xs.chunks(2).filter(|pair| pair.len() == 2) {
+                    // A crossing on a pixel fills it only on the left, so that regions sharing
+                    // a border don’t overlap.
+                    for px in pair[0].max(0.0).ceil() as u32..=pair[1].max(-1.0).floor() as u32 {
+                        if (px as f64) < pair[1] && self.density.is_set(px, py, level) {
+                            canvas.set_styled(px, py, style);
+                        }
+                    }
+                }
+            }
+        }
+        if self.borders {
+            for ring in self.regions.iter().flat_map(|r| &r.rings) {
+                for (i, &(x1, y1)) in ring.iter().enumerate() {
+                    let (x2, y2) = ring[(i + 1) % ring.len()];
+                    canvas.plot_line(x1, y1, x2, y2);
+                }
+            }
+        }
+    }
+}
+
+impl Default for Choropleth {
+    fn default() -> Choropleth {
+        Choropleth::new()
+    }
+}
+
+/// Returns where the edges of a closed ring cross a horizontal line, for an even-odd fill.
+fn crossings(ring: &[(f64, f64)], y: f64) -> Vec<f64> {
+    let mut xs = Vec::new();
+    for (i, &(x1, y1)) in ring.iter().enumerate() {
+        let (x2, y2) = ring[(i + 1) % ring.len()];
+        // Half-open, so that a line through a vertex counts it once.
+        if (y1 <= y) != (y2 <= y) {
+            xs.push(x1 + (y - y1) / (y2 - y1) * (x2 - x1));
+        }
+    }
+    xs
+}
+
+/// Mixes two colours, a fraction `t` of the way from `a` to `b`.
+fn blend(a: Color, b: Color, t: f64) -> Color {
+    let ((r1, g1, b1), (r2, g2, b2)) = (a.to_rgb(), b.to_rgb());
+    let mix = |c1: u8, c2: u8| (c1 as f64 + (c2 as f64 - c1 as f64) * t).round() as u8;
+    Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+}
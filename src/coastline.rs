@@ -0,0 +1,229 @@
+//! A coarse outline of the world’s coastlines, for `Canvas::draw_coastlines`.
+//!
+//! Each coast is a line through `(longitude, latitude)` points in degrees, with closed ones
+//! ending where they start. They were simplified by hand to a point every few degrees, which
+//! is about as fine as a map a terminal wide can show. Coasts stop at the antimeridian rather
+//! than crossing it, so no line runs across the whole map.
+
+pub(crate) const COASTLINES: &[&[(f64, f64)]] = &[
+    // The Americas, from the Bering Strait east along the Arctic coast, around South America
+    // and back up the Pacific coast.
+    &[
+        (-168.0, 65.6), (-166.0, 68.8), (-162.0, 70.2), (-156.5, 71.3), (-152.0, 70.8),
+        (-143.0, 70.1), (-136.0, 69.0), (-128.0, 70.2), (-121.0, 69.3), (-115.0, 68.5),
+        (-108.0, 68.0), (-98.0, 68.0), (-94.0, 69.5), (-90.0, 68.5), (-87.0, 66.5),
+        (-87.0, 64.5), (-92.0, 63.0), (-94.5, 59.5), (-93.0, 57.5), (-88.0, 56.5),
+        (-82.5, 55.0), (-82.0, 53.0), (-79.5, 51.5), (-78.8, 54.8), (-77.0, 58.0),
+        (-78.0, 60.8), (-78.0, 62.4), (-73.0, 62.2), (-69.5, 61.0), (-64.3, 60.4),
+        (-61.5, 56.5), (-57.5, 54.0), (-56.0, 52.0), (-60.0, 50.2), (-66.5, 49.9),
+        (-70.0, 47.6), (-64.5, 49.0), (-64.8, 47.0), (-61.0, 45.8), (-63.5, 44.6),
+        (-66.0, 43.6), (-67.0, 44.6), (-70.2, 43.6), (-70.5, 41.8), (-74.0, 40.5),
+        (-76.0, 37.0), (-75.5, 35.2), (-78.0, 33.8), (-81.0, 31.5), (-80.0, 27.0),
+        (-80.4, 25.2), (-81.8, 26.5), (-83.0, 29.8), (-85.0, 29.8), (-89.0, 30.2),
+        (-89.5, 29.0), (-94.0, 29.5), (-97.0, 27.5), (-97.5, 24.0), (-97.5, 21.5),
+        (-96.0, 19.0), (-94.5, 18.2), (-91.0, 19.0), (-90.5, 21.0), (-87.0, 21.5),
+        (-88.0, 18.0), (-88.5, 16.0), (-84.0, 15.5), (-83.5, 11.0), (-81.5, 9.0),
+        (-79.5, 9.5), (-77.3, 8.5), (-75.5, 10.5), (-71.5, 12.3), (-64.0, 10.5),
+        (-60.0, 8.5), (-57.0, 6.0), (-52.0, 5.0), (-50.0, 1.5), (-48.0, -1.0),
+        (-44.0, -2.5), (-38.5, -3.7), (-35.0, -5.5), (-35.0, -9.0), (-38.5, -13.0),
+        (-39.0, -17.5), (-40.5, -22.0), (-43.0, -23.0), (-48.0, -25.5), (-48.5, -28.5),
+        (-51.0, -31.0), (-53.0, -34.0), (-57.0, -35.0), (-57.5, -38.0), (-62.0, -39.0),
+        (-65.0, -41.0), (-64.0, -43.0), (-67.5, -46.0), (-65.8, -48.0), (-68.5, -50.5),
+        (-68.5, -52.5), (-70.0, -55.0), (-74.0, -53.0), (-75.0, -49.0), (-74.0, -44.0),
+        (-73.5, -40.0), (-73.5, -37.0), (-71.5, -32.5), (-71.5, -28.0), (-70.5, -24.0),
+        (-70.0, -18.5), (-72.0, -17.0), (-76.0, -14.0), (-78.5, -9.0), (-81.0, -6.0),
+        (-81.0, -4.0), (-80.0, -1.5), (-80.0, 1.0), (-79.0, 1.8), (-77.5, 4.0),
+        (-77.3, 7.0), (-78.5, 8.2), (-80.0, 7.5), (-80.5, 8.2), (-83.5, 8.5),
+        (-85.8, 10.5), (-87.5, 13.0), (-91.5, 14.0), (-94.0, 16.0), (-96.5, 15.7),
+        (-100.0, 17.0), (-105.5, 20.0), (-105.5, 22.5), (-109.0, 26.0), (-112.5, 29.5),
+        (-114.8, 31.8), (-113.0, 29.0), (-111.5, 25.0), (-110.0, 23.0), (-112.0, 24.5),
+        (-114.0, 27.5), (-115.5, 30.0), (-117.1, 32.5), (-118.5, 34.0), (-120.6, 34.6),
+        (-122.5, 37.5), (-124.0, 40.5), (-124.5, 43.0), (-124.0, 46.0), (-124.7, 48.4),
+        (-123.0, 49.0), (-127.0, 50.5), (-130.0, 54.5), (-133.0, 57.0), (-136.0, 58.2),
+        (-140.0, 59.7), (-146.0, 60.8), (-150.0, 59.5), (-154.0, 57.5), (-158.0, 56.5),
+        (-163.0, 54.8), (-158.0, 58.6), (-162.0, 60.0), (-165.0, 62.5), (-164.5, 64.5),
+        (-168.0, 65.6),
+    ],
+    // Greenland.
+    &[
+        (-73.0, 78.0), (-66.0, 81.0), (-50.0, 82.5), (-30.0, 83.5), (-20.0, 82.0),
+        (-18.0, 77.0), (-20.0, 72.0), (-22.0, 70.0), (-27.0, 68.3), (-35.0, 65.5),
+        (-40.0, 65.0), (-43.0, 60.0), (-48.0, 61.0), (-51.0, 64.0), (-53.5, 67.0),
+        (-54.0, 70.5), (-58.0, 75.5), (-66.0, 76.0), (-73.0, 78.0),
+    ],
+    // Baffin Island and Ellesmere Island.
+    &[
+        (-80.0, 73.7), (-68.0, 70.3), (-61.8, 66.6), (-64.8, 62.7), (-71.8, 63.2),
+        (-77.0, 65.3), (-73.5, 67.8), (-81.5, 69.5), (-89.5, 71.5), (-85.0, 73.5),
+        (-80.0, 73.7),
+    ],
+    &[
+        (-90.0, 77.0), (-80.0, 76.3), (-75.0, 79.0), (-65.0, 82.0), (-80.0, 83.0),
+        (-92.0, 81.0), (-90.0, 77.0),
+    ],
+    // Newfoundland, Cuba and Hispaniola.
+    &[(-59.3, 47.6), (-53.5, 46.6), (-52.7, 47.6), (-55.8, 51.6), (-59.3, 47.6)],
+    &[(-85.0, 21.8), (-81.5, 23.1), (-77.5, 21.8), (-74.2, 20.2), (-77.5, 19.9), (-81.0, 21.6),
+      (-85.0, 21.8)],
+    &[(-74.4, 18.4), (-72.8, 19.9), (-69.0, 19.8), (-68.4, 18.6), (-71.5, 17.8), (-74.4, 18.4)],
+    // Europe, Asia and Africa, from Portugal north around Scandinavia, east along the Arctic
+    // coast to the antimeridian.
+    &[
+        (-9.0, 37.0), (-9.5, 39.0), (-8.8, 42.5), (-8.0, 43.7), (-2.0, 43.4),
+        (-1.5, 46.0), (-4.5, 48.0), (-1.5, 49.5), (1.5, 50.5), (4.0, 51.5),
+        (5.0, 53.0), (8.0, 53.5), (8.5, 55.5), (8.0, 57.0), (10.5, 57.7),
+        (10.5, 56.0), (12.5, 55.5), (11.0, 54.0), (14.0, 54.0), (18.5, 54.5),
+        (21.0, 55.0), (21.0, 57.0), (24.0, 57.0), (24.0, 59.4), (29.5, 60.0),
+        (23.0, 60.0), (21.5, 61.0), (21.5, 63.5), (25.0, 65.0), (22.0, 65.8),
+        (19.0, 63.5), (17.0, 61.0), (19.0, 59.5), (16.5, 57.0), (14.4, 55.6),
+        (12.5, 56.5), (11.0, 59.0), (8.0, 58.0), (5.5, 58.8), (5.0, 61.0),
+        (8.0, 63.5), (13.0, 66.5), (16.0, 68.5), (20.0, 70.0), (26.0, 71.0),
+        (30.0, 70.0), (33.0, 69.3), (41.0, 67.0), (40.0, 64.5), (44.0, 66.0),
+        (44.0, 68.5), (53.0, 68.5), (58.0, 68.8), (60.0, 70.0), (68.0, 68.5),
+        (69.0, 73.0), (73.0, 72.5), (73.5, 68.0), (80.0, 72.5), (87.0, 74.5),
+        (100.0, 76.0), (104.0, 77.7), (113.0, 73.5), (120.0, 73.0), (129.0, 71.0),
+        (140.0, 72.5), (150.0, 71.5), (160.0, 69.6), (170.0, 70.0), (180.0, 69.0),
+    ],
+    // Chukotka, across the antimeridian.
+    &[(-180.0, 69.0), (-175.0, 67.3), (-170.0, 66.0), (-172.0, 64.5), (-178.0, 65.5),
+      (-180.0, 65.0)],
+    // On from the antimeridian down the Pacific coast of Asia, around India and Arabia, down
+    // Africa and back along the Mediterranean to Portugal.
+    &[
+        (180.0, 65.0), (177.0, 62.5), (170.0, 60.0), (164.0, 60.0), (162.0, 57.5),
+        (163.0, 56.0), (160.0, 53.0), (156.5, 51.0), (156.0, 57.0), (160.0, 61.0),
+        (155.0, 59.3), (150.0, 59.5), (143.0, 59.3), (137.0, 54.0), (141.0, 53.0),
+        (140.5, 48.5), (135.0, 43.5), (131.0, 42.7), (129.5, 40.5), (128.3, 38.5),
+        (129.4, 36.0), (126.5, 34.5), (126.3, 37.5), (124.7, 39.8), (122.0, 40.7),
+        (121.5, 39.0), (118.0, 39.0), (119.5, 37.2), (122.5, 37.0), (120.3, 36.0),
+        (119.0, 34.8), (121.0, 32.0), (122.0, 30.0), (121.0, 28.0), (119.5, 25.5),
+        (116.5, 22.9), (113.5, 22.2), (110.5, 21.0), (108.0, 21.5), (106.5, 20.0),
+        (105.7, 18.5), (108.8, 15.0), (109.3, 12.0), (107.0, 10.5), (104.8, 8.6),
+        (105.0, 10.5), (103.0, 10.5), (101.5, 12.7), (100.0, 13.5), (99.2, 10.0),
+        (100.3, 8.0), (101.5, 6.8), (103.4, 4.0), (104.2, 1.4), (103.4, 1.3),
+        (101.0, 3.0), (100.3, 5.5), (98.5, 8.0), (98.3, 10.0), (98.5, 13.0),
+        (97.6, 16.5), (94.4, 16.0), (94.5, 19.0), (92.4, 20.7), (91.8, 22.5),
+        (90.5, 22.0), (88.0, 21.6), (86.9, 20.8), (85.0, 19.4), (82.3, 17.0),
+        (80.3, 15.5), (80.3, 13.0), (79.8, 10.3), (78.2, 8.8), (77.5, 8.1),
+        (76.5, 9.5), (75.5, 12.0), (74.5, 14.7), (73.0, 18.5), (72.8, 21.0),
+        (72.5, 22.2), (70.5, 20.8), (69.0, 22.3), (70.3, 23.0), (68.5, 23.3),
+        (67.0, 24.8), (66.5, 25.4), (61.6, 25.2), (57.5, 25.7), (56.5, 27.0),
+        (54.5, 26.6), (51.5, 27.8), (50.2, 30.2), (48.0, 30.0), (48.5, 28.5),
+        (50.0, 26.5), (50.5, 25.0), (51.5, 24.2), (54.0, 24.2), (56.0, 26.0),
+        (56.5, 24.5), (58.5, 23.6), (59.8, 22.4), (57.8, 19.0), (55.5, 17.5),
+        (52.0, 16.0), (48.5, 14.0), (45.0, 12.8), (43.5, 12.7), (42.7, 16.5),
+        (40.0, 20.0), (38.5, 23.5), (35.2, 28.0), (34.9, 29.5), (34.2, 27.8),
+        (33.5, 28.0), (32.5, 29.9), (33.6, 27.0), (35.5, 23.5), (37.2, 21.0),
+        (38.5, 18.0), (39.7, 15.3), (41.7, 13.5), (43.3, 11.7), (44.5, 10.4),
+        (51.2, 11.8), (51.0, 10.4), (49.0, 6.0), (46.0, 2.0), (43.0, -1.0),
+        (40.5, -2.5), (39.2, -5.0), (39.3, -8.0), (40.5, -10.5), (40.5, -15.0),
+        (36.8, -18.6), (35.3, -22.0), (35.5, -24.0), (32.8, -25.8), (32.5, -28.5),
+        (30.8, -30.5), (27.5, -33.5), (25.5, -34.0), (22.0, -34.2), (20.0, -34.8),
+        (18.4, -34.2), (17.9, -32.0), (15.3, -27.0), (14.5, -23.0), (11.8, -17.3),
+        (12.3, -13.0), (13.8, -11.0), (12.2, -6.0), (11.8, -4.0), (9.5, -1.5),
+        (9.5, 2.5), (9.7, 4.0), (8.5, 4.5), (6.0, 4.3), (4.5, 6.3),
+        (1.5, 6.2), (-2.0, 4.8), (-4.5, 5.2), (-7.5, 4.4), (-9.5, 5.5),
+        (-11.5, 7.0), (-13.3, 8.9), (-15.0, 11.0), (-16.6, 12.2), (-17.5, 14.7),
+        (-16.5, 16.5), (-16.2, 19.5), (-17.0, 21.0), (-16.0, 23.8), (-14.5, 26.0),
+        (-13.0, 27.7), (-10.0, 29.2), (-9.8, 31.2), (-8.5, 33.3), (-6.8, 34.0),
+        (-5.9, 35.8), (-2.0, 35.1), (1.0, 36.5), (5.0, 36.8), (9.0, 37.3),
+        (10.3, 36.5), (11.0, 35.3), (10.0, 34.0), (11.5, 33.1), (15.2, 32.3),
+        (19.0, 30.3), (20.0, 31.0), (20.5, 32.7), (23.0, 32.6), (25.0, 31.7),
+        (29.0, 30.9), (32.3, 31.3), (34.2, 31.3), (35.0, 32.8), (35.8, 34.6),
+        (36.0, 36.5), (32.5, 36.1), (30.5, 36.4), (27.5, 37.0), (26.2, 39.5),
+        (26.0, 40.8), (23.5, 40.2), (22.6, 40.5), (22.8, 39.3), (24.0, 38.0),
+        (23.0, 36.5), (21.7, 36.9), (21.3, 38.5), (19.4, 40.3), (19.5, 41.8),
+        (16.0, 43.5), (13.7, 45.2), (12.3, 45.3), (12.5, 44.0), (14.0, 42.5),
+        (16.0, 41.5), (18.5, 40.2), (17.0, 39.0), (16.5, 38.0), (15.7, 38.0),
+        (15.8, 40.0), (14.0, 40.8), (11.3, 42.4), (10.3, 43.8), (8.8, 44.4),
+        (7.5, 43.8), (5.0, 43.3), (3.1, 43.0), (3.2, 42.0), (0.8, 41.0),
+        (-0.3, 39.5), (0.2, 38.8), (-0.7, 37.6), (-2.0, 36.7), (-4.5, 36.7),
+        (-5.6, 36.0), (-6.4, 36.8), (-7.5, 37.2), (-9.0, 37.0),
+    ],
+    // The Black Sea and the Caspian Sea.
+    &[
+        (28.0, 41.5), (29.0, 41.2), (33.0, 42.0), (36.0, 41.7), (38.5, 40.9),
+        (41.5, 41.5), (41.7, 42.7), (39.7, 44.0), (38.0, 45.0), (37.0, 46.4),
+        (35.0, 45.0), (34.0, 44.4), (33.0, 45.3), (31.5, 46.6), (30.2, 45.8),
+        (29.6, 45.0), (28.6, 43.7), (28.0, 42.0), (28.0, 41.5),
+    ],
+    &[
+        (47.0, 44.5), (49.0, 46.5), (51.3, 47.0), (53.0, 45.3), (51.0, 44.5),
+        (53.0, 42.0), (53.5, 39.5), (54.0, 37.5), (51.0, 36.7), (49.0, 37.5),
+        (48.8, 38.7), (49.4, 40.2), (48.5, 41.8), (47.4, 43.0), (47.0, 44.5),
+    ],
+    // Great Britain, Ireland and Iceland.
+    &[
+        (-5.0, 50.0), (1.4, 51.2), (1.7, 52.7), (0.3, 53.5), (-1.5, 55.0),
+        (-2.0, 56.0), (-1.8, 57.6), (-3.0, 58.6), (-5.0, 58.6), (-6.0, 57.0),
+        (-5.6, 55.3), (-4.8, 54.8), (-3.0, 54.0), (-3.0, 53.3), (-4.5, 52.8),
+        (-5.0, 51.7), (-3.0, 51.5), (-5.7, 50.0), (-5.0, 50.0),
+    ],
+    &[(-6.0, 52.0), (-6.0, 54.0), (-7.5, 55.2), (-10.0, 54.0), (-10.2, 51.6), (-8.0, 51.6),
+      (-6.0, 52.0)],
+    &[(-22.5, 64.0), (-24.0, 65.5), (-22.0, 66.4), (-16.0, 66.5), (-14.0, 65.3), (-15.0, 64.3),
+      (-18.7, 63.4), (-22.5, 64.0)],
+    // Novaya Zemlya, Sakhalin, Hokkaido and Honshu with Kyushu.
+    &[(52.0, 71.5), (56.0, 73.5), (62.0, 76.2), (68.5, 77.0), (58.0, 74.5), (55.0, 72.3),
+      (52.0, 71.5)],
+    &[(142.0, 46.0), (143.5, 49.5), (143.0, 53.0), (142.3, 54.3), (142.0, 51.0), (141.8, 48.0),
+      (142.0, 46.0)],
+    &[(140.0, 42.0), (141.5, 45.4), (145.5, 43.3), (143.5, 42.0), (141.0, 41.5), (140.0, 42.0)],
+    &[
+        (130.0, 32.5), (130.9, 34.0), (132.5, 35.4), (135.5, 35.6), (136.8, 37.2),
+        (138.5, 37.5), (140.0, 39.8), (140.0, 41.3), (141.5, 41.3), (142.0, 39.5),
+        (141.0, 38.3), (140.8, 36.0), (140.0, 35.0), (138.8, 34.7), (137.0, 34.6),
+        (135.2, 33.8), (132.0, 33.0), (131.5, 31.5), (130.5, 31.2), (130.0, 32.5),
+    ],
+    // Sri Lanka, Luzon and Mindanao.
+    &[(79.8, 6.0), (80.0, 9.8), (81.9, 7.5), (80.6, 5.9), (79.8, 6.0)],
+    &[(120.0, 18.5), (122.3, 18.5), (122.0, 16.3), (124.0, 13.0), (120.6, 13.8), (119.8, 16.0),
+      (120.0, 18.5)],
+    &[(122.0, 7.0), (125.5, 9.8), (126.5, 7.3), (125.5, 5.7), (124.0, 6.3), (122.0, 7.0)],
+    // Sumatra, Java, Borneo and New Guinea.
+    &[
+        (95.3, 5.6), (98.0, 4.0), (104.0, -1.0), (106.0, -3.0), (105.8, -5.8),
+        (104.5, -5.9), (102.0, -4.0), (100.5, -1.5), (98.7, 1.7), (95.3, 5.6),
+    ],
+    &[(105.2, -6.8), (108.0, -6.0), (111.0, -6.5), (114.5, -7.8), (114.4, -8.7), (110.0, -8.1),
+      (105.5, -7.0), (105.2, -6.8)],
+    &[
+        (109.6, 2.0), (113.0, 3.2), (115.5, 5.3), (117.0, 7.0), (119.0, 5.0),
+        (118.0, 4.2), (117.8, 1.0), (116.5, -1.5), (116.0, -3.8), (114.5, -4.0),
+        (111.5, -3.0), (110.2, -2.8), (109.0, 0.0), (109.6, 2.0),
+    ],
+    &[
+        (131.0, -1.3), (134.0, -0.8), (138.0, -1.6), (141.0, -2.6), (145.5, -5.5),
+        (147.5, -6.2), (150.5, -10.5), (147.0, -10.0), (143.5, -9.0), (141.0, -9.1),
+        (138.0, -8.3), (137.8, -5.3), (135.0, -4.4), (132.5, -3.5), (131.0, -1.3),
+    ],
+    // Australia and Tasmania.
+    &[
+        (113.5, -22.0), (114.0, -26.0), (115.0, -30.0), (115.0, -34.0), (118.0, -35.0),
+        (123.5, -33.9), (126.0, -32.3), (131.0, -31.5), (134.0, -32.8), (136.0, -35.0),
+        (138.0, -34.3), (138.5, -35.6), (140.0, -37.7), (143.5, -38.8), (146.4, -39.1),
+        (150.0, -37.5), (150.5, -35.0), (152.5, -32.0), (153.6, -28.2), (153.0, -25.3),
+        (150.8, -22.6), (146.3, -19.0), (145.4, -15.0), (143.5, -14.0), (142.5, -10.7),
+        (141.6, -13.0), (141.7, -16.5), (140.5, -17.6), (136.7, -15.9), (135.5, -14.7),
+        (136.9, -12.2), (132.5, -11.5), (130.0, -13.0), (129.5, -15.0), (126.5, -14.0),
+        (125.0, -15.5), (122.2, -17.5), (121.0, -19.5), (116.8, -20.7), (113.5, -22.0),
+    ],
+    &[(144.7, -40.7), (148.3, -40.9), (148.0, -43.2), (145.5, -43.5), (144.7, -40.7)],
+    // New Zealand and Madagascar.
+    &[(172.7, -34.5), (175.8, -37.2), (178.5, -37.7), (176.9, -39.6), (175.2, -41.6),
+      (174.6, -39.8), (173.8, -39.2), (174.6, -37.0), (172.7, -34.5)],
+    &[(172.7, -40.5), (174.3, -41.7), (173.0, -43.8), (171.2, -44.5), (169.0, -46.6),
+      (166.5, -46.0), (168.3, -44.0), (171.3, -41.8), (172.7, -40.5)],
+    &[(49.3, -12.0), (50.5, -15.5), (49.5, -17.0), (47.2, -24.9), (45.0, -25.5), (43.3, -22.0),
+      (44.3, -16.5), (47.0, -15.0), (49.3, -12.0)],
+    // Antarctica, from the antimeridian round to it again, with its peninsula reaching up
+    // towards South America.
+    &[
+        (-180.0, -77.5), (-160.0, -77.5), (-150.0, -76.5), (-140.0, -75.0), (-120.0, -73.5),
+        (-100.0, -72.5), (-80.0, -73.0), (-75.0, -71.0), (-68.0, -68.0), (-57.0, -63.3),
+        (-62.0, -66.0), (-60.0, -70.0), (-61.0, -74.0), (-50.0, -77.0), (-35.0, -78.0),
+        (-25.0, -75.0), (-15.0, -72.0), (0.0, -70.0), (20.0, -70.0), (40.0, -69.5),
+        (55.0, -66.5), (70.0, -68.0), (80.0, -67.5), (100.0, -66.0), (120.0, -66.5),
+        (140.0, -66.5), (160.0, -70.0), (170.0, -72.0), (180.0, -77.5),
+    ],
+];
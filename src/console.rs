@@ -0,0 +1,61 @@
+use std::io;
+
+/// Makes the console interpret ANSI escape sequences, such as those for styled cells, instead
+/// of printing them as garbage.
+///
+/// On Windows this turns on virtual terminal processing for standard output, which consoles
+/// since Windows 10 support but don’t always enable. Elsewhere terminals already understand
+/// escape sequences, and this does nothing.
+///
+/// ```
+/// drawille::enable_ansi().ok();
+/// ```
+pub fn enable_ansi() -> io::Result<()> {
+    imp::enable_ansi()
+}
+
+#[cfg(windows)]
+mod imp {
+    use std::io;
+    use std::os::raw::c_void;
+
+    const STD_OUTPUT_HANDLE: u32 = -11i32 as u32;
+    const INVALID_HANDLE_VALUE: *mut c_void = -1isize as *mut c_void;
+    const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetStdHandle(std_handle: u32) -> *mut c_void;
+        fn GetConsoleMode(console: *mut c_void, mode: *mut u32) -> i32;
+        fn SetConsoleMode(console: *mut c_void, mode: u32) -> i32;
+    }
+
+    pub fn enable_ansi() -> io::Result<()> {
+        // SAFETY: these calls only read and write the mode of the process’s own console, and
+        // every handle and pointer passed is valid for the duration of the call.
+        unsafe {
+            let handle = GetStdHandle(STD_OUTPUT_HANDLE);
+            if handle.is_null() || handle == INVALID_HANDLE_VALUE {
+                return Err(io::Error::last_os_error());
+            }
+            let mut mode = 0;
+            if GetConsoleMode(handle, &mut mode) == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING == 0
+                && SetConsoleMode(handle, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) == 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use std::io;
+
+    pub fn enable_ansi() -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use canvas::Canvas;
+
+/// Iso-lines of a 2D scalar field, traced with marching squares.
+///
+/// ```
+/// use drawille::{Canvas, Contour};
+///
+/// // A bowl: the distance from the centre of a 21×21 grid.
+/// let field: Vec<f64> = (0..21 * 21).map(|i| {
+///     let (x, y) = ((i % 21) as f64 - 10.0, (i / 21) as f64 - 10.0);
+///     (x * x + y * y).sqrt()
+/// }).collect();
+///
+/// let mut canvas = Canvas::new(80, 80);
+/// Contour::new(field, 21).levels(vec![3.0, 6.0, 9.0]).draw(&mut canvas, 0, 0, 80, 80);
+/// assert!(canvas.get(40, 28));
+/// assert!(!canvas.get(40, 40));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Contour {
+    values: Vec<f64>,
+    columns: usize,
+    levels: Option<Vec<f64>>,
+}
+
+impl Contour {
+    /// Creates a new `Contour` of a field given as rows of `columns` samples each, with five
+    /// levels spread evenly between its smallest and largest values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` is zero or doesn’t divide the number of values.
+    pub fn new(values: Vec<f64>, columns: usize) -> Contour {
+        assert!(columns > 0 && values.len() % columns == 0,
+                "field of {} values isn’t made of rows of {}", values.len(), columns);
+        Contour { values, columns, levels: None }
+    }
+
+    /// Sets the values at which iso-lines are drawn, and return the `Contour` for use again.
+    pub fn levels(mut self, levels: Vec<f64>) -> Contour {
+        self.levels = Some(levels);
+        self
+    }
+
+    /// Returns the number of rows of samples in the field.
+    pub fn rows(&self) -> usize {
+        self.values.len() / self.columns
+    }
+
+    /// Draws the iso-lines onto the `Canvas`, stretching the field over a rectangle given by its
+    /// top-left corner and size in pixels.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, width: u32, height: u32) {
+        let (columns, rows) = (self.columns, self.rows());
+        if columns < 2 || rows < 2 || width == 0 || height == 0 {
+            return;
+        }
+        let sx = (width - 1) as f64 / (columns - 1) as f64;
+        let sy = (height - 1) as f64 / (rows - 1) as f64;
+        let value = |i: usize, j: usize| self.values[j * columns + i];
+        let pixel = |(gx, gy): (f64, f64)| {
+            ((x as f64 + gx * sx).round() as u32, (y as f64 + gy * sy).round() as u32)
+        };
+        for level in self.resolved_levels() {
+            for j in 0..rows - 1 {
+                for i in 0..columns - 1 {
+                    let corners = [value(i, j), value(i + 1, j), value(i + 1, j + 1),
+                                   value(i, j + 1)];
+                    for (p1, p2) in cell_segments(corners, level) {
+                        let ((x1, y1), (x2, y2)) = (pixel((i as f64 + p1.0, j as f64 + p1.1)),
+                                                    pixel((i as f64 + p2.0, j as f64 + p2.1)));
+                        canvas.line(x1, y1, x2, y2);
+                    }
+                }
+            }
+        }
+    }
+
+    fn resolved_levels(&self) -> Vec<f64> {
+        if let Some(ref levels) = self.levels {
+            return levels.clone();
+        }
+        let finite = self.values.iter().cloned().filter(|v| v.is_finite());
+        let (min, max) = finite.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+            (lo.min(v), hi.max(v))
+        });
+        if min >= max {
+            return Vec::new();
+        }
+        (1..6).map(|i| min + (max - min) * i as f64 / 6.0).collect()
+    }
+}
+
+/// Returns the segments of an iso-line crossing a grid cell, with corners given clockwise from
+/// the top left, as pairs of points relative to the cell’s top-left corner.
+///
+/// Saddles, where opposite corners are on the same side of the level, are resolved using the
+/// average of the corners as the value at the centre.
+fn cell_segments(corners: [f64; 4], level: f64) -> Vec<((f64, f64), (f64, f64))> {
+    // The position of each corner, and the edges between them in the same order: top, right,
+    // bottom and left.
+    const POSITIONS: [(f64, f64); 4] = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+    if corners.iter().any(|v| !v.is_finite()) {
+        return Vec::new();
+    }
+    let above: Vec<bool> = corners.iter().map(|&v| v >= level).collect();
+    let crossings: Vec<(f64, f64)> = (0..4).filter(|&e| above[e] != above[(e + 1) % 4]).map(|e| {
+        let (a, b) = (corners[e], corners[(e + 1) % 4]);
+        let t = (level - a) / (b - a);
+        let (pa, pb) = (POSITIONS[e], POSITIONS[(e + 1) % 4]);
+        (pa.0 + (pb.0 - pa.0) * t, pa.1 + (pb.1 - pa.1) * t)
+    }).collect();
+    match crossings.len() {
+        2 => vec![(crossings[0], crossings[1])],
+        4 => {
+            let centre = corners.iter().sum::<f64>() / 4.0;
+            if (centre >= level) == above[0] {
+                // The top-left and bottom-right corners are joined through the centre, which
+                // cuts off the other two.
+                vec![(crossings[0], crossings[1]), (crossings[2], crossings[3])]
+            } else {
+                vec![(crossings[3], crossings[0]), (crossings[1], crossings[2])]
+            }
+        }
+        _ => Vec::new(),
+    }
+}
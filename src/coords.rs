@@ -0,0 +1,268 @@
+//! Points in the three coordinate systems a `Canvas` deals in, and the conversions between
+//! them.
+//!
+//! A pixel is a single Braille dot. A cell is a character of the `Canvas`, made of
+//! `CELL_WIDTH` by `CELL_HEIGHT` pixels, counting from 0 at the top-left. A terminal position
+//! is where a cell lands on screen, counting from 1 as cursor movement escape sequences do.
+//!
+//! ```
+//! use drawille::coords::{CellPoint, PixelPoint, TermPoint};
+//!
+//! let pixel = PixelPoint::new(5, 9);
+//! assert_eq!(pixel.cell(), CellPoint::new(2, 2));
+//! assert_eq!(pixel.offset_in_cell(), (1, 1));
+//!
+//! // A canvas drawn with its top-left cell at the start of the terminal’s third line.
+//! let origin = TermPoint::new(1, 3);
+//! assert_eq!(pixel.cell().to_term(origin), TermPoint::new(3, 5));
+//! assert_eq!(TermPoint::new(3, 5).to_cell(origin), Some(pixel.cell()));
+//! ```
+
+/// The width of a cell, in pixels.
+pub const CELL_WIDTH: u32 = 2;
+
+/// The height of a cell, in pixels.
+pub const CELL_HEIGHT: u32 = 4;
+
+/// Returns the number of whole cells covered by the given width and height in pixels, rounding
+/// down as `Canvas::new` does.
+pub fn pixels_to_cells(width: u32, height: u32) -> (u32, u32) {
+    (width / CELL_WIDTH, height / CELL_HEIGHT)
+}
+
+/// Returns the width and height in pixels of the given number of cells.
+pub fn cells_to_pixels(columns: u32, rows: u32) -> (u32, u32) {
+    (columns * CELL_WIDTH, rows * CELL_HEIGHT)
+}
+
+/// The dimensions of a `Canvas`, given in whichever units are clearer, pixels or cells.
+///
+/// ```
+/// use drawille::{Canvas, Size};
+///
+/// assert_eq!(Size::cells(10, 5), Size::pixels(20, 20));
+/// let canvas = Canvas::with_size(Size::cells(10, 5));
+/// assert_eq!(canvas.rows()[0].chars().count(), 11);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Size {
+    width: u32,
+    height: u32,
+}
+
+impl Size {
+    /// Creates a new `Size` of the given width and height in pixels.
+    ///
+    /// A `Canvas` only stores whole cells, so a width that isn’t a multiple of `CELL_WIDTH`, or
+    /// a height that isn’t a multiple of `CELL_HEIGHT`, is rounded down.
+    pub fn pixels(width: u32, height: u32) -> Size {
+        Size { width, height }
+    }
+
+    /// Creates a new `Size` of the given number of columns and rows of cells.
+    pub fn cells(columns: u32, rows: u32) -> Size {
+        let (width, height) = cells_to_pixels(columns, rows);
+        Size { width, height }
+    }
+
+    /// Returns the width and height in pixels.
+    pub fn in_pixels(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Returns the number of whole columns and rows of cells.
+    pub fn in_cells(&self) -> (u32, u32) {
+        pixels_to_cells(self.width, self.height)
+    }
+}
+
+/// A pixel of a `Canvas`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PixelPoint {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl PixelPoint {
+    /// Creates a new `PixelPoint`.
+    pub fn new(x: u32, y: u32) -> PixelPoint {
+        PixelPoint { x, y }
+    }
+
+    /// Returns the cell holding the pixel.
+    pub fn cell(&self) -> CellPoint {
+        CellPoint::new(self.x / CELL_WIDTH, self.y / CELL_HEIGHT)
+    }
+
+    /// Returns where the pixel lies within its cell, with x from 0 to 1 and y from 0 to 3.
+    pub fn offset_in_cell(&self) -> (u32, u32) {
+        (self.x % CELL_WIDTH, self.y % CELL_HEIGHT)
+    }
+}
+
+impl From<(u32, u32)> for PixelPoint {
+    fn from((x, y): (u32, u32)) -> PixelPoint {
+        PixelPoint::new(x, y)
+    }
+}
+
+impl From<PixelPoint> for (u32, u32) {
+    fn from(point: PixelPoint) -> (u32, u32) {
+        (point.x, point.y)
+    }
+}
+
+/// A cell of a `Canvas`, by column and row.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CellPoint {
+    pub col: u32,
+    pub row: u32,
+}
+
+impl CellPoint {
+    /// Creates a new `CellPoint`.
+    pub fn new(col: u32, row: u32) -> CellPoint {
+        CellPoint { col, row }
+    }
+
+    /// Returns the top-left pixel of the cell.
+    pub fn top_left(&self) -> PixelPoint {
+        PixelPoint::new(self.col * CELL_WIDTH, self.row * CELL_HEIGHT)
+    }
+
+    /// Returns the bottom-right pixel of the cell.
+    pub fn bottom_right(&self) -> PixelPoint {
+        let top_left = self.top_left();
+        PixelPoint::new(top_left.x + CELL_WIDTH - 1, top_left.y + CELL_HEIGHT - 1)
+    }
+
+    /// Detects whether the cell holds the pixel.
+    pub fn contains(&self, pixel: PixelPoint) -> bool {
+        pixel.cell() == *self
+    }
+
+    /// Returns where the cell lands on the terminal, for a `Canvas` drawn with its top-left
+    /// cell at `origin`.
+    pub fn to_term(self, origin: TermPoint) -> TermPoint {
+        TermPoint::new(origin.col + self.col, origin.row + self.row)
+    }
+}
+
+impl From<PixelPoint> for CellPoint {
+    fn from(pixel: PixelPoint) -> CellPoint {
+        pixel.cell()
+    }
+}
+
+impl From<(u32, u32)> for CellPoint {
+    fn from((col, row): (u32, u32)) -> CellPoint {
+        CellPoint::new(col, row)
+    }
+}
+
+impl From<CellPoint> for (u32, u32) {
+    fn from(point: CellPoint) -> (u32, u32) {
+        (point.col, point.row)
+    }
+}
+
+/// A rectangle of cells of a `Canvas`, by its top-left cell and its size in cells.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CellRect {
+    pub col: u32,
+    pub row: u32,
+    pub columns: u32,
+    pub rows: u32,
+}
+
+impl CellRect {
+    /// Creates a new `CellRect`.
+    pub fn new(col: u32, row: u32, columns: u32, rows: u32) -> CellRect {
+        CellRect { col, row, columns, rows }
+    }
+
+    /// Returns the smallest `CellRect` holding every cell that any of a rectangle of pixels,
+    /// given by its top-left corner and size, touches.
+    ///
+    /// ```
+    /// use drawille::coords::CellRect;
+    ///
+    /// assert_eq!(CellRect::from_pixels(1, 3, 2, 2), CellRect::new(0, 0, 2, 2));
+    /// ```
+    pub fn from_pixels(x: u32, y: u32, width: u32, height: u32) -> CellRect {
+        if width == 0 || height == 0 {
+            return CellRect::new(x / CELL_WIDTH, y / CELL_HEIGHT, 0, 0);
+        }
+        let (col, row) = (x / CELL_WIDTH, y / CELL_HEIGHT);
+        let (last_col, last_row) = ((x + width - 1) / CELL_WIDTH, (y + height - 1) / CELL_HEIGHT);
+        CellRect::new(col, row, last_col - col + 1, last_row - row + 1)
+    }
+
+    /// Detects whether the rectangle holds no cells.
+    pub fn is_empty(&self) -> bool {
+        self.columns == 0 || self.rows == 0
+    }
+
+    /// Detects whether the rectangle holds the cell.
+    pub fn contains(&self, cell: CellPoint) -> bool {
+        cell.col >= self.col && cell.col - self.col < self.columns
+            && cell.row >= self.row && cell.row - self.row < self.rows
+    }
+
+    /// Detects whether the two rectangles share any cell.
+    pub fn intersects(&self, other: &CellRect) -> bool {
+        let overlaps = |a: u32, a_len: u32, b: u32, b_len: u32| {
+            (a as u64) < b as u64 + b_len as u64 && (b as u64) < a as u64 + a_len as u64
+        };
+        !self.is_empty() && !other.is_empty()
+            && overlaps(self.col, self.columns, other.col, other.columns)
+            && overlaps(self.row, self.rows, other.row, other.rows)
+    }
+}
+
+/// A position on the terminal, by column and row counting from 1, as in the escape sequence
+/// `ESC [ row ; col H`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TermPoint {
+    pub col: u32,
+    pub row: u32,
+}
+
+impl TermPoint {
+    /// Creates a new `TermPoint`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` or `row` is zero.
+    pub fn new(col: u32, row: u32) -> TermPoint {
+        assert!(col > 0 && row > 0, "terminal positions count from 1");
+        TermPoint { col, row }
+    }
+
+    /// Creates a new `TermPoint` from a column and row counting from 0, as mouse events do.
+    pub fn from_zero_based(col: u32, row: u32) -> TermPoint {
+        TermPoint { col: col + 1, row: row + 1 }
+    }
+
+    /// Returns the column and row counting from 0.
+    pub fn zero_based(&self) -> (u32, u32) {
+        (self.col - 1, self.row - 1)
+    }
+
+    /// Returns the cell of a `Canvas` drawn with its top-left cell at `origin` that lies at
+    /// this position, or `None` if it lies above or to the left of the `Canvas`.
+    pub fn to_cell(self, origin: TermPoint) -> Option<CellPoint> {
+        Some(CellPoint::new(self.col.checked_sub(origin.col)?, self.row.checked_sub(origin.row)?))
+    }
+
+    /// Returns the sequence that moves the cursor to this position.
+    pub fn cursor_to(&self) -> String {
+        format!("\x1b[{};{}H", self.row, self.col)
+    }
+}
+
+impl Default for TermPoint {
+    fn default() -> TermPoint {
+        TermPoint { col: 1, row: 1 }
+    }
+}
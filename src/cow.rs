@@ -0,0 +1,287 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::sync::Arc;
+
+use fnv::{FnvHashMap, FnvHasher};
+
+/// The log₂ of the width and height of a chunk, in cells.
+const CHUNK_BITS: u16 = 4;
+
+/// The width and height of a chunk, in cells.
+pub(crate) const CHUNK_SIZE: u32 = 1 << CHUNK_BITS;
+
+type Chunk<V> = Arc<FnvHashMap<(u16, u16), V>>;
+
+/// A map from cells to values, split into square chunks that are shared between clones and
+/// only copied when written to.
+///
+/// Cloning copies a pointer per chunk rather than every cell, so keeping many snapshots of a
+/// large `Canvas` that change a little between them costs memory only for the changes.
+#[derive(Clone)]
+pub(crate) struct CowMap<V> {
+    chunks: FnvHashMap<(u16, u16), Chunk<V>>,
+}
+
+impl<V> Default for CowMap<V> {
+    fn default() -> CowMap<V> {
+        CowMap { chunks: FnvHashMap::default() }
+    }
+}
+
+fn chunk_of((col, row): (u16, u16)) -> (u16, u16) {
+    (col >> CHUNK_BITS, row >> CHUNK_BITS)
+}
+
+impl<V: Clone> CowMap<V> {
+    pub(crate) fn get(&self, key: &(u16, u16)) -> Option<&V> {
+        self.chunks.get(&chunk_of(*key)).and_then(|chunk| chunk.get(key))
+    }
+
+    pub(crate) fn insert(&mut self, key: (u16, u16), value: V) -> Option<V> {
+        let chunk = self.chunks.entry(chunk_of(key)).or_default();
+        Arc::make_mut(chunk).insert(key, value)
+    }
+
+    pub(crate) fn remove(&mut self, key: &(u16, u16)) -> Option<V> {
+        let index = chunk_of(*key);
+        let chunk = self.chunks.get_mut(&index)?;
+        // Look before writing, so that removing nothing doesn’t copy a shared chunk.
+        if !chunk.contains_key(key) {
+            return None;
+        }
+        let value = Arc::make_mut(chunk).remove(key);
+        if chunk.is_empty() {
+            self.chunks.remove(&index);
+        }
+        value
+    }
+
+    /// Detects whether the chunk holding a cell has anything in it.
+    pub(crate) fn has_chunk(&self, key: (u16, u16)) -> bool {
+        self.chunks.contains_key(&chunk_of(key))
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.chunks.clear();
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.chunks.values().map(|chunk| chunk.len()).sum()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&(u16, u16), &V)> {
+        self.chunks.values().flat_map(|chunk| chunk.iter())
+    }
+
+    pub(crate) fn keys(&self) -> impl Iterator<Item = &(u16, u16)> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Estimates the heap memory used, counting shared chunks in full.
+    pub(crate) fn heap_bytes(&self) -> usize {
+        let entry = mem::size_of::<((u16, u16), V)>() + 1;
+        let chunks = self.chunks.capacity() * (mem::size_of::<((u16, u16), Chunk<V>)>() + 1);
+        chunks + self.chunks.values()
+            .map(|chunk| mem::size_of::<FnvHashMap<(u16, u16), V>>() + chunk.capacity() * entry)
+            .sum::<usize>()
+    }
+
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.chunks.shrink_to_fit();
+        for chunk in self.chunks.values_mut() {
+            // Shared chunks are left alone, since shrinking them would mean copying them.
+            if let Some(chunk) = Arc::get_mut(chunk) {
+                chunk.shrink_to_fit();
+            }
+        }
+    }
+}
+
+impl<V: Clone + Hash> CowMap<V> {
+    /// Hashes the contents, in a way that doesn’t depend on the order of the entries.
+    pub(crate) fn content_hash(&self) -> u64 {
+        self.iter().fold(0, |sum: u64, entry| sum.wrapping_add(mix(&entry)))
+    }
+}
+
+/// Hashes an entry for summing into a hash of a whole map.
+fn mix<T: Hash>(entry: &T) -> u64 {
+    let mut hasher = FnvHasher::default();
+    entry.hash(&mut hasher);
+    // Mix the bits, since the entries are summed and FNV’s low bits are weak.
+    let mut h = hasher.finish();
+    h = (h ^ (h >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    h ^ (h >> 31)
+}
+
+// Compared cell by cell, so that maps with the same contents are equal however they have been
+// chunked and shared.
+impl<V: Clone + PartialEq> PartialEq for CowMap<V> {
+    fn eq(&self, other: &CowMap<V>) -> bool {
+        self.len() == other.len() && self.iter().all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<V: Clone + Eq> Eq for CowMap<V> {}
+
+impl<V: Clone + fmt::Debug> fmt::Debug for CowMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// The number of cells in a chunk.
+const CHUNK_CELLS: usize = 1 << (2 * CHUNK_BITS);
+
+/// The dot bits of a chunk of cells, stored densely so that a pixel is found by indexing
+/// rather than hashing, along with which cells hold an entry at all.
+#[derive(Clone)]
+struct DotChunk {
+    bits: [u8; CHUNK_CELLS],
+    present: [u64; CHUNK_CELLS / 64],
+    len: u16,
+}
+
+impl Default for DotChunk {
+    fn default() -> DotChunk {
+        DotChunk { bits: [0; CHUNK_CELLS], present: [0; CHUNK_CELLS / 64], len: 0 }
+    }
+}
+
+impl DotChunk {
+    fn contains(&self, i: usize) -> bool {
+        self.present[i / 64] & 1 << (i % 64) != 0
+    }
+
+    fn mark(&mut self, i: usize) {
+        if !self.contains(i) {
+            self.present[i / 64] |= 1 << (i % 64);
+            self.len += 1;
+        }
+    }
+}
+
+/// Returns the index of a cell within its chunk.
+fn index_of((col, row): (u16, u16)) -> usize {
+    let mask = (1 << CHUNK_BITS) - 1;
+    ((row & mask) << CHUNK_BITS | (col & mask)) as usize
+}
+
+/// Like a `CowMap<u8>` for the dot bits of cells, with each chunk held as a dense array, since
+/// setting pixels is by far the most common thing done to a `Canvas`.
+#[derive(Clone, Default)]
+pub(crate) struct DotMap {
+    chunks: FnvHashMap<(u16, u16), Arc<DotChunk>>,
+}
+
+impl DotMap {
+    pub(crate) fn get(&self, key: &(u16, u16)) -> Option<&u8> {
+        let chunk = self.chunks.get(&chunk_of(*key))?;
+        let i = index_of(*key);
+        if chunk.contains(i) { Some(&chunk.bits[i]) } else { None }
+    }
+
+    /// Returns the bits of a cell, inserting `default` first if it has none.
+    pub(crate) fn entry_or(&mut self, key: (u16, u16), default: u8) -> &mut u8 {
+        let chunk = Arc::make_mut(self.chunks.entry(chunk_of(key)).or_default());
+        let i = index_of(key);
+        if !chunk.contains(i) {
+            chunk.mark(i);
+            chunk.bits[i] = default;
+        }
+        &mut chunk.bits[i]
+    }
+
+    pub(crate) fn insert(&mut self, key: (u16, u16), bits: u8) -> Option<u8> {
+        let chunk = Arc::make_mut(self.chunks.entry(chunk_of(key)).or_default());
+        let i = index_of(key);
+        let old = if chunk.contains(i) { Some(chunk.bits[i]) } else { None };
+        chunk.mark(i);
+        chunk.bits[i] = bits;
+        old
+    }
+
+    pub(crate) fn remove(&mut self, key: &(u16, u16)) -> Option<u8> {
+        let index = chunk_of(*key);
+        let chunk = self.chunks.get_mut(&index)?;
+        let i = index_of(*key);
+        // Look before writing, so that removing nothing doesn’t copy a shared chunk.
+        if !chunk.contains(i) {
+            return None;
+        }
+        let chunk = Arc::make_mut(chunk);
+        chunk.present[i / 64] &= !(1 << (i % 64));
+        chunk.len -= 1;
+        let bits = mem::replace(&mut chunk.bits[i], 0);
+        if chunk.len == 0 {
+            self.chunks.remove(&index);
+        }
+        Some(bits)
+    }
+
+    /// Detects whether the chunk holding a cell has anything in it.
+    pub(crate) fn has_chunk(&self, key: (u16, u16)) -> bool {
+        self.chunks.contains_key(&chunk_of(key))
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.chunks.clear();
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.chunks.values().map(|chunk| chunk.len as usize).sum()
+    }
+
+    pub(crate) fn iter<'a>(&'a self) -> impl Iterator<Item = ((u16, u16), u8)> + 'a {
+        self.chunks.iter().flat_map(|(&(cx, cy), chunk)| {
+            (0..CHUNK_CELLS).filter(move |&i| chunk.contains(i)).map(move |i| {
+                let (dx, dy) = ((i as u16) & ((1 << CHUNK_BITS) - 1), (i as u16) >> CHUNK_BITS);
+                ((cx << CHUNK_BITS | dx, cy << CHUNK_BITS | dy), chunk.bits[i])
+            })
+        })
+    }
+
+    pub(crate) fn keys<'a>(&'a self) -> impl Iterator<Item = (u16, u16)> + 'a {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub(crate) fn values<'a>(&'a self) -> impl Iterator<Item = u8> + 'a {
+        self.iter().map(|(_, bits)| bits)
+    }
+
+    /// Estimates the heap memory used, counting shared chunks in full.
+    pub(crate) fn heap_bytes(&self) -> usize {
+        let chunk = mem::size_of::<DotChunk>() + 2 * mem::size_of::<usize>();
+        self.chunks.capacity() * (mem::size_of::<((u16, u16), Arc<DotChunk>)>() + 1)
+            + self.chunks.len() * chunk
+    }
+
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.chunks.shrink_to_fit();
+    }
+
+    /// Hashes the contents, in a way that doesn’t depend on the order of the entries.
+    pub(crate) fn content_hash(&self) -> u64 {
+        self.iter().fold(0, |sum: u64, entry| sum.wrapping_add(mix(&entry)))
+    }
+}
+
+impl PartialEq for DotMap {
+    fn eq(&self, other: &DotMap) -> bool {
+        self.len() == other.len() && self.iter().all(|(key, bits)| other.get(&key) == Some(&bits))
+    }
+}
+
+impl Eq for DotMap {}
+
+impl fmt::Debug for DotMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
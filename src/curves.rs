@@ -0,0 +1,94 @@
+use std::f32::consts::PI;
+
+use turtle::Turtle;
+
+impl Turtle {
+    /// Traces the curve `f(t)` for `t` from `start` to `end`, in `samples` straight steps, as
+    /// offsets from the `Turtle`’s position, and then returns it to where it was.
+    ///
+    /// The brush is lifted on the way to the start of the curve and on the way back, and the
+    /// curve is only drawn if the brush was down to begin with.
+    ///
+    /// ```
+    /// use drawille::Turtle;
+    ///
+    /// let mut turtle = Turtle::new(20.0, 20.0);
+    /// turtle.curve(0.0, 1.0, 10, |t| (t * 10.0, 0.0));
+    /// assert_eq!((turtle.x, turtle.y), (20.0, 20.0));
+    /// assert!(turtle.cvs.get(30, 20) && !turtle.cvs.get(19, 20));
+    /// ```
+    pub fn curve<F: Fn(f32) -> (f32, f32)>(&mut self, start: f32, end: f32, samples: u32, f: F) {
+        let (cx, cy, brush) = (self.x, self.y, self.brush);
+        let samples = samples.max(1);
+        let point = |i: u32| {
+            let (dx, dy) = f(start + (end - start) * i as f32 / samples as f32);
+            (cx + dx, cy + dy)
+        };
+        self.up();
+        let (x, y) = point(0);
+        self.teleport(x, y);
+        self.brush = brush;
+        for i in 1..=samples {
+            let (x, y) = point(i);
+            self.teleport(x, y);
+        }
+        self.up();
+        self.teleport(cx, cy);
+        self.brush = brush;
+    }
+
+    /// Traces a spirograph curve around the `Turtle`: the path of a pen `offset` from the
+    /// centre of a wheel of `wheel` radius, rolling round inside a ring of `ring` radius.
+    ///
+    /// The curve is traced until it closes, which takes `wheel / gcd(ring, wheel)` laps.
+    pub fn spiro(&mut self, ring: u32, wheel: u32, offset: f32, samples: u32) {
+        if wheel == 0 {
+            return;
+        }
+        let laps = wheel / gcd(ring, wheel);
+        let (big, small) = (ring as f32, wheel as f32);
+        let k = (big - small) / small;
+        self.curve(0.0, 2.0 * PI * laps as f32, samples, |t| {
+            ((big - small) * t.cos() + offset * (k * t).cos(),
+             (big - small) * t.sin() - offset * (k * t).sin())
+        });
+    }
+
+    /// Traces a Lissajous figure around the `Turtle`, `width` by `height` in size, with `a`
+    /// swings across for every `b` swings up and down, the first starting `phase` radians
+    /// ahead.
+    pub fn lissajous(&mut self, a: f32, b: f32, phase: f32, width: f32, height: f32,
+                     samples: u32) {
+        self.curve(0.0, 2.0 * PI, samples, |t| {
+            (width / 2.0 * (a * t + phase).sin(), height / 2.0 * (b * t).sin())
+        });
+    }
+
+    /// Traces a rose around the `Turtle`, the curve `r = radius × cos(kθ)`.
+    ///
+    /// A whole `k` gives `k` petals if it is odd and `2k` if it is even, and a fraction with a
+    /// small denominator gives overlapping petals, traced until the curve closes.
+    ///
+    /// ```
+    /// use drawille::Turtle;
+    ///
+    /// let mut turtle = Turtle::new(20.0, 20.0);
+    /// turtle.rose(3.0, 15.0, 300);
+    /// assert!(turtle.cvs.get(35, 20) && !turtle.cvs.get(5, 20));
+    /// ```
+    pub fn rose(&mut self, k: f32, radius: f32, samples: u32) {
+        // A rose with k = n/d in lowest terms closes after at most d turns.
+        let turns = (1..=64).find(|&d| {
+            let n = k * d as f32;
+            (n - n.round()).abs() < 1e-4
+        }).unwrap_or(1);
+        self.curve(0.0, 2.0 * PI * turns as f32, samples, |t| {
+            let r = radius * (k * t).cos();
+            (r * t.cos(), r * t.sin())
+        });
+    }
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
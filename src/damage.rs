@@ -0,0 +1,63 @@
+use std::cmp;
+
+use coords::CellRect;
+
+/// The span of columns that has changed in each row of cells, which has no bearing on whether
+/// two canvases are equal.
+///
+/// A row whose first column is past its last is clean.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Damage(Vec<(u16, u16)>);
+
+const CLEAN: (u16, u16) = (u16::MAX, 0);
+
+impl Damage {
+    /// Marks the columns from `first` to `last` inclusive of the row as changed.
+    pub(crate) fn mark(&mut self, row: u16, first: u16, last: u16) {
+        let row = row as usize;
+        if row >= self.0.len() {
+            self.0.resize(row + 1, CLEAN);
+        }
+        let span = &mut self.0[row];
+        *span = (cmp::min(span.0, first), cmp::max(span.1, last));
+    }
+
+    /// Marks every cell of the rectangle as changed, clipped to what can be stored.
+    pub(crate) fn mark_rect(&mut self, rect: CellRect) {
+        let max = u16::MAX as u32;
+        if rect.is_empty() || rect.col > max || rect.row > max {
+            return;
+        }
+        let last_col = cmp::min(rect.col as u64 + rect.columns as u64 - 1, max as u64) as u16;
+        let last_row = cmp::min(rect.row as u64 + rect.rows as u64 - 1, max as u64) as u16;
+        for row in rect.row as u16..=last_row {
+            self.mark(row, rect.col as u16, last_col);
+        }
+    }
+
+    /// Detects whether any cell of the rectangle has changed.
+    pub(crate) fn is_dirty(&self, rect: CellRect) -> bool {
+        self.spans().any(|(row, first, last)| {
+            rect.intersects(&CellRect::new(first, row, last - first + 1, 1))
+        })
+    }
+
+    /// Returns each row that has changed, with its first and last changed column.
+    pub(crate) fn spans(&self) -> impl Iterator<Item = (u32, u32, u32)> + '_ {
+        self.0.iter().enumerate().filter(|&(_, span)| span.0 <= span.1)
+            .map(|(row, &(first, last))| (row as u32, first as u32, last as u32))
+    }
+
+    /// Marks everything as unchanged.
+    pub(crate) fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+impl PartialEq for Damage {
+    fn eq(&self, _: &Damage) -> bool {
+        true
+    }
+}
+
+impl Eq for Damage {}
@@ -0,0 +1,359 @@
+//! Animated demos, and a `Runner` that cycles through them like a screensaver.
+//!
+//! A `Demo` is set up for a size with `init`, moved on by fixed steps with `update`, and drawn
+//! with `render`. The demos here are starting points for animations of your own, and
+//! `Runner::record` plays demos without waiting, which makes them handy for visual tests.
+//!
+//! ```
+//! use drawille::demo::{Life, Runner};
+//!
+//! let mut runner = Runner::all(80, 40);
+//! runner.add(Life::new(7));
+//! let animation = runner.record(3);
+//! assert_eq!(animation.len(), 15);
+//! assert!(animation.frames()[0].0.starts_with("cube"));
+//! ```
+
+use std::f64::consts::PI;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use animation::Animation;
+use canvas::Canvas;
+use particles::ParticleSystem;
+use plasma;
+use style::Color;
+use timer::FrameTimer;
+
+/// An animation that a `Runner` can play.
+pub trait Demo {
+    /// Returns the name shown while the demo plays.
+    fn name(&self) -> &str;
+
+    /// Starts the demo afresh, to be drawn `width` by `height` pixels.
+    fn init(&mut self, width: u32, height: u32);
+
+    /// Moves the demo on by `dt` seconds.
+    fn update(&mut self, dt: f64);
+
+    /// Draws the demo onto a cleared `Canvas`.
+    fn render(&self, canvas: &mut Canvas);
+}
+
+/// Plays `Demo`s one after another, each for the same length of time, over and over.
+pub struct Runner {
+    demos: Vec<Box<dyn Demo>>,
+    width: u32,
+    height: u32,
+    fps: u32,
+    length: Duration,
+}
+
+impl Runner {
+    /// Creates a new `Runner` without demos, drawing them `width` by `height` pixels at 30
+    /// frames per second for 10 seconds each.
+    pub fn new(width: u32, height: u32) -> Runner {
+        Runner { demos: Vec::new(), width, height, fps: 30, length: Duration::from_secs(10) }
+    }
+
+    /// Creates a new `Runner` like `new`, with every demo in this module added.
+    pub fn all(width: u32, height: u32) -> Runner {
+        let mut runner = Runner::new(width, height);
+        runner.add(Cube::new());
+        runner.add(Fireworks::new(1));
+        runner.add(Life::new(1));
+        runner.add(Plasma::new());
+        runner
+    }
+
+    /// Sets the number of updates and frames per second, and return it for use again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fps` is zero.
+    pub fn fps(mut self, fps: u32) -> Runner {
+        assert!(fps > 0, "a Runner needs a positive frame rate");
+        self.fps = fps;
+        self
+    }
+
+    /// Sets how long each demo plays for, and return it for use again.
+    pub fn length(mut self, length: Duration) -> Runner {
+        self.length = length;
+        self
+    }
+
+    /// Adds a demo after those added before it.
+    pub fn add<D: Demo + 'static>(&mut self, demo: D) {
+        self.demos.push(Box::new(demo));
+    }
+
+    /// Returns the number of demos.
+    pub fn len(&self) -> usize {
+        self.demos.len()
+    }
+
+    /// Detects whether no demos have been added.
+    pub fn is_empty(&self) -> bool {
+        self.demos.is_empty()
+    }
+
+    /// Plays each demo for `frames` frames, without waiting between them, and returns what was
+    /// drawn. Playing demos with the same seeds records the same frames.
+    pub fn record(&mut self, frames: u32) -> Animation {
+        let mut animation = Animation::new();
+        let step = Duration::from_secs(1) / self.fps;
+        let mut canvas = Canvas::new(self.width, self.height);
+        for demo in &mut self.demos {
+            demo.init(self.width, self.height);
+            for _ in 0..frames {
+                demo.update(step.as_secs_f64());
+                draw(&**demo, &mut canvas);
+                animation.push(&canvas, step);
+            }
+        }
+        animation
+    }
+
+    /// Plays the demos on a terminal in real time, round `cycles` times or forever if `None`,
+    /// drawing each frame over the last from the top-left corner.
+    pub fn run<W: Write>(&mut self, out: &mut W, cycles: Option<u32>) -> io::Result<()> {
+        let mut timer = FrameTimer::new(self.fps);
+        let mut canvas = Canvas::new(self.width, self.height);
+        let mut frame = String::new();
+        write!(out, "\x1b[2J")?;
+        let mut cycle = 0;
+        while !self.demos.is_empty() && cycles.map_or(true, |cycles| cycle < cycles) {
+            for demo in &mut self.demos {
+                demo.init(self.width, self.height);
+                let start = Instant::now();
+                while start.elapsed() < self.length {
+                    for _ in 0..timer.tick() {
+                        demo.update(timer.dt());
+                    }
+                    draw(&**demo, &mut canvas);
+                    frame.clear();
+                    canvas.frame_into(&mut frame);
+                    write!(out, "\x1b[H{}", frame.replace('\n', "\r\n"))?;
+                    out.flush()?;
+                    timer.wait();
+                }
+            }
+            cycle += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Draws a demo and its name onto the cleared `Canvas`.
+fn draw(demo: &dyn Demo, canvas: &mut Canvas) {
+    canvas.clear();
+    demo.render(canvas);
+    let name = demo.name();
+    canvas.text(0, 0, name.chars().count() as u32 * 2, name);
+}
+
+/// A wireframe cube spinning in perspective.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cube {
+    size: (u32, u32),
+    angle: f64,
+}
+
+impl Cube {
+    /// Creates a new `Cube`.
+    pub fn new() -> Cube {
+        Cube::default()
+    }
+}
+
+impl Demo for Cube {
+    fn name(&self) -> &str {
+        "cube"
+    }
+
+    fn init(&mut self, width: u32, height: u32) {
+        *self = Cube { size: (width, height), angle: 0.0 };
+    }
+
+    fn update(&mut self, dt: f64) {
+        self.angle = (self.angle + dt) % (2.0 * PI);
+    }
+
+    fn render(&self, canvas: &mut Canvas) {
+        let (w, h) = (self.size.0 as f64, self.size.1 as f64);
+        let scale = w.min(h) * 1.2;
+        let (sin_y, cos_y) = self.angle.sin_cos();
+        let (sin_x, cos_x) = (self.angle * 0.7).sin_cos();
+        let corner = |i: u32| {
+            let (x, y, z) = ([-1.0, 1.0][i as usize & 1], [-1.0, 1.0][i as usize >> 1 & 1],
+                             [-1.0, 1.0][i as usize >> 2 & 1]);
+            let (x, z) = (x * cos_y - z * sin_y, x * sin_y + z * cos_y);
+            let (y, z) = (y * cos_x - z * sin_x, y * sin_x + z * cos_x);
+            let depth = z + 5.0;
+            let px = (w / 2.0 + x * scale / depth).round().max(0.0);
+            let py = (h / 2.0 + y * scale / depth).round().max(0.0);
+            (px as u32, py as u32)
+        };
+        // Corners that differ in exactly one coordinate share an edge.
+        for a in 0..8 {
+            for bit in &[1, 2, 4] {
+                if a & bit == 0 {
+                    let ((x1, y1), (x2, y2)) = (corner(a), corner(a | bit));
+                    canvas.line(x1, y1, x2, y2);
+                }
+            }
+        }
+    }
+}
+
+/// Fireworks bursting at random, falling under gravity with short trails.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fireworks {
+    seed: u64,
+    rng: u64,
+    size: (u32, u32),
+    particles: ParticleSystem,
+    next: f64,
+}
+
+impl Fireworks {
+    /// Creates new `Fireworks`, bursting where the given seed says.
+    pub fn new(seed: u64) -> Fireworks {
+        Fireworks { seed, rng: seed, size: (0, 0), particles: ParticleSystem::new(), next: 0.0 }
+    }
+}
+
+impl Demo for Fireworks {
+    fn name(&self) -> &str {
+        "fireworks"
+    }
+
+    fn init(&mut self, width: u32, height: u32) {
+        *self = Fireworks::new(self.seed);
+        self.size = (width, height);
+        self.particles = ParticleSystem::new().gravity(0.0, height as f32 / 2.0).trails(3);
+    }
+
+    fn update(&mut self, dt: f64) {
+        self.next -= dt;
+        if self.next <= 0.0 {
+            self.next += 0.5 + uniform(&mut self.rng) * 0.5;
+            let (w, h) = (self.size.0 as f64, self.size.1 as f64);
+            let (x, y) = (w * (0.2 + 0.6 * uniform(&mut self.rng)),
+                          h * (0.15 + 0.35 * uniform(&mut self.rng)));
+            let speed = w.min(h) * 0.4;
+            for _ in 0..60 {
+                let angle = uniform(&mut self.rng) * 2.0 * PI;
+                let v = speed * (0.3 + 0.7 * uniform(&mut self.rng));
+                let life = 1.0 + uniform(&mut self.rng);
+                self.particles.spawn(x as f32, y as f32, (v * angle.cos()) as f32,
+                                     (v * angle.sin()) as f32, life as f32);
+            }
+        }
+        self.particles.step(dt as f32);
+    }
+
+    fn render(&self, canvas: &mut Canvas) {
+        self.particles.draw(canvas);
+    }
+}
+
+/// Conway’s Game of Life, one pixel per cell, on a board that wraps round at the edges.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Life {
+    seed: u64,
+    size: (u32, u32),
+    cells: Vec<bool>,
+}
+
+impl Life {
+    /// Creates a new `Life`, starting with random cells chosen by the given seed.
+    pub fn new(seed: u64) -> Life {
+        Life { seed, size: (0, 0), cells: Vec::new() }
+    }
+}
+
+impl Demo for Life {
+    fn name(&self) -> &str {
+        "life"
+    }
+
+    fn init(&mut self, width: u32, height: u32) {
+        let mut rng = self.seed;
+        self.size = (width, height);
+        self.cells = (0..width * height).map(|_| uniform(&mut rng) < 0.3).collect();
+    }
+
+    fn update(&mut self, _dt: f64) {
+        let (w, h) = (self.size.0 as usize, self.size.1 as usize);
+        let alive = |x: usize, y: usize| self.cells[(y % h) * w + x % w] as u8;
+        let cells = (0..w * h).map(|i| {
+            let (x, y) = (i % w + w, i / w + h);
+            let neighbours = alive(x - 1, y - 1) + alive(x, y - 1) + alive(x + 1, y - 1)
+                + alive(x - 1, y) + alive(x + 1, y)
+                + alive(x - 1, y + 1) + alive(x, y + 1) + alive(x + 1, y + 1);
+            neighbours == 3 || (neighbours == 2 && self.cells[i])
+        }).collect();
+        self.cells = cells;
+    }
+
+    fn render(&self, canvas: &mut Canvas) {
+        let w = self.size.0.max(1);
+        for (i, _) in self.cells.iter().enumerate().filter(|&(_, &alive)| alive) {
+            canvas.set(i as u32 % w, i as u32 / w);
+        }
+    }
+}
+
+/// The classic plasma effect, drawn with a `drawille::Plasma` in cycling colours.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Plasma {
+    size: (u32, u32),
+    time: f64,
+    effect: plasma::Plasma,
+}
+
+impl Plasma {
+    /// Creates a new `Plasma`.
+    pub fn new() -> Plasma {
+        let palette = vec![Color::Blue, Color::Cyan, Color::Green, Color::Yellow, Color::Red,
+                           Color::Magenta];
+        Plasma { size: (0, 0), time: 0.0, effect: plasma::Plasma::new().palette(palette) }
+    }
+}
+
+impl Default for Plasma {
+    fn default() -> Plasma {
+        Plasma::new()
+    }
+}
+
+impl Demo for Plasma {
+    fn name(&self) -> &str {
+        "plasma"
+    }
+
+    fn init(&mut self, width: u32, height: u32) {
+        self.size = (width, height);
+        self.time = 0.0;
+    }
+
+    fn update(&mut self, dt: f64) {
+        self.time += dt;
+    }
+
+    fn render(&self, canvas: &mut Canvas) {
+        self.effect.draw(canvas, 0, 0, self.size.0, self.size.1, self.time);
+    }
+}
+
+/// Returns a random number from 0 up to but not including 1, moving on a SplitMix64 generator.
+fn uniform(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
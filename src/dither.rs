@@ -0,0 +1,136 @@
+use canvas::Canvas;
+
+/// A pattern of thresholds for showing levels of grey as densities of dots.
+///
+/// Ordered dithers give the fine, regular texture usual in terminal graphics. Larger Bayer
+/// matrices show more distinct levels, at the cost of a coarser pattern.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Dither {
+    /// A 2×2 Bayer matrix, showing 5 levels.
+    Bayer2,
+    /// A 4×4 Bayer matrix, showing 17 levels.
+    #[default]
+    Bayer4,
+    /// An 8×8 Bayer matrix, showing 65 levels.
+    Bayer8,
+    /// An irregular pattern without the cross-hatching of the Bayer matrices, from the R2
+    /// low-discrepancy sequence, which approximates blue noise.
+    BlueNoise,
+}
+
+impl Dither {
+    /// Returns the threshold at a pixel, between 0 and 1: a level above it sets the pixel.
+    ///
+    /// ```
+    /// use drawille::Dither;
+    ///
+    /// assert_eq!(Dither::Bayer2.threshold(1, 0), 0.625);
+    /// assert_eq!(Dither::Bayer2.threshold(3, 2), 0.625);
+    /// ```
+    pub fn threshold(&self, x: u32, y: u32) -> f64 {
+        let bits = match *self {
+            Dither::Bayer2 => 1,
+            Dither::Bayer4 => 2,
+            Dither::Bayer8 => 3,
+            Dither::BlueNoise => {
+                // The plastic number’s reciprocals spread successive points most evenly.
+                let t = 0.754_877_666_246_692_7 * x as f64 + 0.569_840_290_998_053_3 * y as f64;
+                return t.fract();
+            }
+        };
+        // The lowest bits of the coordinates pick the most significant part of the value.
+        let mut value = 0;
+        for i in 0..bits {
+            let (xb, yb) = ((x >> i) & 1, (y >> i) & 1);
+            value = value * 4 + (2 * (xb ^ yb) + yb);
+        }
+        (value as f64 + 0.5) / (1u32 << (2 * bits)) as f64
+    }
+}
+
+/// Turns levels of brightness into densities of dots, with a `Dither` and gamma correction.
+///
+/// The eye doesn’t see brightness in proportion to the number of dots set, and images store
+/// brightness gamma-encoded. A gamma of 2.2 converts the usual sRGB brightness into the
+/// fraction of dots to set, so that mid-grey doesn’t come out too dark.
+///
+/// ```
+/// use drawille::{Canvas, Density, Dither};
+///
+/// let mut canvas = Canvas::new(0, 0);
+/// canvas.fill_level(0, 0, 8, 8, 0.5, &Density::new(Dither::Bayer4));
+/// assert_eq!(canvas.pixels().count(), 32);
+/// canvas.clear();
+/// canvas.fill_level(0, 0, 8, 8, 0.5, &Density::new(Dither::Bayer4).gamma(2.2));
+/// assert_eq!(canvas.pixels().count(), 12);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Density {
+    dither: Dither,
+    gamma: f64,
+}
+
+impl Density {
+    /// Creates a new `Density` with the given dither and no gamma correction.
+    pub fn new(dither: Dither) -> Density {
+        Density { dither, gamma: 1.0 }
+    }
+
+    /// Sets the gamma that levels are raised to before dithering, and return it for use again.
+    pub fn gamma(mut self, gamma: f64) -> Density {
+        self.gamma = gamma;
+        self
+    }
+
+    /// Returns the dither.
+    pub fn dither(&self) -> Dither {
+        self.dither
+    }
+
+    /// Detects whether a pixel showing the given level, from 0 for black to 1 for white, is set.
+    pub fn is_set(&self, x: u32, y: u32, level: f64) -> bool {
+        let level = level.clamp(0.0, 1.0).powf(self.gamma);
+        level > self.dither.threshold(x, y)
+    }
+}
+
+impl Default for Density {
+    fn default() -> Density {
+        Density::new(Dither::default())
+    }
+}
+
+impl Canvas {
+    /// Creates a new `Canvas` from a greyscale image like `from_luma`, showing its brightness
+    /// as densities of dots.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `luma` holds fewer than `width * height` bytes.
+    pub fn from_luma_density(width: u32, height: u32, luma: &[u8], density: &Density) -> Canvas {
+        let (w, h) = (width as usize, height as usize);
+        assert!(luma.len() >= w * h, "luma buffer is smaller than width * height");
+        let mut canvas = Canvas::new(width, height);
+        for y in 0..h {
+            for x in 0..w {
+                if density.is_set(x as u32, y as u32, luma[y * w + x] as f64 / 255.0) {
+                    canvas.set(x as u32, y as u32);
+                }
+            }
+        }
+        canvas
+    }
+
+    /// Fills a rectangle, given by its top-left corner and size, with the density of dots that
+    /// shows `level`, from 0 for empty to 1 for full, such as for a cell of a heat map.
+    pub fn fill_level(&mut self, x: u32, y: u32, width: u32, height: u32, level: f64,
+                      density: &Density) {
+        for py in y..y + height {
+            for px in x..x + width {
+                if density.is_set(px, py, level) {
+                    self.set(px, py);
+                }
+            }
+        }
+    }
+}
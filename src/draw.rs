@@ -0,0 +1,152 @@
+use canvas::Canvas;
+use stroke::LineStyle;
+use style::{Span, Style};
+
+/// Draws onto a `Canvas` with calls chained one after another, as returned by `Canvas::draw`.
+///
+/// Each method draws just like the `Canvas` method of the same name.
+///
+/// ```
+/// use drawille::Canvas;
+///
+/// let mut canvas = Canvas::new(20, 8);
+/// canvas.draw()
+///     .rect(0, 0, 20, 8)
+///     .line(0, 0, 19, 7)
+///     .text(4, 4, 10, "hi");
+/// assert!(canvas.get(19, 0) && canvas.get(10, 4) && canvas.rows()[1].contains("hi"));
+/// ```
+#[derive(Debug)]
+pub struct Draw<'a> {
+    canvas: &'a mut Canvas,
+}
+
+impl<'a> Draw<'a> {
+    /// Returns the `Canvas` being drawn onto.
+    pub fn canvas(self) -> &'a mut Canvas {
+        self.canvas
+    }
+
+    /// Sets a pixel.
+    pub fn set(self, x: u32, y: u32) -> Draw<'a> {
+        self.canvas.set(x, y);
+        self
+    }
+
+    /// Sets a pixel, and gives its cell the provided style.
+    pub fn set_styled(self, x: u32, y: u32, style: Style) -> Draw<'a> {
+        self.canvas.set_styled(x, y, style);
+        self
+    }
+
+    /// Deletes a pixel.
+    pub fn unset(self, x: u32, y: u32) -> Draw<'a> {
+        self.canvas.unset(x, y);
+        self
+    }
+
+    /// Toggles a pixel.
+    pub fn toggle(self, x: u32, y: u32) -> Draw<'a> {
+        self.canvas.toggle(x, y);
+        self
+    }
+
+    /// Draws a line.
+    pub fn line(self, x1: u32, y1: u32, x2: u32, y2: u32) -> Draw<'a> {
+        self.canvas.line(x1, y1, x2, y2);
+        self
+    }
+
+    /// Draws a line with the given dash pattern and style.
+    pub fn line_styled(self, x1: u32, y1: u32, x2: u32, y2: u32, style: &LineStyle) -> Draw<'a> {
+        self.canvas.line_styled(x1, y1, x2, y2, style);
+        self
+    }
+
+    /// Draws lines joining each point to the next.
+    pub fn polyline(self, points: &[(u32, u32)]) -> Draw<'a> {
+        self.canvas.polyline_styled(points, &LineStyle::solid());
+        self
+    }
+
+    /// Sets every pixel in a column from `y1` to `y2` inclusive.
+    pub fn vline(self, x: u32, y1: u32, y2: u32) -> Draw<'a> {
+        self.canvas.vline(x, y1, y2);
+        self
+    }
+
+    /// Draws the outline of a rectangle with its top-left corner at `(x, y)`.
+    pub fn rect(self, x: u32, y: u32, width: u32, height: u32) -> Draw<'a> {
+        self.canvas.rect_styled(x, y, width, height, &LineStyle::solid());
+        self
+    }
+
+    /// Draws the outline of a rectangle with the given dash pattern and style.
+    pub fn rect_styled(self, x: u32, y: u32, width: u32, height: u32, style: &LineStyle)
+        -> Draw<'a>
+    {
+        self.canvas.rect_styled(x, y, width, height, style);
+        self
+    }
+
+    /// Draws a circle, which looks round on screen.
+    pub fn circle(self, x: u32, y: u32, radius: u32) -> Draw<'a> {
+        self.canvas.circle(x, y, radius);
+        self
+    }
+
+    /// Sets a letter.
+    pub fn set_char(self, x: u32, y: u32, c: char) -> Draw<'a> {
+        self.canvas.set_char(x, y, c);
+        self
+    }
+
+    /// Sets the style of a cell, without changing what it holds.
+    pub fn set_cell_style(self, x: u32, y: u32, style: Style) -> Draw<'a> {
+        self.canvas.set_cell_style(x, y, style);
+        self
+    }
+
+    /// Draws text.
+    pub fn text(self, x: u32, y: u32, max_width: u32, text: &str) -> Draw<'a> {
+        self.canvas.text(x, y, max_width, text);
+        self
+    }
+
+    /// Draws styled text.
+    pub fn text_styled(self, x: u32, y: u32, max_width: u32, spans: &[Span]) -> Draw<'a> {
+        self.canvas.text_styled(x, y, max_width, spans);
+        self
+    }
+
+    /// Draws text on the overlay.
+    pub fn overlay_text(self, x: u32, y: u32, max_width: u32, text: &str) -> Draw<'a> {
+        self.canvas.overlay_text(x, y, max_width, text);
+        self
+    }
+
+    /// Sets the pixel at the given world coordinates.
+    pub fn plot(self, x: f64, y: f64) -> Draw<'a> {
+        self.canvas.plot(x, y);
+        self
+    }
+
+    /// Draws a line between two points in world coordinates.
+    pub fn plot_line(self, x1: f64, y1: f64, x2: f64, y2: f64) -> Draw<'a> {
+        self.canvas.plot_line(x1, y1, x2, y2);
+        self
+    }
+
+    /// Draws a circle around a point in world coordinates.
+    pub fn plot_circle(self, x: f64, y: f64, radius: f64) -> Draw<'a> {
+        self.canvas.plot_circle(x, y, radius);
+        self
+    }
+}
+
+impl Canvas {
+    /// Starts drawing with chained calls, such as `canvas.draw().line(..).rect(..)`.
+    pub fn draw(&mut self) -> Draw<'_> {
+        Draw { canvas: self }
+    }
+}
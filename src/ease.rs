@@ -0,0 +1,264 @@
+//! Easing functions and tweens, for animations that speed up and slow down smoothly.
+//!
+//! Every easing function maps progress `t` from 0 to 1 onto an eased progress, which starts at
+//! 0 and ends at 1 but may overshoot in between.
+//!
+//! ```
+//! use drawille::Turtle;
+//! use drawille::ease::{self, Tween};
+//!
+//! let mut turtle = Turtle::new(0.0, 10.0);
+//! turtle.up();
+//! for (x, y) in Tween::new((0.0f32, 10.0), (40.0, 10.0), 20).easing(ease::cubic_in_out) {
+//!     turtle.teleport(x, y);
+//! }
+//! assert_eq!(turtle.x, 40.0);
+//! ```
+
+use std::convert::TryFrom;
+use std::f64::consts::PI;
+
+use scene::Transform;
+
+/// Moves at a constant speed.
+pub fn linear(t: f64) -> f64 {
+    t
+}
+
+/// Starts slowly and speeds up, quadratically.
+pub fn quad_in(t: f64) -> f64 {
+    t * t
+}
+
+/// Starts quickly and slows down, quadratically.
+pub fn quad_out(t: f64) -> f64 {
+    1.0 - quad_in(1.0 - t)
+}
+
+/// Speeds up and then slows down, quadratically.
+pub fn quad_in_out(t: f64) -> f64 {
+    in_out(quad_in, t)
+}
+
+/// Starts slowly and speeds up, cubically.
+pub fn cubic_in(t: f64) -> f64 {
+    t * t * t
+}
+
+/// Starts quickly and slows down, cubically.
+pub fn cubic_out(t: f64) -> f64 {
+    1.0 - cubic_in(1.0 - t)
+}
+
+/// Speeds up and then slows down, cubically.
+pub fn cubic_in_out(t: f64) -> f64 {
+    in_out(cubic_in, t)
+}
+
+/// Starts slowly and speeds up, along a quarter sine wave.
+pub fn sine_in(t: f64) -> f64 {
+    1.0 - (t * PI / 2.0).cos()
+}
+
+/// Starts quickly and slows down, along a quarter sine wave.
+pub fn sine_out(t: f64) -> f64 {
+    (t * PI / 2.0).sin()
+}
+
+/// Speeds up and then slows down, along half a sine wave.
+pub fn sine_in_out(t: f64) -> f64 {
+    (1.0 - (t * PI).cos()) / 2.0
+}
+
+/// Starts very slowly and speeds up exponentially.
+pub fn expo_in(t: f64) -> f64 {
+    if t <= 0.0 { 0.0 } else { 2f64.powf(10.0 * t - 10.0) }
+}
+
+/// Starts very quickly and slows down exponentially.
+pub fn expo_out(t: f64) -> f64 {
+    1.0 - expo_in(1.0 - t)
+}
+
+/// Speeds up and then slows down, exponentially.
+pub fn expo_in_out(t: f64) -> f64 {
+    in_out(expo_in, t)
+}
+
+/// Pulls back a little before setting off.
+pub fn back_in(t: f64) -> f64 {
+    const C: f64 = 1.70158;
+    (C + 1.0) * t * t * t - C * t * t
+}
+
+/// Overshoots the end a little before settling.
+pub fn back_out(t: f64) -> f64 {
+    1.0 - back_in(1.0 - t)
+}
+
+/// Pulls back, then overshoots, before settling.
+pub fn back_in_out(t: f64) -> f64 {
+    in_out(back_in, t)
+}
+
+/// Overshoots the end and wobbles around it like a spring.
+pub fn elastic_out(t: f64) -> f64 {
+    if t <= 0.0 || t >= 1.0 {
+        return t.clamp(0.0, 1.0);
+    }
+    2f64.powf(-10.0 * t) * ((t * 10.0 - 0.75) * (2.0 * PI / 3.0)).sin() + 1.0
+}
+
+/// Bounces off the end like a dropped ball.
+pub fn bounce_out(t: f64) -> f64 {
+    const N: f64 = 7.5625;
+    const D: f64 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+/// Bounces before setting off, like `bounce_out` in reverse.
+pub fn bounce_in(t: f64) -> f64 {
+    1.0 - bounce_out(1.0 - t)
+}
+
+/// Runs an easing function forwards for the first half and mirrored for the second.
+fn in_out(ease: fn(f64) -> f64, t: f64) -> f64 {
+    if t < 0.5 {
+        ease(t * 2.0) / 2.0
+    } else {
+        1.0 - ease((1.0 - t) * 2.0) / 2.0
+    }
+}
+
+/// A value that can be interpolated between two others.
+pub trait Lerp: Copy {
+    /// Returns the value a fraction `t` of the way from `self` to `to`.
+    fn lerp(self, to: Self, t: f64) -> Self;
+}
+
+impl Lerp for f64 {
+    fn lerp(self, to: f64, t: f64) -> f64 {
+        self + (to - self) * t
+    }
+}
+
+impl Lerp for f32 {
+    fn lerp(self, to: f32, t: f64) -> f32 {
+        self + (to - self) * t as f32
+    }
+}
+
+impl<A: Lerp, B: Lerp> Lerp for (A, B) {
+    fn lerp(self, to: (A, B), t: f64) -> (A, B) {
+        (self.0.lerp(to.0, t), self.1.lerp(to.1, t))
+    }
+}
+
+/// An angle in degrees, which interpolates the short way round.
+///
+/// ```
+/// use drawille::ease::{Angle, Lerp};
+///
+/// assert_eq!(Angle(350.0).lerp(Angle(10.0), 0.5).0, 360.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Angle(pub f64);
+
+impl Lerp for Angle {
+    fn lerp(self, to: Angle, t: f64) -> Angle {
+        let delta = (to.0 - self.0 + 180.0).rem_euclid(360.0) - 180.0;
+        Angle(self.0 + delta * t)
+    }
+}
+
+impl From<Angle> for Transform {
+    fn from(angle: Angle) -> Transform {
+        Transform::rotate(angle.0)
+    }
+}
+
+/// A value moving from one point to another over a number of frames, following an easing
+/// function.
+///
+/// As an `Iterator` it yields the value for every frame, from the start to the end inclusive.
+///
+/// ```
+/// use drawille::ease::{self, Tween};
+///
+/// let tween = Tween::new(0.0, 100.0, 4).easing(ease::quad_in);
+/// assert_eq!(tween.at(2), 25.0);
+/// assert_eq!(tween.collect::<Vec<_>>(), [0.0, 6.25, 25.0, 56.25, 100.0]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Tween<T> {
+    from: T,
+    to: T,
+    frames: u32,
+    easing: fn(f64) -> f64,
+    // The next frame to yield, wide enough to count past the last frame of the longest tween.
+    frame: u64,
+}
+
+impl<T: Lerp> Tween<T> {
+    /// Creates a new `Tween` from `from` to `to` over `frames` frames, moving at a constant
+    /// speed.
+    pub fn new(from: T, to: T, frames: u32) -> Tween<T> {
+        Tween { from, to, frames, easing: linear, frame: 0 }
+    }
+
+    /// Sets the easing function, and return it for use again.
+    pub fn easing(mut self, easing: fn(f64) -> f64) -> Tween<T> {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the number of frames the `Tween` takes.
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// Returns the value at the given frame, holding the end value after the last one.
+    pub fn at(&self, frame: u32) -> T {
+        if frame >= self.frames {
+            return self.to;
+        }
+        self.from.lerp(self.to, (self.easing)(frame as f64 / self.frames as f64))
+    }
+
+    /// Detects whether every frame has been yielded.
+    pub fn is_finished(&self) -> bool {
+        self.frame > self.frames as u64
+    }
+}
+
+impl<T: Lerp> Iterator for Tween<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.is_finished() {
+            return None;
+        }
+        let frame = self.frame as u32;
+        self.frame += 1;
+        Some(self.at(frame))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = (self.frames as u64 + 1).saturating_sub(self.frame);
+        match usize::try_from(left) {
+            Ok(left) => (left, Some(left)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
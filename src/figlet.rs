@@ -0,0 +1,214 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use fnv::FnvHashMap;
+
+use canvas::Canvas;
+
+/// The characters every FIGlet font defines, in order, before any code-tagged characters.
+const REQUIRED_CHARS: [u32; 7] = [196, 214, 220, 228, 246, 252, 223];
+
+/// An error encountered while loading a FIGlet font.
+#[derive(Debug)]
+pub enum FigletError {
+    /// The font could not be read.
+    Io(io::Error),
+    /// The `flf2a` header line is missing or malformed.
+    InvalidHeader,
+    /// A character definition was malformed or cut short.
+    InvalidGlyph(u32),
+}
+
+impl fmt::Display for FigletError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FigletError::Io(ref e) => write!(f, "could not read FIGlet font: {}", e),
+            FigletError::InvalidHeader => write!(f, "invalid FIGlet font header"),
+            FigletError::InvalidGlyph(c) => write!(f, "invalid FIGlet glyph for code {}", c),
+        }
+    }
+}
+
+impl Error for FigletError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            FigletError::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FigletError {
+    fn from(e: io::Error) -> FigletError {
+        FigletError::Io(e)
+    }
+}
+
+/// A FIGlet font, used to draw large banner text onto a `Canvas` at pixel resolution.
+///
+/// Every non-blank character of a glyph becomes a single dot. Glyphs are placed side by side at
+/// their full width; FIGlet’s kerning and smushing rules are not applied.
+///
+/// ```
+/// use drawille::{Canvas, FigFont};
+///
+/// let font = FigFont::parse("flf2a$ 2 2 2 -1 1\n\
+///                            a tiny font\n\
+///                            $@\n\
+///                            $@@\n\
+///                            #@\n\
+///                            #@@\n").unwrap();
+/// let mut canvas = Canvas::new(4, 4);
+/// font.draw(&mut canvas, 1, 0, "!");
+/// assert!(canvas.get(1, 0) && canvas.get(1, 1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct FigFont {
+    height: usize,
+    glyphs: FnvHashMap<char, Vec<Vec<bool>>>,
+}
+
+impl FigFont {
+    /// Parses a FIGlet font from the contents of an `.flf` file.
+    pub fn parse(source: &str) -> Result<FigFont, FigletError> {
+        let mut lines = source.lines();
+        let header = lines.next().ok_or(FigletError::InvalidHeader)?;
+        if !header.starts_with("flf2a") {
+            return Err(FigletError::InvalidHeader);
+        }
+        let hardblank = header[5..].chars().next().ok_or(FigletError::InvalidHeader)?;
+        // Layouts may be negative, such as an old layout of -1 for full width.
+        let params: Vec<i32> = header[5 + hardblank.len_utf8()..]
+            .split_whitespace()
+            .map(|p| p.parse().ok())
+            .take(5)
+            .collect::<Option<_>>()
+            .ok_or(FigletError::InvalidHeader)?;
+        if params.len() < 5 || params[0] <= 0 || params[4] < 0 {
+            return Err(FigletError::InvalidHeader);
+        }
+        let (height, comment_lines) = (params[0] as usize, params[4]);
+        for _ in 0..comment_lines {
+            lines.next().ok_or(FigletError::InvalidHeader)?;
+        }
+
+        let mut font = FigFont { height, glyphs: FnvHashMap::default() };
+        let required = (32..127).chain(REQUIRED_CHARS.iter().cloned());
+        for code in required {
+            match lines.next() {
+                Some(first) => font.read_glyph(code, first, &mut lines, hardblank)?,
+                // Tolerate fonts that leave out the tail of the required characters.
+                None => return Ok(font),
+            }
+        }
+        while let Some(tag) = lines.next() {
+            let code = tag.split_whitespace().next().and_then(parse_code);
+            match code {
+                Some(code) => {
+                    let first = lines.next().ok_or(FigletError::InvalidGlyph(code))?;
+                    font.read_glyph(code, first, &mut lines, hardblank)?;
+                }
+                None if tag.trim().is_empty() => {}
+                None => return Err(FigletError::InvalidHeader),
+            }
+        }
+        Ok(font)
+    }
+
+    /// Loads a FIGlet font from an `.flf` file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<FigFont, FigletError> {
+        FigFont::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Returns the height of the font’s glyphs, in pixels.
+    pub fn height(&self) -> u32 {
+        self.height as u32
+    }
+
+    /// Draws text in this font onto the `Canvas`, with its top-left corner at the given
+    /// coordinates.
+    ///
+    /// Each line of the text starts a new row of glyphs below the previous one. Characters the
+    /// font does not define are skipped.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, text: &str) {
+        // Whatever runs past the largest coordinate is left out, and drawing stops there.
+        let offset = |start: u32, by: usize| {
+            u32::try_from(by).ok().and_then(|by| start.checked_add(by))
+        };
+        for (n, line) in text.lines().enumerate() {
+            let top = match n.checked_mul(self.height).and_then(|dy| offset(y, dy)) {
+                Some(top) => top,
+                None => break,
+            };
+            let mut left = Some(x);
+            for c in line.chars() {
+                let (start, glyph) = match (left, self.glyphs.get(&c)) {
+                    (Some(start), Some(glyph)) => (start, glyph),
+                    (Some(_), None) => continue,
+                    (None, _) => break,
+                };
+                let mut width = 0;
+                for (dy, row) in glyph.iter().enumerate() {
+                    for (dx, &dot) in row.iter().enumerate() {
+                        if !dot {
+                            continue;
+                        }
+                        if let (Some(px), Some(py)) = (offset(start, dx), offset(top, dy)) {
+                            canvas.set(px, py);
+                        }
+                    }
+                    width = width.max(row.len());
+                }
+                left = offset(start, width);
+            }
+        }
+    }
+
+    /// Reads the `height` lines of a single glyph, the first of which has already been taken.
+    fn read_glyph<'a, I>(&mut self, code: u32, first: &str, lines: &mut I, hardblank: char)
+        -> Result<(), FigletError>
+        where I: Iterator<Item = &'a str>
+    {
+        let mut rows = Vec::with_capacity(self.height);
+        rows.push(glyph_row(first, hardblank));
+        for _ in 1..self.height {
+            let line = lines.next().ok_or(FigletError::InvalidGlyph(code))?;
+            rows.push(glyph_row(line, hardblank));
+        }
+        if let Some(c) = ::std::char::from_u32(code) {
+            self.glyphs.insert(c, rows);
+        }
+        Ok(())
+    }
+}
+
+/// Strips the end marks off a glyph line and converts it into dots.
+fn glyph_row(line: &str, hardblank: char) -> Vec<bool> {
+    let line = line.trim_end();
+    let body = match line.chars().last() {
+        Some(endmark) => line.trim_end_matches(endmark),
+        None => line,
+    };
+    body.chars().map(|c| c != ' ' && c != hardblank).collect()
+}
+
+/// Parses a code tag, which may be decimal, hexadecimal (`0x`) or octal (leading `0`).
+fn parse_code(tag: &str) -> Option<u32> {
+    let (negative, tag) = match tag.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, tag),
+    };
+    let code = if let Some(hex) = tag.strip_prefix("0x").or_else(|| tag.strip_prefix("0X")) {
+        u32::from_str_radix(hex, 16).ok()?
+    } else if tag.len() > 1 && tag.starts_with('0') {
+        u32::from_str_radix(&tag[1..], 8).ok()?
+    } else {
+        tag.parse().ok()?
+    };
+    // Negative codes are reserved for font-specific use and don’t map to characters.
+    if negative { Some(u32::MAX) } else { Some(code) }
+}
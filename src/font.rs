@@ -0,0 +1,212 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use ab_glyph::{point, Font, FontArc, Glyph, OutlinedGlyph, PxScale, ScaleFont};
+
+use canvas::Canvas;
+use dither::Density;
+
+/// An error encountered while loading a TrueType or OpenType font.
+#[derive(Debug)]
+pub enum FontError {
+    /// The font could not be read.
+    Io(io::Error),
+    /// The data isn’t a font that can be parsed.
+    InvalidFont,
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FontError::Io(ref e) => write!(f, "could not read font: {}", e),
+            FontError::InvalidFont => write!(f, "invalid TrueType or OpenType font"),
+        }
+    }
+}
+
+impl Error for FontError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            FontError::Io(ref e) => Some(e),
+            FontError::InvalidFont => None,
+        }
+    }
+}
+
+impl From<io::Error> for FontError {
+    fn from(e: io::Error) -> FontError {
+        FontError::Io(e)
+    }
+}
+
+/// How the coverage of a rasterized glyph, from 0 to 1, is turned into dots.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FontMode {
+    /// Sets every pixel that the glyph covers at least the given fraction of, which gives
+    /// crisp, solid letters.
+    Threshold(f64),
+    /// Sets pixels at a density following the coverage, which keeps the anti-aliased edges of
+    /// large letters as a lighter fringe.
+    Density(Density),
+}
+
+/// A TrueType or OpenType font, used to draw text onto a `Canvas` at any size in pixels.
+///
+/// This suits titles and other large text, where the cell-sized characters of `text` and the
+/// fixed pixels of a `FigFont` fall short. Kerning is applied between letters.
+///
+/// ```no_run
+/// use drawille::{Canvas, TtfFont};
+///
+/// let font = TtfFont::load("DejaVuSans.ttf").unwrap().size(32.0);
+/// let mut canvas = Canvas::new(0, 0);
+/// font.draw(&mut canvas, 0, 0, "Hello");
+/// // The text is drawn within the height of its line.
+/// assert!(canvas.pixels().count() > 0);
+/// assert!(canvas.pixels().all(|(_, y)| (y as f32) < font.line_height()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TtfFont {
+    font: FontArc,
+    size: f32,
+    mode: FontMode,
+}
+
+impl TtfFont {
+    /// Parses a font from the contents of a `.ttf` or `.otf` file, to be drawn 16 pixels high
+    /// with a threshold of one half.
+    pub fn parse(data: Vec<u8>) -> Result<TtfFont, FontError> {
+        let font = FontArc::try_from_vec(data).map_err(|_| FontError::InvalidFont)?;
+        Ok(TtfFont { font, size: 16.0, mode: FontMode::Threshold(0.5) })
+    }
+
+    /// Loads a font from a `.ttf` or `.otf` file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<TtfFont, FontError> {
+        TtfFont::parse(fs::read(path)?)
+    }
+
+    /// Sets the height of the font in pixels, from its highest ascender to its lowest
+    /// descender, and return it for use again.
+    pub fn size(mut self, size: f32) -> TtfFont {
+        self.size = size;
+        self
+    }
+
+    /// Sets how glyphs are turned into dots, and return it for use again.
+    pub fn mode(mut self, mode: FontMode) -> TtfFont {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the distance from one line of text to the next, in pixels.
+    pub fn line_height(&self) -> f32 {
+        let font = self.font.as_scaled(PxScale::from(self.size));
+        font.height() + font.line_gap()
+    }
+
+    /// Returns how wide a line of text is when drawn in this font, in pixels.
+    pub fn width(&self, text: &str) -> f32 {
+        let font = self.font.as_scaled(PxScale::from(self.size));
+        let mut width = 0.0;
+        let mut previous = None;
+        for c in text.chars() {
+            let id = font.glyph_id(c);
+            if let Some(previous) = previous {
+                width += font.kern(previous, id);
+            }
+            width += font.h_advance(id);
+            previous = Some(id);
+        }
+        width
+    }
+
+    /// Draws text in this font onto the `Canvas`, with the top-left corner of its first line at
+    /// the given coordinates.
+    ///
+    /// Each line of the text starts a new row below the previous one. Parts of glyphs that fall
+    /// above or left of the `Canvas` are left out.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, text: &str) {
+        let scale = PxScale::from(self.size);
+        let font = self.font.as_scaled(scale);
+        let mut baseline = y as f32 + font.ascent();
+        for line in text.lines() {
+            let mut pen = x as f32;
+            let mut previous = None;
+            for c in line.chars() {
+                let id = font.glyph_id(c);
+                if let Some(previous) = previous {
+                    pen += font.kern(previous, id);
+                }
+                let glyph = id.with_scale_and_position(scale, point(pen, baseline));
+                self.draw_glyph(canvas, glyph);
+                pen += font.h_advance(id);
+                previous = Some(id);
+            }
+            baseline += self.line_height();
+        }
+    }
+
+    /// Rasterizes a single positioned glyph onto the `Canvas`.
+    fn draw_glyph(&self, canvas: &mut Canvas, glyph: Glyph) {
+        if let Some(outlined) = self.font.outline_glyph(glyph) {
+            let bounds = outlined.px_bounds();
+            self.draw_outlined(canvas, &outlined, bounds.min.x as i64, bounds.min.y as i64);
+        }
+    }
+
+    /// Rasterizes an outlined glyph onto the `Canvas` with the top-left corner of its bounds at
+    /// the given pixel.
+    fn draw_outlined(&self, canvas: &mut Canvas, outlined: &OutlinedGlyph, left: i64, top: i64) {
+        outlined.draw(|dx, dy, coverage| {
+            let (px, py) = (left + dx as i64, top + dy as i64);
+            if px < 0 || py < 0 || px > u32::MAX as i64 || py > u32::MAX as i64 {
+                return;
+            }
+            let (px, py) = (px as u32, py as u32);
+            let set = match self.mode {
+                FontMode::Threshold(threshold) => coverage as f64 >= threshold && coverage > 0.0,
+                FontMode::Density(ref density) => density.is_set(px, py, coverage as f64),
+            };
+            if set {
+                canvas.set(px, py);
+            }
+        });
+    }
+}
+
+impl Canvas {
+    /// Draws a single character from a font as dots, `size` pixels high as `TtfFont::size`
+    /// takes it, with the top-left corner of its ink at the given coordinates, and returns
+    /// whether the font could draw it.
+    ///
+    /// Unlike `set_char`, whose characters are locked to cells, this places symbols and icons
+    /// at pixel resolution and at any size. Characters the font has no glyph for, and glyphs
+    /// that are only bitmaps, such as those of colour emoji fonts, draw nothing; a symbol font
+    /// such as DejaVu Sans covers many. The font’s `FontMode` is used.
+    ///
+    /// ```no_run
+    /// use drawille::{Canvas, TtfFont};
+    ///
+    /// let font = TtfFont::load("DejaVuSans.ttf").unwrap();
+    /// let mut canvas = Canvas::new(0, 0);
+    /// for (i, &size) in [8.0, 16.0, 32.0].iter().enumerate() {
+    ///     assert!(canvas.stamp_glyph(&font, i as u32 * 40, 0, '☂', size));
+    /// }
+    /// ```
+    pub fn stamp_glyph(&mut self, font: &TtfFont, x: u32, y: u32, c: char, size: f32) -> bool {
+        let id = font.font.glyph_id(c);
+        if id.0 == 0 {
+            return false;
+        }
+        match font.font.outline_glyph(id.with_scale(PxScale::from(size))) {
+            Some(outlined) => {
+                font.draw_outlined(self, &outlined, x as i64, y as i64);
+                true
+            }
+            None => false,
+        }
+    }
+}
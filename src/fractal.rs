@@ -0,0 +1,113 @@
+//! Ready-made fractal curves, drawn by a `Turtle` following an `LSystem` and scaled to fit a
+//! square `Canvas`.
+//!
+//! Each level of depth multiplies the number of lines by a constant factor, so depths beyond
+//! about 8 give more detail than a terminal can show and take a while to draw.
+//!
+//! ```
+//! use drawille::fractal;
+//!
+//! assert_eq!(fractal::koch_snowflake(2, 32).frame(), [
+//! "    ⢀⣀⡜⢆⣀⡀       ",
+//! " ⢀⢦ ⢀⠇  ⢪ ⢀⢦     ",
+//! "⢹⠉ ⠉⠉    ⠉⠉ ⠩⡋   ",
+//! "⠓⠲⡀        ⢠⠒⠓   ",
+//! "⡤⠤⠃        ⠑⠤⡤   ",
+//! "⣸⣀ ⣀⣀    ⣀⣀ ⣐⣅   ",
+//! " ⠈⠞ ⠈⡆  ⢜ ⠈⠞     ",
+//! "    ⠈⠉⢣⠎⠉⠁       ",
+//! "                 "].join("\n"));
+//! ```
+
+use canvas::Canvas;
+use lsystem::LSystem;
+use turtle::Turtle;
+
+/// Returns the `LSystem` of the Koch snowflake: a triangle whose sides are each bent outwards
+/// into a point, again and again.
+pub fn koch_snowflake_system() -> LSystem {
+    LSystem::new("F--F--F", 60.0).rule('F', "F+F--F+F")
+}
+
+/// Returns the `LSystem` of the Sierpiński triangle: a triangle with its middle cut out, and
+/// the same again in each of the three that are left.
+pub fn sierpinski_system() -> LSystem {
+    LSystem::new("F-G-G", 120.0).rule('F', "F-G+F+G-F").rule('G', "GG")
+}
+
+/// Returns the `LSystem` of the Heighway dragon: a strip of paper folded in half again and
+/// again, then opened out at right angles.
+pub fn dragon_system() -> LSystem {
+    LSystem::new("FX", 90.0).rule('X', "X+YF+").rule('Y', "-FX-Y")
+}
+
+/// Returns the `LSystem` of the Hilbert curve, which visits every point of a square grid
+/// without crossing itself.
+pub fn hilbert_system() -> LSystem {
+    LSystem::new("A", 90.0).rule('A', "+BF-AFA-FB+").rule('B', "-AF+BFB+FA-")
+}
+
+/// Draws the Koch snowflake at the given depth, scaled to fit `size` pixels square.
+///
+/// ```
+/// use drawille::{fractal, Turtle};
+///
+/// let mut turtle = Turtle::new(0.0, 0.0).record(true);
+/// fractal::koch_snowflake_system().draw(&mut turtle, 2, 1.0);
+/// assert_eq!(turtle.segments().len(), 3 * 4 * 4);
+/// ```
+pub fn koch_snowflake(depth: u32, size: u32) -> Canvas {
+    render(&koch_snowflake_system(), depth, size)
+}
+
+/// Draws the Sierpiński triangle at the given depth, scaled to fit `size` pixels square.
+pub fn sierpinski(depth: u32, size: u32) -> Canvas {
+    render(&sierpinski_system(), depth, size)
+}
+
+/// Draws the Heighway dragon at the given depth, with 2<sup>depth</sup> lines, scaled to fit
+/// `size` pixels square.
+///
+/// ```
+/// use drawille::{fractal, Turtle};
+///
+/// let mut turtle = Turtle::new(0.0, 0.0).record(true);
+/// fractal::dragon_system().draw(&mut turtle, 10, 1.0);
+/// assert_eq!(turtle.segments().len(), 1024);
+/// // The dragon never draws the same line twice.
+/// let mut lines: Vec<_> = turtle.segments().iter().map(|&(x1, y1, x2, y2)| {
+///     let a = (x1.round() as i32, y1.round() as i32);
+///     let b = (x2.round() as i32, y2.round() as i32);
+///     if a < b { (a, b) } else { (b, a) }
+/// }).collect();
+/// lines.sort();
+/// lines.dedup();
+/// assert_eq!(lines.len(), 1024);
+/// ```
+pub fn dragon(depth: u32, size: u32) -> Canvas {
+    render(&dragon_system(), depth, size)
+}
+
+/// Draws the Hilbert curve at the given depth, through a grid of 2<sup>depth</sup> points
+/// square, scaled to fit `size` pixels square.
+///
+/// At a depth that makes the grid spacing exactly two pixels, the curve fills its square
+/// with evenly spaced lines.
+///
+/// ```
+/// use drawille::fractal;
+///
+/// // 16 points square, 2 pixels apart.
+/// let canvas = fractal::hilbert(4, 31);
+/// assert_eq!(canvas.pixels().count(), 16 * 16 + 255);
+/// ```
+pub fn hilbert(depth: u32, size: u32) -> Canvas {
+    render(&hilbert_system(), depth, size)
+}
+
+/// Draws an `LSystem` with a `Turtle` and scales the result to fit `size` pixels square.
+fn render(system: &LSystem, depth: u32, size: u32) -> Canvas {
+    let mut turtle = Turtle::new(0.0, 0.0).record(true);
+    system.draw(&mut turtle, depth, 1.0);
+    turtle.fitted_canvas(size, size)
+}
@@ -0,0 +1,265 @@
+use std::f64::consts::PI;
+
+use fnv::FnvHashSet;
+
+use canvas::Canvas;
+
+/// An analog dial with needles, such as a speedometer, a meter or, with `Gauge::clock`, a
+/// clock face.
+///
+/// Angles are in degrees clockwise from twelve o’clock. The face is an arc over the sweep of
+/// the gauge, with ticks along it and labels inside it; needles start at the centre.
+///
+/// `draw` draws the whole gauge, while `update` only redraws what changed since the last
+/// call, which suits a gauge redrawn every frame onto a `Canvas` that isn’t cleared.
+///
+/// ```
+/// use drawille::{Canvas, Gauge};
+///
+/// let mut gauge = Gauge::clock();
+/// let mut canvas = Canvas::new(0, 0);
+/// gauge.update(&mut canvas, 20, 20, 18, &Gauge::clock_hands(10, 8, 0.0));
+/// gauge.update(&mut canvas, 20, 20, 18, &Gauge::clock_hands(10, 9, 0.0));
+///
+/// let mut fresh = Canvas::new(0, 0);
+/// Gauge::clock().draw(&mut fresh, 20, 20, 18, &Gauge::clock_hands(10, 9, 0.0));
+/// assert_eq!(canvas.frame(), fresh.frame());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gauge {
+    range: (f64, f64),
+    sweep: (f64, f64),
+    ticks: (f64, f64),
+    labels: Vec<(f64, String)>,
+    lengths: Vec<f64>,
+    drawn: Option<Drawn>,
+}
+
+/// What `Gauge::update` last drew, so that it can undo just the needles.
+#[derive(Clone, Debug, PartialEq)]
+struct Drawn {
+    at: (u32, u32, u32),
+    face: FnvHashSet<(u32, u32)>,
+    needles: Vec<(u32, u32)>,
+}
+
+impl Gauge {
+    /// Creates a new `Gauge` for values from 0 to 1, sweeping 270° from lower left to lower
+    /// right, with a major tick every tenth and no labels.
+    pub fn new() -> Gauge {
+        Gauge {
+            range: (0.0, 1.0),
+            sweep: (-135.0, 135.0),
+            ticks: (0.1, 0.0),
+            labels: Vec::new(),
+            lengths: Vec::new(),
+            drawn: None,
+        }
+    }
+
+    /// Creates a new `Gauge` set up as a clock face, for the values from `clock_hands`: twelve
+    /// hours all the way round, with a major tick every hour, a minor one every minute,
+    /// 12, 3, 6 and 9 labelled, and a short hour hand.
+    pub fn clock() -> Gauge {
+        let labels = [(0.0, "12"), (3.0, "3"), (6.0, "6"), (9.0, "9")];
+        Gauge::new()
+            .range(0.0, 12.0)
+            .sweep(0.0, 360.0)
+            .ticks(1.0, 0.2)
+            .labels(labels.iter().map(|&(v, s)| (v, s.to_string())).collect())
+            .lengths(vec![0.45, 0.75, 0.85])
+    }
+
+    /// Returns the values of the hour, minute and second hands of a `Gauge::clock` showing
+    /// the given time.
+    ///
+    /// ```
+    /// use drawille::Gauge;
+    ///
+    /// assert_eq!(Gauge::clock_hands(15, 30, 0.0), [3.5, 6.0, 0.0]);
+    /// ```
+    pub fn clock_hands(hours: u32, minutes: u32, seconds: f64) -> [f64; 3] {
+        let seconds = seconds % 60.0;
+        let minutes = (minutes % 60) as f64 + seconds / 60.0;
+        let hours = (hours % 12) as f64 + minutes / 60.0;
+        [hours, minutes / 5.0, seconds / 5.0]
+    }
+
+    /// Sets the values at the start and end of the sweep, and return the `Gauge` for use
+    /// again. Values outside the range are shown at its ends.
+    pub fn range(mut self, min: f64, max: f64) -> Gauge {
+        self.range = (min, max);
+        self.drawn = None;
+        self
+    }
+
+    /// Sets the angles of the start and end of the face, in degrees clockwise from twelve
+    /// o’clock, and return the `Gauge` for use again.
+    ///
+    /// The end may be less than the start, for a gauge whose values increase anticlockwise.
+    pub fn sweep(mut self, start: f64, end: f64) -> Gauge {
+        self.sweep = (start, end);
+        self.drawn = None;
+        self
+    }
+
+    /// Sets the gap between major ticks and between minor ticks, as values, and return the
+    /// `Gauge` for use again. A gap of 0 leaves out those ticks.
+    pub fn ticks(mut self, major: f64, minor: f64) -> Gauge {
+        self.ticks = (major, minor);
+        self.drawn = None;
+        self
+    }
+
+    /// Sets the labels drawn inside the face, each next to the value it labels, and return
+    /// the `Gauge` for use again.
+    pub fn labels(mut self, labels: Vec<(f64, String)>) -> Gauge {
+        self.labels = labels;
+        self.drawn = None;
+        self
+    }
+
+    /// Sets the length of each needle as a fraction of the radius, from the first to the
+    /// last, and return the `Gauge` for use again. Needles without a length reach 0.85 of
+    /// the way to the face.
+    pub fn lengths(mut self, lengths: Vec<f64>) -> Gauge {
+        self.lengths = lengths;
+        self
+    }
+
+    /// Returns the angle of a value, in degrees clockwise from twelve o’clock.
+    ///
+    /// ```
+    /// use drawille::Gauge;
+    ///
+    /// assert_eq!(Gauge::new().angle(0.5), 0.0);
+    /// assert_eq!(Gauge::new().angle(2.0), 135.0);
+    /// ```
+    pub fn angle(&self, value: f64) -> f64 {
+        let ((min, max), (start, end)) = (self.range, self.sweep);
+        let t = if max != min { ((value - min) / (max - min)).clamp(0.0, 1.0) } else { 0.0 };
+        start + t * (end - start)
+    }
+
+    /// Draws the face and one needle for each of `values`, centred at the given pixel
+    /// coordinates with the face `radius` pixels out.
+    ///
+    /// Parts of the gauge that would lie at negative coordinates are not drawn.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, radius: u32, values: &[f64]) {
+        self.draw_face(canvas, x, y, radius);
+        for (x, y) in self.needle_points(x, y, radius, values) {
+            canvas.set(x, y);
+        }
+    }
+
+    /// Draws just the face: the arc, its ticks and its labels.
+    pub fn draw_face(&self, canvas: &mut Canvas, x: u32, y: u32, radius: u32) {
+        for (x, y) in self.face_points(x, y, radius) {
+            canvas.set(x, y);
+        }
+        let r = radius as f64 * 0.7;
+        for (value, label) in &self.labels {
+            let (lx, ly) = polar(x, y, r, self.angle(*value));
+            // Each letter is two pixels wide and four high.
+            let width = label.chars().count() as f64 * 2.0;
+            let (lx, ly) = ((lx - width / 2.0).round(), (ly - 2.0).round());
+            if lx >= 0.0 && ly >= 0.0 {
+                canvas.text(lx as u32, ly as u32, width as u32, label);
+            }
+        }
+    }
+
+    /// Draws the needles for `values`, removing the ones drawn by the last call, and draws
+    /// the face as well if it isn’t there yet or the gauge has moved.
+    ///
+    /// The pixels of the old needles are unset unless they belong to the face, so anything
+    /// else drawn where they were is removed too.
+    pub fn update(&mut self, canvas: &mut Canvas, x: u32, y: u32, radius: u32, values: &[f64]) {
+        let at = (x, y, radius);
+        match self.drawn {
+            Some(ref drawn) if drawn.at == at => {
+                for p in &drawn.needles {
+                    if !drawn.face.contains(p) {
+                        canvas.unset(p.0, p.1);
+                    }
+                }
+            }
+            _ => {
+                self.draw_face(canvas, x, y, radius);
+                let face = self.face_points(x, y, radius).into_iter().collect();
+                self.drawn = Some(Drawn { at, face, needles: Vec::new() });
+            }
+        }
+        let needles = self.needle_points(x, y, radius, values);
+        for &(x, y) in &needles {
+            canvas.set(x, y);
+        }
+        if let Some(ref mut drawn) = self.drawn {
+            drawn.needles = needles;
+        }
+    }
+
+    /// Returns the pixels of the arc and the ticks.
+    fn face_points(&self, x: u32, y: u32, radius: u32) -> Vec<(u32, u32)> {
+        let mut points = Vec::new();
+        let r = radius as f64;
+        let (start, end) = self.sweep;
+        let steps = (r * (end - start).abs().to_radians()).ceil().max(1.0) as u32;
+        for i in 0..steps {
+            let a = start + (end - start) * i as f64 / steps as f64;
+            let b = start + (end - start) * (i + 1) as f64 / steps as f64;
+            segment(&mut points, polar(x, y, r, a), polar(x, y, r, b));
+        }
+
+        let (min, max) = self.range;
+        for &(gap, length) in &[(self.ticks.1, 0.08), (self.ticks.0, 0.18)] {
+            if gap <= 0.0 || max == min {
+                continue;
+            }
+            let count = ((max - min).abs() / gap + 1e-9).floor() as u32;
+            for i in 0..=count {
+                let angle = self.angle(min + (max - min).signum() * gap * i as f64);
+                let outer = polar(x, y, r, angle);
+                let inner = polar(x, y, r * (1.0 - length), angle);
+                segment(&mut points, inner, outer);
+            }
+        }
+        points
+    }
+
+    /// Returns the pixels of the needles for `values`.
+    fn needle_points(&self, x: u32, y: u32, radius: u32, values: &[f64]) -> Vec<(u32, u32)> {
+        let mut points = Vec::new();
+        for (i, &value) in values.iter().enumerate() {
+            let length = self.lengths.get(i).cloned().unwrap_or(0.85);
+            let tip = polar(x, y, radius as f64 * length, self.angle(value));
+            segment(&mut points, (x as f64, y as f64), tip);
+        }
+        points
+    }
+}
+
+impl Default for Gauge {
+    fn default() -> Gauge {
+        Gauge::new()
+    }
+}
+
+/// Returns the point `r` pixels from `(x, y)` at an angle in degrees clockwise from twelve
+/// o’clock.
+pub(crate) fn polar(x: u32, y: u32, r: f64, degrees: f64) -> (f64, f64) {
+    let (sin, cos) = (degrees * PI / 180.0).sin_cos();
+    (x as f64 + r * sin, y as f64 - r * cos)
+}
+
+/// Adds the pixels of the line between two points, leaving out any at negative coordinates.
+pub(crate) fn segment(points: &mut Vec<(u32, u32)>, (x1, y1): (f64, f64), (x2, y2): (f64, f64)) {
+    let steps = (x2 - x1).abs().max((y2 - y1).abs()).round().max(1.0) as u32;
+    for i in 0..=steps {
+        let t = i as f64 / steps as f64;
+        let (x, y) = ((x1 + (x2 - x1) * t).round(), (y1 + (y2 - y1) * t).round());
+        if x >= 0.0 && y >= 0.0 {
+            points.push((x as u32, y as u32));
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use std::f64::consts::FRAC_PI_4;
+
+use canvas::Canvas;
+#[cfg(feature = "coastline")]
+use coastline::COASTLINES;
+use world::clip;
+
+/// The furthest latitude north or south that the Mercator projection shows, which makes the
+/// whole world a square.
+const MERCATOR_LIMIT: f64 = 85.051_128_78;
+
+/// A way of flattening the globe onto a `Canvas`, turning latitudes and longitudes in degrees
+/// into positions on a map.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Projection {
+    /// Longitude and latitude as they are, evenly spaced: the simplest map, twice as wide as
+    /// it is tall, which stretches everything away from the equator sideways.
+    Equirectangular,
+    /// The Mercator projection, which keeps shapes true but makes land near the poles look
+    /// ever larger. Latitudes beyond about 85° are clamped, since the poles are infinitely
+    /// far away.
+    Mercator,
+}
+
+impl Projection {
+    /// Returns how far up the map a latitude lies, in the same units as longitude.
+    fn y(self, lat: f64) -> f64 {
+        match self {
+            Projection::Equirectangular => lat,
+            Projection::Mercator => {
+                let lat = lat.clamp(-MERCATOR_LIMIT, MERCATOR_LIMIT).to_radians();
+                (FRAC_PI_4 + lat / 2.0).tan().ln().to_degrees()
+            }
+        }
+    }
+
+    /// Returns the latitude of a height up the map, the inverse of `y`.
+    fn lat(self, y: f64) -> f64 {
+        match self {
+            Projection::Equirectangular => y,
+            Projection::Mercator => (2.0 * y.to_radians().exp().atan() - 2.0 * FRAC_PI_4)
+                .to_degrees(),
+        }
+    }
+}
+
+/// The part of the globe shown on a map and the size in pixels it is drawn at, for turning
+/// latitudes and longitudes into pixels and back.
+///
+/// A new `Viewport` shows the whole world, which `bounds` narrows down to a region. Points
+/// outside the bounds are still projected, off the edges of the map, so that lines to them can
+/// be clipped.
+///
+/// ```
+/// use drawille::{Canvas, Projection, Viewport};
+///
+/// let map = Viewport::new(Projection::Equirectangular, 361, 181);
+/// // Null Island, where the equator meets the prime meridian, is in the middle.
+/// assert_eq!(map.to_pixel(0.0, 0.0), Some((180, 90)));
+/// assert_eq!(map.to_pixel(51.5, -0.1), Some((180, 39)));
+///
+/// let mut canvas = Canvas::new(0, 0);
+/// for &(lat, lon) in &[(40.7, -74.0), (35.7, 139.7), (-33.9, 151.2)] {
+///     canvas.plot_geo(&map, lat, lon);
+/// }
+/// assert_eq!(canvas.pixels().count(), 3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Viewport {
+    projection: Projection,
+    west: f64,
+    south: f64,
+    east: f64,
+    north: f64,
+    width: u32,
+    height: u32,
+}
+
+impl Viewport {
+    /// Creates a new `Viewport` of the whole world, drawn `width` by `height` pixels.
+    ///
+    /// The world is twice as wide as it is tall in the equirectangular projection and square in
+    /// the Mercator projection; since Braille pixels are about twice as tall as they are wide,
+    /// a map looks right on a `Canvas` a little less than twice that many pixels wide.
+    pub fn new(projection: Projection, width: u32, height: u32) -> Viewport {
+        Viewport { projection, west: -180.0, south: -90.0, east: 180.0, north: 90.0, width, height }
+    }
+
+    /// Shows only the region between the given longitudes and latitudes in degrees, and
+    /// return it for use again.
+    ///
+    /// A region may span the antimeridian, with `east` less than `west` or beyond 180°, such as
+    /// the Pacific from 120° to -60°.
+    pub fn bounds(mut self, west: f64, south: f64, east: f64, north: f64) -> Viewport {
+        let east = if east <= west { east + 360.0 } else { east };
+        self.west = west;
+        self.south = south.min(north);
+        self.east = east;
+        self.north = north.max(south);
+        self
+    }
+
+    /// Returns the projection of the `Viewport`.
+    pub fn projection(&self) -> Projection {
+        self.projection
+    }
+
+    /// Returns the width and height of the `Viewport` in pixels.
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Returns the fractional pixel coordinates of a latitude and longitude in degrees, which
+    /// may lie outside the map.
+    pub fn project(&self, lat: f64, lon: f64) -> (f64, f64) {
+        self.project_unwrapped(lat, self.wrap(lon))
+    }
+
+    /// Projects a latitude and longitude as `project` does, but takes the longitude as it is
+    /// rather than wrapping it to within the bounds.
+    fn project_unwrapped(&self, lat: f64, lon: f64) -> (f64, f64) {
+        let (w, h) = self.extent();
+        let top = self.projection.y(self.north);
+        let bottom = self.projection.y(self.south);
+        let x = (lon - self.west) / (self.east - self.west) * w;
+        let y = match top > bottom {
+            true => (top - self.projection.y(lat)) / (top - bottom) * h,
+            false => 0.0,
+        };
+        (x, y)
+    }
+
+    /// Returns the pixel that a latitude and longitude in degrees falls on, or `None` if it is
+    /// off the map.
+    pub fn to_pixel(&self, lat: f64, lon: f64) -> Option<(u32, u32)> {
+        let (w, h) = self.extent();
+        let (x, y) = self.project(lat, lon);
+        let (x, y) = (x.round(), y.round());
+        if x >= 0.0 && y >= 0.0 && x <= w && y <= h && self.width > 0 && self.height > 0 {
+            Some((x as u32, y as u32))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the latitude and longitude in degrees at fractional pixel coordinates, the
+    /// inverse of `project`, such as the place under the mouse.
+    ///
+    /// ```
+    /// use drawille::{Projection, Viewport};
+    ///
+    /// let map = Viewport::new(Projection::Mercator, 200, 200).bounds(-30.0, 30.0, 45.0, 72.0);
+    /// let (x, y) = map.project(48.9, 2.4);
+    /// let (lat, lon) = map.unproject(x, y);
+    /// assert!((lat - 48.9).abs() < 1e-9 && (lon - 2.4).abs() < 1e-9);
+    /// ```
+    pub fn unproject(&self, x: f64, y: f64) -> (f64, f64) {
+        let (w, h) = self.extent();
+        let top = self.projection.y(self.north);
+        let bottom = self.projection.y(self.south);
+        let lon = if w > 0.0 { self.west + x / w * (self.east - self.west) } else { self.west };
+        let lat = match h > 0.0 {
+            true => self.projection.lat(top - y / h * (top - bottom)),
+            false => self.north,
+        };
+        let lon = if lon > 180.0 { lon - 360.0 } else { lon };
+        (lat, lon)
+    }
+
+    /// Returns the longitude in degrees moved by whole turns to within the bounds, or failing
+    /// that to within a turn east of the western edge.
+    fn wrap(&self, lon: f64) -> f64 {
+        if lon >= self.west && lon <= self.east {
+            lon
+        } else {
+            self.west + (lon - self.west).rem_euclid(360.0)
+        }
+    }
+
+    /// Returns the largest pixel coordinates within the `Viewport`.
+    fn extent(&self) -> (f64, f64) {
+        ((self.width as f64 - 1.0).max(0.0), (self.height as f64 - 1.0).max(0.0))
+    }
+
+    /// Draws a line between two points given in degrees onto a `Canvas`, clipped to the map.
+    ///
+    /// The line is straight on the map, so keep the points close together. One whose ends are
+    /// more than half a turn apart once wrapped goes the short way round instead, across the
+    /// edge of the map, and is split in two where it leaves one side and comes in the other.
+    pub(crate) fn line(&self, canvas: &mut Canvas, from: (f64, f64), to: (f64, f64)) {
+        let (a, b) = (self.wrap(from.1), self.wrap(to.1));
+        if (a - b).abs() <= 180.0 {
+            self.segment(canvas, (from.0, a), (to.0, b));
+            return;
+        }
+        // The far side of the seam is a turn east or west of the near side.
+        let turn = if a > b { 360.0 } else { -360.0 };
+        let (b, seam) = (b + turn, if a > b { self.west + 360.0 } else { self.west });
+        let lat = from.0 + (seam - a) / (b - a) * (to.0 - from.0);
+        self.segment(canvas, (from.0, a), (lat, seam));
+        self.segment(canvas, (lat, seam - turn), (to.0, b - turn));
+    }
+
+    /// Draws a straight line between two points on the map, with longitudes as they are.
+    fn segment(&self, canvas: &mut Canvas, from: (f64, f64), to: (f64, f64)) {
+        let (w, h) = self.extent();
+        let p1 = self.project_unwrapped(from.0, from.1);
+        let p2 = self.project_unwrapped(to.0, to.1);
+        if let Some(((x1, y1), (x2, y2))) = clip(p1, p2, w, h) {
+            canvas.line(x1.round() as u32, y1.round() as u32, x2.round() as u32, y2.round() as u32);
+        }
+    }
+}
+
+/// Returns points every degree or so along the shortest path over the globe between two
+/// places, each a latitude and longitude in degrees, from `from` to `to` inclusive.
+///
+/// The shortest path is a great circle, which on most maps is a curve bowing towards the pole,
+/// rather than the straight line between the places. For places on opposite sides of the
+/// globe, where every great circle through them is as short as any other, it goes over the
+/// north or south pole.
+///
+/// ```
+/// use drawille::great_circle;
+///
+/// // London to San Francisco passes over Greenland, well north of either city.
+/// let route = great_circle((51.5, -0.1), (37.8, -122.4), 16);
+/// assert_eq!(route.len(), 17);
+/// assert!(route.iter().any(|&(lat, _)| lat > 60.0));
+/// ```
+pub fn great_circle(from: (f64, f64), to: (f64, f64), steps: usize) -> Vec<(f64, f64)> {
+    let (p, q) = (unit(from), unit(to));
+    let cos = dot(p, q).clamp(-1.0, 1.0);
+    let angle = cos.acos();
+    // The direction to head off in from `p`, at right angles to it.
+    let towards = |v: [f64; 3]| [v[0] - cos * p[0], v[1] - cos * p[1], v[2] - cos * p[2]];
+    let mut dir = towards(q);
+    if dot(dir, dir) < 1e-18 {
+        let pole = if p[2].abs() < 1.0 - 1e-9 { [0.0, 0.0, 1.0] } else { [1.0, 0.0, 0.0] };
+        let along = dot(pole, p);
+        dir = [pole[0] - along * p[0], pole[1] - along * p[1], pole[2] - along * p[2]];
+    }
+    let len = dot(dir, dir).sqrt();
+    let steps = steps.max(1);
+    (0..=steps).map(|i| {
+        if i == 0 {
+            return from;
+        } else if i == steps {
+            return to;
+        }
+        let t = angle * i as f64 / steps as f64;
+        let (sin, cos) = t.sin_cos();
+        let v = [0, 1, 2].map(|k| p[k] * cos + dir[k] / len * sin);
+        (v[2].clamp(-1.0, 1.0).asin().to_degrees(), v[1].atan2(v[0]).to_degrees())
+    }).collect()
+}
+
+/// Returns the point on the unit sphere at a latitude and longitude in degrees.
+fn unit((lat, lon): (f64, f64)) -> [f64; 3] {
+    let (lat, lon) = (lat.to_radians(), lon.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+impl Canvas {
+    /// Sets the pixel at a latitude and longitude in degrees on a map, if it is on the map.
+    pub fn plot_geo(&mut self, viewport: &Viewport, lat: f64, lon: f64) {
+        if let Some((x, y)) = viewport.to_pixel(lat, lon) {
+            self.set(x, y);
+        }
+    }
+
+    /// Draws the shortest route over the globe between two places, each a latitude and
+    /// longitude in degrees, onto a map.
+    ///
+    /// The route follows the great circle between them, curving as the projection bends it,
+    /// and one that crosses the edge of the map at the antimeridian leaves one side and comes
+    /// back in the other.
+    ///
+    /// ```
+    /// use drawille::{Canvas, Projection, Viewport};
+    ///
+    /// let map = Viewport::new(Projection::Mercator, 160, 160);
+    /// let mut canvas = Canvas::new(0, 0);
+    /// // Tokyo to Los Angeles, across the Pacific and so across the edge of the map.
+    /// canvas.draw_great_circle(&map, (35.7, 139.7), (34.1, -118.2));
+    /// let xs: Vec<u32> = canvas.pixels().map(|(x, _)| x).collect();
+    /// assert!(xs.contains(&0) && xs.contains(&159));
+    /// // No line runs back across the whole map.
+    /// assert!(!xs.iter().any(|&x| x > 40 && x < 120));
+    /// ```
+    pub fn draw_great_circle(&mut self, viewport: &Viewport, from: (f64, f64), to: (f64, f64)) {
+        let angle = dot(unit(from), unit(to)).clamp(-1.0, 1.0).acos();
+        let steps = angle.to_degrees().ceil() as usize;
+        for pair in great_circle(from, to, steps).windows(2) {
+            viewport.line(self, pair[0], pair[1]);
+        }
+    }
+
+    /// Draws the coastlines of the world onto a map.
+    ///
+    /// The outlines are built in and coarse: the continents and the larger islands, simplified
+    /// by hand to a point every few degrees. They are good for showing where on the world
+    /// something is, not for any detail.
+    ///
+    /// ```
+    /// use drawille::{Canvas, Projection, Viewport};
+    ///
+    /// let map = Viewport::new(Projection::Equirectangular, 160, 80);
+    /// let mut canvas = Canvas::new(80, 20);
+    /// canvas.draw_coastlines(&map);
+    /// canvas.plot_geo(&map, 51.5, -0.1);
+    /// // The middle of the Pacific is open sea.
+    /// assert!(!canvas.get(5, 40));
+    /// ```
+    #[cfg(feature = "coastline")]
+    pub fn draw_coastlines(&mut self, viewport: &Viewport) {
+        for coast in COASTLINES {
+            for pair in coast.windows(2) {
+                let ((lon1, lat1), (lon2, lat2)) = (pair[0], pair[1]);
+                viewport.line(self, (lat1, lon1), (lat2, lon2));
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use canvas::Canvas;
+
+/// A graph of labelled nodes and the edges between them, laid out with a force-directed
+/// algorithm.
+///
+/// ```
+/// use drawille::{Canvas, Graph};
+///
+/// let mut graph = Graph::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// graph.add_edge(a, b);
+/// graph.add_edge(b, c);
+/// graph.add_edge(c, a);
+/// graph.layout(100);
+/// // The nodes spread out into a triangle with sides of about the same length.
+/// let p = graph.positions();
+/// let side = |i: usize, j: usize| (p[i].0 - p[j].0).hypot(p[i].1 - p[j].1);
+/// assert!((side(a, b) - side(b, c)).abs() < 0.1 && (side(b, c) - side(c, a)).abs() < 0.1);
+///
+/// let mut canvas = Canvas::new(60, 40);
+/// graph.draw(&mut canvas, 0, 0, 60, 40);
+/// let frame = canvas.frame();
+/// assert!(frame.contains("a") && frame.contains("b") && frame.contains("c"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Graph {
+    labels: Vec<String>,
+    edges: Vec<(usize, usize)>,
+    positions: Vec<(f64, f64)>,
+}
+
+impl Graph {
+    /// Creates a new, empty `Graph`.
+    pub fn new() -> Graph {
+        Graph::default()
+    }
+
+    /// Adds a node to the `Graph` and returns its index.
+    ///
+    /// New nodes start out spread around a circle, so that layouts are reproducible.
+    pub fn add_node<S: Into<String>>(&mut self, label: S) -> usize {
+        let index = self.labels.len();
+        let angle = index as f64 * 2.399_963; // The golden angle, in radians.
+        let radius = (index as f64 + 1.0).sqrt();
+        self.labels.push(label.into());
+        self.positions.push((radius * angle.cos(), radius * angle.sin()));
+        index
+    }
+
+    /// Adds an edge between the nodes with the given indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index isn’t that of a node.
+    pub fn add_edge(&mut self, a: usize, b: usize) {
+        assert!(a < self.labels.len() && b < self.labels.len(), "edge to a missing node");
+        self.edges.push((a, b));
+    }
+
+    /// Returns the labels of the nodes, in the order they were added.
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Returns the edges of the `Graph`.
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// Returns the current positions of the nodes, in arbitrary units.
+    pub fn positions(&self) -> &[(f64, f64)] {
+        &self.positions
+    }
+
+    /// Moves the nodes for the given number of iterations of the Fruchterman–Reingold
+    /// algorithm: every pair of nodes repels, every edge attracts, and the distance nodes may
+    /// move cools down over time.
+    pub fn layout(&mut self, iterations: usize) {
+        let n = self.positions.len();
+        if n < 2 {
+            return;
+        }
+        // The ideal distance between nodes, in a unit square per node.
+        let k = 1.0;
+        let mut temperature = (n as f64).sqrt();
+        let cooling = temperature / (iterations as f64 + 1.0);
+        for _ in 0..iterations {
+            let mut forces = vec![(0.0, 0.0); n];
+            for i in 0..n {
+                for j in i + 1..n {
+                    let (dx, dy, d) = self.offset(i, j);
+                    let f = k * k / d;
+                    forces[i].0 += dx / d * f;
+                    forces[i].1 += dy / d * f;
+                    forces[j].0 -= dx / d * f;
+                    forces[j].1 -= dy / d * f;
+                }
+            }
+            for &(a, b) in &self.edges {
+                if a == b {
+                    continue;
+                }
+                let (dx, dy, d) = self.offset(a, b);
+                let f = d * d / k;
+                forces[a].0 -= dx / d * f;
+                forces[a].1 -= dy / d * f;
+                forces[b].0 += dx / d * f;
+                forces[b].1 += dy / d * f;
+            }
+            for (p, f) in self.positions.iter_mut().zip(&forces) {
+                let length = f.0.hypot(f.1);
+                if length > 0.0 {
+                    let step = length.min(temperature);
+                    p.0 += f.0 / length * step;
+                    p.1 += f.1 / length * step;
+                }
+            }
+            temperature -= cooling;
+        }
+    }
+
+    /// Draws the `Graph` onto the `Canvas`, scaling its layout to fit a rectangle given by its
+    /// top-left corner and size in pixels.
+    ///
+    /// Edges are lines, nodes are small squares and labels are overlay text to the right of
+    /// their nodes, so they don’t erase the edges beneath them.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, width: u32, height: u32) {
+        if self.positions.is_empty() || width < 3 || height < 3 {
+            return;
+        }
+        let (mut min, mut max) = ((f64::INFINITY, f64::INFINITY), (f64::NEG_INFINITY,
+                                                                   f64::NEG_INFINITY));
+        for &(px, py) in &self.positions {
+            min = (min.0.min(px), min.1.min(py));
+            max = (max.0.max(px), max.1.max(py));
+        }
+        // Leave a pixel around the edge for the node squares.
+        let (w, h) = ((width - 3) as f64, (height - 3) as f64);
+        let scale = |v: f64, lo: f64, hi: f64, size: f64| {
+            if hi > lo { (v - lo) / (hi - lo) * size } else { size / 2.0 }
+        };
+        let pixels: Vec<(u32, u32)> = self.positions.iter().map(|&(px, py)| {
+            (x + 1 + scale(px, min.0, max.0, w).round() as u32,
+             y + 1 + scale(py, min.1, max.1, h).round() as u32)
+        }).collect();
+        for &(a, b) in &self.edges {
+            let ((x1, y1), (x2, y2)) = (pixels[a], pixels[b]);
+            canvas.line(x1, y1, x2, y2);
+        }
+        for (&(px, py), label) in pixels.iter().zip(&self.labels) {
+            for dy in 0..3 {
+                for dx in 0..3 {
+                    canvas.set(px + dx - 1, py + dy - 1);
+                }
+            }
+            canvas.overlay_text((px / 2 + 1) * 2, py, u32::MAX, label);
+        }
+    }
+
+    /// Returns the offset from node `b` to node `a`, and its length, which is never zero.
+    fn offset(&self, a: usize, b: usize) -> (f64, f64, f64) {
+        let (pa, pb) = (self.positions[a], self.positions[b]);
+        let (dx, dy) = (pa.0 - pb.0, pa.1 - pb.1);
+        (dx, dy, dx.hypot(dy).max(0.01))
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::Write;
+
+use canvas::Canvas;
+use style::{Color, Style};
+
+impl Canvas {
+    /// Renders the `Canvas` as an HTML `<pre>` element, with styled cells as `<span>`s carrying
+    /// inline CSS, for embedding in web pages and CI reports.
+    ///
+    /// Colours are given as RGB, using xterm’s palette for the named ones.
+    ///
+    /// ```
+    /// use drawille::{Canvas, Color, Span, Style};
+    ///
+    /// let mut canvas = Canvas::new(6, 4);
+    /// canvas.text_styled(0, 0, 6, &[Span::new("<a", Style::new().fg(Color::Red))]);
+    /// assert!(canvas.to_html().starts_with("<pre class=\"drawille\">\
+    ///     <span style=\"color:#cd0000\">&lt;a</span>  \n"));
+    /// ```
+    pub fn to_html(&self) -> String {
+        let (columns, rows) = self.extents();
+        let mut out = String::from("<pre class=\"drawille\">");
+        for y in 0..rows {
+            if y > 0 {
+                out.push('\n');
+            }
+            let mut current = Style::default();
+            for x in 0..columns {
+                let (c, style) = self.cell(x as u16, y as u16);
+                if style != current {
+                    if !current.is_plain() {
+                        out.push_str("</span>");
+                    }
+                    if !style.is_plain() {
+                        let _ = write!(out, "<span style=\"{}\">", css(&style));
+                    }
+                    current = style;
+                }
+                match c {
+                    '&' => out.push_str("&amp;"),
+                    '<' => out.push_str("&lt;"),
+                    '>' => out.push_str("&gt;"),
+                    '"' => out.push_str("&quot;"),
+                    c => out.push(c),
+                }
+            }
+            if !current.is_plain() {
+                out.push_str("</span>");
+            }
+        }
+        out.push_str("</pre>");
+        out
+    }
+}
+
+/// Returns the inline CSS declarations for a style.
+fn css(style: &Style) -> String {
+    let hex = |color: &Color| {
+        let (r, g, b) = color.to_rgb();
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    };
+    let mut declarations = Vec::new();
+    if let Some(ref fg) = style.fg {
+        declarations.push(format!("color:{}", hex(fg)));
+    }
+    if let Some(ref bg) = style.bg {
+        declarations.push(format!("background-color:{}", hex(bg)));
+    }
+    if style.bold {
+        declarations.push("font-weight:bold".to_string());
+    }
+    if style.underline {
+        declarations.push("text-decoration:underline".to_string());
+    }
+    declarations.join(";")
+}
@@ -0,0 +1,154 @@
+#[cfg(feature = "image")]
+use image::DynamicImage;
+
+use canvas::Canvas;
+
+/// How the brightness of an image is turned into pixels when importing it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImportMode {
+    /// Sets every pixel whose brightness is at least the given threshold.
+    Threshold(u8),
+    /// Runs a Sobel edge detector and sets only the pixels whose gradient magnitude is at least
+    /// the given threshold.
+    ///
+    /// The gradient is scaled so that a sharp edge between black and white reaches 255. This
+    /// usually reads far better than `Threshold` for photographs.
+    Edges(u8),
+}
+
+/// For each row of a cell, the dot bits that each byte of a bitmap row gives the four cells it
+/// covers, one cell per byte of the `u32` with the leftmost lowest.
+///
+/// Looking up the four rows of a block and or-ing them together transposes eight columns by
+/// four rows of pixels into four cells at once.
+static CELL_BITS: [[u32; 256]; 4] = [cell_bits(0), cell_bits(1), cell_bits(2), cell_bits(3)];
+
+const fn cell_bits(row: usize) -> [u32; 256] {
+    let left = [0x01, 0x02, 0x04, 0x40][row];
+    let right = [0x08, 0x10, 0x20, 0x80][row];
+    let mut table = [0; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut cell = 0;
+        while cell < 4 {
+            if byte & (0x80 >> (2 * cell)) != 0 {
+                table[byte] |= left << (8 * cell);
+            }
+            if byte & (0x40 >> (2 * cell)) != 0 {
+                table[byte] |= right << (8 * cell);
+            }
+            cell += 1;
+        }
+        byte += 1;
+    }
+    table
+}
+
+impl Canvas {
+    /// Creates a new `Canvas` from a black and white bitmap, with one bit per pixel, the most
+    /// significant bit of each byte leftmost, and each row padded to a whole number of bytes.
+    ///
+    /// This converts whole blocks of pixels into cells at once, without setting each pixel in
+    /// turn, so it is fast enough to turn video into Braille as it plays.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bitmap` holds fewer than `(width + 7) / 8 * height` bytes.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let bitmap = [0b1100_0000,
+    ///               0b1000_0001];
+    /// let canvas = Canvas::from_bitmap(8, 2, &bitmap);
+    /// assert_eq!(canvas.rows()[0], "⠋  ⠐ ");
+    /// ```
+    pub fn from_bitmap(width: u32, height: u32, bitmap: &[u8]) -> Canvas {
+        let stride = width.div_ceil(8) as usize;
+        let h = height as usize;
+        assert!(bitmap.len() >= stride * h, "bitmap is smaller than its width and height");
+        let mut canvas = Canvas::new(width, height);
+        // Padding bits past the last pixel of a row are ignored.
+        let last_mask = match width % 8 {
+            0 => 0xff,
+            n => 0xff << (8 - n),
+        };
+        for (cell_row, y) in (0..h).step_by(4).enumerate() {
+            if cell_row > u16::MAX as usize {
+                break;
+            }
+            for bx in 0..stride.min(u16::MAX as usize / 4 + 1) {
+                let mut cells = 0;
+                for (dy, table) in CELL_BITS.iter().enumerate().take(h - y) {
+                    let mut byte = bitmap[(y + dy) * stride + bx];
+                    if bx == stride - 1 {
+                        byte &= last_mask;
+                    }
+                    cells |= table[byte as usize];
+                }
+                if cells == 0 {
+                    continue;
+                }
+                for cell in 0..4 {
+                    let col = (bx * 4 + cell) as u16;
+                    canvas.or_cell(col, cell_row as u16, (cells >> (8 * cell)) as u8);
+                }
+            }
+        }
+        canvas
+    }
+
+    /// Creates a new `Canvas` from a greyscale image, given as one brightness byte per pixel in
+    /// row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `luma` holds fewer than `width * height` bytes.
+    ///
+    /// ```
+    /// use drawille::{Canvas, ImportMode};
+    ///
+    /// let luma = [0, 0, 255, 255,
+    ///             0, 0, 255, 255];
+    /// let canvas = Canvas::from_luma(4, 2, &luma, ImportMode::Edges(128));
+    /// assert!(!canvas.get(0, 0) && canvas.get(1, 0) && canvas.get(2, 0) && !canvas.get(3, 0));
+    /// ```
+    pub fn from_luma(width: u32, height: u32, luma: &[u8], mode: ImportMode) -> Canvas {
+        let (w, h) = (width as usize, height as usize);
+        assert!(luma.len() >= w * h, "luma buffer is smaller than width * height");
+        let stride = w.div_ceil(8);
+        let mut bitmap = vec![0; stride * h];
+        for y in 0..h {
+            for x in 0..w {
+                let on = match mode {
+                    ImportMode::Threshold(t) => luma[y * w + x] >= t,
+                    ImportMode::Edges(t) => sobel(luma, w, h, x, y) >= t as u32,
+                };
+                if on {
+                    bitmap[y * stride + x / 8] |= 0x80 >> (x % 8);
+                }
+            }
+        }
+        Canvas::from_bitmap(width, height, &bitmap)
+    }
+
+    /// Creates a new `Canvas` from an image, converting it to greyscale first.
+    #[cfg(feature = "image")]
+    pub fn from_image(image: &DynamicImage, mode: ImportMode) -> Canvas {
+        let luma = image.to_luma8();
+        Canvas::from_luma(luma.width(), luma.height(), luma.as_raw(), mode)
+    }
+}
+
+/// Computes the Sobel gradient magnitude at a pixel, repeating the border pixels outwards.
+fn sobel(luma: &[u8], w: usize, h: usize, x: usize, y: usize) -> u32 {
+    let at = |dx: isize, dy: isize| {
+        let sx = (x as isize + dx).max(0).min(w as isize - 1) as usize;
+        let sy = (y as isize + dy).max(0).min(h as isize - 1) as usize;
+        luma[sy * w + sx] as i32
+    };
+    let gx = at(1, -1) + 2 * at(1, 0) + at(1, 1) - at(-1, -1) - 2 * at(-1, 0) - at(-1, 1);
+    let gy = at(-1, 1) + 2 * at(0, 1) + at(1, 1) - at(-1, -1) - 2 * at(0, -1) - at(1, -1);
+    let magnitude = ((gx * gx + gy * gy) as f64).sqrt() / 4.0;
+    magnitude.min(255.0) as u32
+}
@@ -0,0 +1,107 @@
+use std::fmt::Write;
+
+use canvas::Canvas;
+use style::Color;
+
+/// The colour of pixels in cells without a foreground colour of their own.
+const DEFAULT_COLOR: Color = Color::White;
+
+/// The largest number of base64 bytes that the Kitty graphics protocol takes in one escape.
+const KITTY_CHUNK: usize = 4096;
+
+impl Canvas {
+    /// Rasterises the `Canvas` to 8-bit RGBA, with each pixel as a `scale`×`scale` square, and
+    /// returns it along with its width and height. Unset pixels are transparent.
+    fn to_rgba(&self, scale: u32) -> (Vec<u8>, u32, u32) {
+        let (image, width, height) = self.rasterize(scale, DEFAULT_COLOR);
+        let mut rgba = Vec::with_capacity(image.len() * 4);
+        for pixel in image {
+            match pixel {
+                Some(color) => {
+                    let (r, g, b) = color.to_rgb();
+                    rgba.extend_from_slice(&[r, g, b, 255]);
+                }
+                None => rgba.extend_from_slice(&[0, 0, 0, 0]),
+            }
+        }
+        (rgba, width, height)
+    }
+
+    /// Renders the `Canvas` as an image using the Kitty graphics protocol, with each pixel drawn
+    /// as a `scale`×`scale` square.
+    ///
+    /// Set pixels take the foreground colour of their cell, or white if it has none, and the
+    /// rest are transparent. Letters aren’t drawn.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(2, 4);
+    /// canvas.set(0, 0);
+    /// assert!(canvas.to_kitty(2).starts_with("\x1b_Gf=32,s=8,v=16,a=T,m=0;"));
+    /// ```
+    pub fn to_kitty(&self, scale: u32) -> String {
+        let (rgba, width, height) = self.to_rgba(scale);
+        let data = base64(&rgba);
+        let chunks: Vec<&str> = data.as_bytes().chunks(KITTY_CHUNK)
+            .map(|c| ::std::str::from_utf8(c).unwrap_or_default())
+            .collect();
+        let mut out = String::new();
+        for (i, chunk) in chunks.iter().enumerate() {
+            let more = (i + 1 < chunks.len()) as u8;
+            if i == 0 {
+                let _ = write!(out, "\x1b_Gf=32,s={},v={},a=T,m={};{}\x1b\\", width, height, more,
+                               chunk);
+            } else {
+                let _ = write!(out, "\x1b_Gm={};{}\x1b\\", more, chunk);
+            }
+        }
+        out
+    }
+
+    /// Renders the `Canvas` as a PNG image using iTerm2’s inline image protocol, with each pixel
+    /// drawn as a `scale`×`scale` square.
+    ///
+    /// Set pixels take the foreground colour of their cell, or white if it has none, and the
+    /// rest are transparent. Letters aren’t drawn.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(2, 4);
+    /// canvas.set(0, 0);
+    /// assert!(canvas.to_iterm2(2).starts_with("\x1b]1337;File=inline=1;"));
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn to_iterm2(&self, scale: u32) -> String {
+        use image::{ImageEncoder, ExtendedColorType};
+        use image::codecs::png::PngEncoder;
+
+        let (rgba, width, height) = self.to_rgba(scale);
+        let mut png = Vec::new();
+        // Encoding into memory can only fail on invalid dimensions, which `to_rgba` never gives.
+        let _ = PngEncoder::new(&mut png).write_image(&rgba, width, height,
+                                                      ExtendedColorType::Rgba8);
+        format!("\x1b]1337;File=inline=1;size={};preserveAspectRatio=1:{}\x07", png.len(),
+                base64(&png))
+    }
+}
+
+/// Encodes bytes as standard, padded base64.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = (chunk[0] as u32) << 16 | (*chunk.get(1).unwrap_or(&0) as u32) << 8
+            | *chunk.get(2).unwrap_or(&0) as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - i * 6) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
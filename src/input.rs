@@ -0,0 +1,209 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crossterm::event::{self as ct, DisableMouseCapture, EnableMouseCapture, KeyCode, KeyEventKind,
+                       KeyModifiers, MouseEventKind};
+use crossterm::execute;
+use crossterm::terminal;
+
+use coords::CellPoint;
+
+/// A key pressed on the keyboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    Escape,
+    Backspace,
+    Tab,
+    /// A printable character, including the space.
+    Char(char),
+}
+
+impl Key {
+    /// Returns the direction that the key points in as a step along the x and y axes, with y
+    /// growing downwards, for the arrow keys and for W, A, S and D.
+    ///
+    /// ```
+    /// use drawille::Key;
+    ///
+    /// assert_eq!(Key::Up.direction(), Some((0, -1)));
+    /// assert_eq!(Key::Char('d').direction(), Some((1, 0)));
+    /// assert_eq!(Key::Char('x').direction(), None);
+    /// ```
+    pub fn direction(&self) -> Option<(i32, i32)> {
+        match *self {
+            Key::Up | Key::Char('w') | Key::Char('W') => Some((0, -1)),
+            Key::Down | Key::Char('s') | Key::Char('S') => Some((0, 1)),
+            Key::Left | Key::Char('a') | Key::Char('A') => Some((-1, 0)),
+            Key::Right | Key::Char('d') | Key::Char('D') => Some((1, 0)),
+            _ => None,
+        }
+    }
+}
+
+/// A mouse button.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Middle,
+    Right,
+}
+
+/// What the mouse did.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MouseAction {
+    Press(MouseButton),
+    Release(MouseButton),
+    /// The mouse moved while the button was held.
+    Drag(MouseButton),
+    /// The mouse moved with no button held.
+    Move,
+    ScrollUp,
+    ScrollDown,
+}
+
+/// Something the user did.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputEvent {
+    Key(Key),
+    /// The mouse did something at the given column and row of cells.
+    Mouse(MouseAction, u32, u32),
+    /// The terminal was resized to the given number of columns and rows.
+    Resize(u32, u32),
+    /// The user asked to quit, with Q or Ctrl+C.
+    ///
+    /// Escape arrives as `Key::Escape`, so that a program can use it to back out of a menu or
+    /// a mode and decide for itself whether it quits.
+    Quit,
+}
+
+impl InputEvent {
+    /// Returns the pixel at the top-left of the cell of a mouse event, in the coordinates of a
+    /// `Canvas` drawn from the top-left of the terminal.
+    pub fn pixel(&self) -> Option<(u32, u32)> {
+        match *self {
+            InputEvent::Mouse(_, column, row) => {
+                Some(CellPoint::new(column, row).top_left().into())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Keyboard and mouse input from the terminal, for interactive programs.
+///
+/// While an `Input` exists, the terminal is in raw mode, so keys arrive as they are pressed
+/// rather than a line at a time, and mouse events are reported. Both are restored when it is
+/// dropped.
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use drawille::{Canvas, Input, InputEvent, Key};
+///
+/// let mut input = Input::new().unwrap();
+/// let (mut x, mut y) = (10, 10);
+/// loop {
+///     match input.poll(Duration::from_millis(30)).unwrap() {
+///         Some(InputEvent::Quit) | Some(InputEvent::Key(Key::Escape)) => break,
+///         Some(InputEvent::Key(key)) => if let Some((dx, dy)) = key.direction() {
+///             x += dx;
+///             y += dy;
+///         },
+///         _ => {}
+///     }
+///     let mut canvas = Canvas::new(40, 40);
+///     canvas.set(x as u32, y as u32);
+///     print!("\x1b[H{}", canvas.frame_crlf());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Input {
+    _private: (),
+}
+
+impl Input {
+    /// Puts the terminal into raw mode and starts reporting mouse events.
+    pub fn new() -> io::Result<Input> {
+        terminal::enable_raw_mode()?;
+        if let Err(e) = execute!(io::stdout(), EnableMouseCapture) {
+            let _ = terminal::disable_raw_mode();
+            return Err(e);
+        }
+        Ok(Input { _private: () })
+    }
+
+    /// Waits up to `timeout` for an event, returning `None` if there was none.
+    ///
+    /// Events this facade doesn’t describe, such as function keys, are also returned as
+    /// `None`, so that a game loop can carry on regardless.
+    pub fn poll(&mut self, timeout: Duration) -> io::Result<Option<InputEvent>> {
+        if ct::poll(timeout)? {
+            Ok(convert(ct::read()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Blocks until the next event that this facade describes.
+    pub fn read(&mut self) -> io::Result<InputEvent> {
+        loop {
+            if let Some(event) = convert(ct::read()?) {
+                return Ok(event);
+            }
+        }
+    }
+}
+
+impl Drop for Input {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), DisableMouseCapture);
+        let _ = io::stdout().flush();
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Converts a crossterm event, if it’s one that `InputEvent` describes.
+fn convert(event: ct::Event) -> Option<InputEvent> {
+    match event {
+        ct::Event::Key(key) if key.kind != KeyEventKind::Release => {
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            Some(match key.code {
+                KeyCode::Char('c') if ctrl => InputEvent::Quit,
+                KeyCode::Char('q') | KeyCode::Char('Q') => InputEvent::Quit,
+                KeyCode::Up => InputEvent::Key(Key::Up),
+                KeyCode::Down => InputEvent::Key(Key::Down),
+                KeyCode::Left => InputEvent::Key(Key::Left),
+                KeyCode::Right => InputEvent::Key(Key::Right),
+                KeyCode::Enter => InputEvent::Key(Key::Enter),
+                KeyCode::Esc => InputEvent::Key(Key::Escape),
+                KeyCode::Backspace => InputEvent::Key(Key::Backspace),
+                KeyCode::Tab => InputEvent::Key(Key::Tab),
+                KeyCode::Char(c) => InputEvent::Key(Key::Char(c)),
+                _ => return None,
+            })
+        }
+        ct::Event::Mouse(mouse) => {
+            let button = |b| match b {
+                ct::MouseButton::Left => MouseButton::Left,
+                ct::MouseButton::Middle => MouseButton::Middle,
+                ct::MouseButton::Right => MouseButton::Right,
+            };
+            let action = match mouse.kind {
+                MouseEventKind::Down(b) => MouseAction::Press(button(b)),
+                MouseEventKind::Up(b) => MouseAction::Release(button(b)),
+                MouseEventKind::Drag(b) => MouseAction::Drag(button(b)),
+                MouseEventKind::Moved => MouseAction::Move,
+                MouseEventKind::ScrollUp => MouseAction::ScrollUp,
+                MouseEventKind::ScrollDown => MouseAction::ScrollDown,
+                _ => return None,
+            };
+            Some(InputEvent::Mouse(action, mouse.column as u32, mouse.row as u32))
+        }
+        ct::Event::Resize(columns, rows) => Some(InputEvent::Resize(columns as u32, rows as u32)),
+        _ => None,
+    }
+}
@@ -0,0 +1,254 @@
+use std::cmp::Ordering;
+use std::f64;
+
+use art::Art;
+use canvas::Canvas;
+use chart::Fill;
+use world::clip;
+
+/// Something placed on the grid of an `Isometric` map.
+#[derive(Clone, Debug)]
+enum Item {
+    Tile { col: i32, row: i32, fill: Option<Fill> },
+    Block { col: i32, row: i32, height: u32, fill: Option<Fill> },
+    Sprite { col: f64, row: f64, art: Box<Art> },
+}
+
+impl Item {
+    /// Returns whether the item lies flat on the ground, beneath everything standing up.
+    fn is_flat(&self) -> bool {
+        matches!(*self, Item::Tile { .. })
+    }
+
+    /// Returns how far back the item is: the further back, the earlier it is drawn.
+    fn depth(&self) -> f64 {
+        match *self {
+            Item::Tile { col, row, .. } | Item::Block { col, row, .. } => (col + row) as f64 + 1.0,
+            Item::Sprite { col, row, .. } => col + row + 1.0,
+        }
+    }
+}
+
+/// An isometric map: a grid of diamond tiles seen from above at an angle, with raised blocks
+/// and sprites standing on it, for strategy and building games.
+///
+/// Columns of the grid run down to the right and rows down to the left, so that tile `(0, 0)`
+/// is at the top. Flat tiles are drawn first, then blocks and sprites from the back of the map
+/// to the front, each clearing what it stands in front of, so that nearer things hide those
+/// behind them whichever order they were added in. Things at the same depth are drawn in the
+/// order they were added, so add a sprite standing on a block after the block.
+///
+/// Unless given an origin, the map is placed so that everything on it just fits against the
+/// top-left corner of the `Canvas`.
+///
+/// ```
+/// use drawille::{Art, Canvas, Isometric};
+/// use drawille::chart::Fill;
+///
+/// let person = Art::parse("⢀⡀\n⣿⣿\n⡇⢸").unwrap();
+/// let mut ground = Isometric::new(16, 8);
+/// for row in 0..4 {
+///     for col in 0..4 {
+///         ground = ground.tile(col, row, None);
+///     }
+/// }
+/// // The block is in front of the person, so partly hides them whichever is added first.
+/// let map = ground.clone().block(1, 1, 12, Some(Fill::Solid)).sprite(0.0, 0.0, person.clone());
+/// let other = ground.sprite(0.0, 0.0, person).block(1, 1, 12, Some(Fill::Solid));
+/// let (mut canvas, mut other_canvas) = (Canvas::new(0, 0), Canvas::new(0, 0));
+/// map.draw(&mut canvas);
+/// other.draw(&mut other_canvas);
+/// assert_eq!(canvas.frame(), other_canvas.frame());
+///
+/// let (x, y) = map.to_screen(0.5, 0.5);
+/// assert_eq!(map.tile_at(x, y), (0, 0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Isometric {
+    tile: (u32, u32),
+    origin: Option<(f64, f64)>,
+    items: Vec<Item>,
+}
+
+impl Isometric {
+    /// Creates a new, empty `Isometric` map of diamond tiles `width` by `height` pixels, which
+    /// look right at twice as wide as they are tall.
+    pub fn new(width: u32, height: u32) -> Isometric {
+        Isometric { tile: (width.max(2), height.max(2)), origin: None, items: Vec::new() }
+    }
+
+    /// Places the top corner of tile `(0, 0)` at a pixel instead of fitting the map against the
+    /// top-left corner of the `Canvas`, such as for scrolling around a map larger than it, and
+    /// return it for use again.
+    ///
+    /// Whatever falls above or left of the `Canvas` is left out.
+    pub fn origin(mut self, x: f64, y: f64) -> Isometric {
+        self.origin = Some((x, y));
+        self
+    }
+
+    /// Adds a flat tile, outlined and shaded with a `Fill` or left empty, and return it for use
+    /// again.
+    pub fn tile(mut self, col: i32, row: i32, fill: Option<Fill>) -> Isometric {
+        self.items.push(Item::Tile { col, row, fill });
+        self
+    }
+
+    /// Adds a block standing on a tile, `height` pixels tall, with its top shaded with a `Fill`
+    /// or left empty and its sides shaded with a checkerboard on the left and sparsely on the
+    /// right, and return it for use again.
+    pub fn block(mut self, col: i32, row: i32, height: u32, fill: Option<Fill>) -> Isometric {
+        self.items.push(Item::Block { col, row, height, fill });
+        self
+    }
+
+    /// Adds a sprite standing with the middle of its bottom edge on the middle of a tile, and
+    /// return it for use again.
+    ///
+    /// The sprite may stand between tiles, at fractions of a column or row, such as while it
+    /// walks from one to the next.
+    pub fn sprite(mut self, col: f64, row: f64, art: Art) -> Isometric {
+        self.items.push(Item::Sprite { col, row, art: Box::new(art) });
+        self
+    }
+
+    /// Returns the pixel at a point of the grid, in columns and rows, where the top corner of
+    /// tile `(col, row)` is the point `(col, row)` and its middle `(col + 0.5, row + 0.5)`.
+    pub fn to_screen(&self, col: f64, row: f64) -> (f64, f64) {
+        let (ox, oy) = self.resolved_origin();
+        let (w, h) = (self.tile.0 as f64 / 2.0, self.tile.1 as f64 / 2.0);
+        (ox + (col - row) * w, oy + (col + row) * h)
+    }
+
+    /// Returns the tile under a pixel on the ground, such as the one under the mouse, the
+    /// inverse of `to_screen`.
+    pub fn tile_at(&self, x: f64, y: f64) -> (i32, i32) {
+        let (ox, oy) = self.resolved_origin();
+        let (w, h) = (self.tile.0 as f64 / 2.0, self.tile.1 as f64 / 2.0);
+        let (u, v) = ((x - ox) / w, (y - oy) / h);
+        (((u + v) / 2.0).floor() as i32, ((v - u) / 2.0).floor() as i32)
+    }
+
+    /// Draws the map onto the `Canvas`.
+    pub fn draw(&self, canvas: &mut Canvas) {
+        let mut order: Vec<&Item> = self.items.iter().collect();
+        // A stable sort, so that things at the same depth keep the order they were added in.
+        order.sort_by(|a, b| b.is_flat().cmp(&a.is_flat())
+            .then(a.depth().partial_cmp(&b.depth()).unwrap_or(Ordering::Equal)));
+        let (hw, hh) = (self.tile.0 as f64 / 2.0, self.tile.1 as f64 / 2.0);
+        for item in order {
+            match *item {
+                Item::Tile { col, row, fill } => {
+                    let (x, y) = self.to_screen(col as f64, row as f64);
+                    let top = [(x, y), (x + hw, y + hh), (x, y + 2.0 * hh), (x - hw, y + hh)];
+                    paint(canvas, &top, fill);
+                    outline(canvas, &top);
+                }
+                Item::Block { col, row, height, fill } => {
+                    let (x, y) = self.to_screen(col as f64, row as f64);
+                    let (b, t) = (y + 2.0 * hh, y + 2.0 * hh - height as f64);
+                    let (side, up) = (y + hh, y + hh - height as f64);
+                    let top = [(x, y - height as f64), (x + hw, up), (x, t), (x - hw, up)];
+                    let left = [(x - hw, up), (x, t), (x, b), (x - hw, side)];
+                    let right = [(x, t), (x + hw, up), (x + hw, side), (x, b)];
+                    paint(canvas, &top, fill);
+                    paint(canvas, &left, Some(Fill::Checker));
+                    paint(canvas, &right, Some(Fill::Sparse));
+                    for face in &[&top[..], &left[..], &right[..]] {
+                        outline(canvas, face);
+                    }
+                }
+                Item::Sprite { col, row, ref art } => {
+                    let (x, y) = self.to_screen(col + 0.5, row + 0.5);
+                    let (w, h) = art.pixel_size();
+                    let (left, top) = ((x - w as f64 / 2.0).round(), y.round() - h as f64);
+                    // Clear the outline of the sprite, row by row, so that what it stands in
+                    // front of doesn’t show through the gaps between its dots.
+                    let mut spans = vec![(u32::MAX, 0); h as usize];
+                    for (px, py) in art.canvas().pixels() {
+                        let span = &mut spans[py as usize];
+                        *span = (span.0.min(px), span.1.max(px));
+                    }
+                    for (dy, &(from, to)) in spans.iter().enumerate() {
+                        let py = top + dy as f64;
+                        for dx in from..to.saturating_add(1) {
+                            if left + dx as f64 >= 0.0 && py >= 0.0 {
+                                canvas.unset((left + dx as f64) as u32, py as u32);
+                            }
+                        }
+                    }
+                    if left >= 0.0 && top >= 0.0 {
+                        art.draw(canvas, left as u32, top as u32);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the pixel of the top corner of tile `(0, 0)`, as given or so that the map fits
+    /// against the top-left corner of the `Canvas`.
+    fn resolved_origin(&self) -> (f64, f64) {
+        if let Some(origin) = self.origin {
+            return origin;
+        }
+        let (hw, hh) = (self.tile.0 as f64 / 2.0, self.tile.1 as f64 / 2.0);
+        let (mut left, mut top) = (f64::INFINITY, f64::INFINITY);
+        for item in &self.items {
+            let (l, t) = match *item {
+                Item::Tile { col, row, .. } => {
+                    ((col - row) as f64 * hw - hw, (col + row) as f64 * hh)
+                }
+                Item::Block { col, row, height, .. } => {
+                    ((col - row) as f64 * hw - hw, (col + row) as f64 * hh - height as f64)
+                }
+                Item::Sprite { col, row, ref art } => {
+                    let (w, h) = art.pixel_size();
+                    ((col - row) * hw - w as f64 / 2.0, (col + row + 1.0) * hh - h as f64)
+                }
+            };
+            left = left.min(l);
+            top = top.min(t);
+        }
+        if left.is_finite() { (-left.floor(), -top.floor()) } else { (0.0, 0.0) }
+    }
+}
+
+/// Sets the pixels inside a convex polygon that a `Fill` covers and clears the rest, or clears
+/// them all without one.
+fn paint(canvas: &mut Canvas, polygon: &[(f64, f64)], fill: Option<Fill>) {
+    let (top, bottom) = polygon.iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |r, p| (r.0.min(p.1), r.1.max(p.1)));
+    for py in top.max(0.0).ceil() as u32..=bottom.max(0.0).floor() as u32 {
+        let y = py as f64;
+        let (mut from, mut to) = (f64::INFINITY, f64::NEG_INFINITY);
+        for (i, &(x1, y1)) in polygon.iter().enumerate() {
+            let (x2, y2) = polygon[(i + 1) % polygon.len()];
+            if (y1 <= y && y <= y2) || (y2 <= y && y <= y1) {
+                let x = if y1 == y2 { x1.min(x2) } else { x1 + (y - y1) / (y2 - y1) * (x2 - x1) };
+                let x_end = if y1 == y2 { x1.max(x2) } else { x };
+                from = from.min(x);
+                to = to.max(x_end);
+            }
+        }
+        if from > to || to < 0.0 {
+            continue;
+        }
+        for px in from.max(0.0).ceil() as u32..=to.floor() as u32 {
+            match fill {
+                Some(fill) if fill.covers(px, py) => canvas.set(px, py),
+                _ => canvas.unset(px, py),
+            }
+        }
+    }
+}
+
+/// Draws the edges of a closed polygon, leaving out what falls above or left of the `Canvas`.
+fn outline(canvas: &mut Canvas, polygon: &[(f64, f64)]) {
+    let far = u32::MAX as f64;
+    for (i, &p1) in polygon.iter().enumerate() {
+        let p2 = polygon[(i + 1) % polygon.len()];
+        if let Some(((x1, y1), (x2, y2))) = clip(p1, p2, far, far) {
+            canvas.line(x1.round() as u32, y1.round() as u32, x2.round() as u32, y2.round() as u32);
+        }
+    }
+}
@@ -0,0 +1,357 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use animation::Animation;
+use canvas::Canvas;
+use style::{Color, Style};
+
+/// A change made to a `Canvas`, as recorded in a `Journal`.
+///
+/// Coordinates are where the change landed, after the out-of-bounds policy and with y counting
+/// down from the top, so that a journal replays the same onto a `Canvas` with default settings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Edit {
+    /// A pixel was set.
+    Set(u32, u32),
+    /// A pixel was deleted.
+    Unset(u32, u32),
+    /// A pixel was toggled.
+    Toggle(u32, u32),
+    /// The pixels in the column `x` from the first y to the second inclusive were set.
+    Vline(u32, u32, u32),
+    /// A letter was set with the given style.
+    Char(u32, u32, char, Style),
+    /// A letter was set on the overlay with the given style.
+    OverlayChar(u32, u32, char, Style),
+    /// The overlay letter was removed.
+    UnsetOverlayChar(u32, u32),
+    /// The style of the cell was set.
+    CellStyle(u32, u32, Style),
+    /// The `Canvas` was cleared.
+    Clear,
+    /// The overlay was cleared.
+    ClearOverlay,
+}
+
+impl Edit {
+    /// Makes the change on the `Canvas`.
+    pub fn apply(&self, canvas: &mut Canvas) {
+        match *self {
+            Edit::Set(x, y) => canvas.set(x, y),
+            Edit::Unset(x, y) => canvas.unset(x, y),
+            Edit::Toggle(x, y) => canvas.toggle(x, y),
+            Edit::Vline(x, y1, y2) => canvas.vline(x, y1, y2),
+            Edit::Char(x, y, c, style) => canvas.set_styled_char(x, y, c, style),
+            Edit::OverlayChar(x, y, c, style) => canvas.set_overlay_styled_char(x, y, c, style),
+            Edit::UnsetOverlayChar(x, y) => canvas.unset_overlay_char(x, y),
+            Edit::CellStyle(x, y, style) => canvas.set_cell_style(x, y, style),
+            Edit::Clear => canvas.clear(),
+            Edit::ClearOverlay => canvas.clear_overlay(),
+        }
+    }
+}
+
+/// An error produced while importing a `Journal`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JournalError {
+    /// The first line isn’t a journal header.
+    InvalidHeader,
+    /// The line with the given number, counting the header as 1, couldn’t be read.
+    InvalidLine(usize),
+}
+
+impl fmt::Display for JournalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JournalError::InvalidHeader => write!(f, "not a drawing journal"),
+            JournalError::InvalidLine(line) => write!(f, "invalid journal entry on line {}", line),
+        }
+    }
+}
+
+impl Error for JournalError {}
+
+/// A record of every change made to a `Canvas`, and when, for replaying its drawing later.
+///
+/// Recording starts with `Canvas::start_journal`. A journal can be replayed onto a fresh
+/// `Canvas`, turned into an `Animation` at any speed, and exported as text to be imported
+/// again elsewhere, which helps in tracking down rendering bugs.
+///
+/// ```
+/// use drawille::{Canvas, Journal};
+///
+/// let mut canvas = Canvas::new(10, 8);
+/// canvas.start_journal();
+/// canvas.line(0, 0, 9, 7);
+/// canvas.set_char(0, 4, 'x');
+/// let journal = canvas.take_journal().unwrap();
+/// assert_eq!(journal.replay().frame(), canvas.frame());
+///
+/// let imported = Journal::import(&journal.export()).unwrap();
+/// assert_eq!(imported.edits().count(), journal.len());
+/// assert_eq!(imported.replay().frame(), canvas.frame());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Journal {
+    size: (u32, u32),
+    entries: Vec<(Duration, Edit)>,
+    start: Option<Instant>,
+}
+
+// When recording started only times new entries, so it isn’t compared.
+impl PartialEq for Journal {
+    fn eq(&self, other: &Journal) -> bool {
+        self.size == other.size && self.entries == other.entries
+    }
+}
+
+impl Eq for Journal {}
+
+impl Journal {
+    /// Creates a new, empty `Journal` for a `Canvas` of the given width and height in pixels.
+    pub fn new(width: u32, height: u32) -> Journal {
+        Journal { size: (width, height), entries: Vec::new(), start: None }
+    }
+
+    /// Adds a change made the given time after the journal started.
+    pub fn push(&mut self, time: Duration, edit: Edit) {
+        self.entries.push((time, edit));
+    }
+
+    /// Creates a new, empty `Journal` whose times count from now.
+    pub(crate) fn start(width: u32, height: u32) -> Journal {
+        Journal { start: Some(Instant::now()), ..Journal::new(width, height) }
+    }
+
+    /// Adds a change made now.
+    pub(crate) fn record(&mut self, edit: Edit) {
+        let now = Instant::now();
+        let start = *self.start.get_or_insert(now);
+        self.entries.push((now - start, edit));
+    }
+
+    /// Returns the width and height, in pixels, of the `Canvas` the journal was recorded on.
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    /// Returns every change, with the time after the journal started that it was made.
+    pub fn entries(&self) -> &[(Duration, Edit)] {
+        &self.entries
+    }
+
+    /// Returns an iterator over the changes, without their times.
+    pub fn edits<'a>(&'a self) -> impl Iterator<Item = Edit> + 'a {
+        self.entries.iter().map(|&(_, edit)| edit)
+    }
+
+    /// Returns the number of changes.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Detects whether no changes have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the time of the last change.
+    pub fn duration(&self) -> Duration {
+        self.entries.last().map_or(Duration::from_secs(0), |&(time, _)| time)
+    }
+
+    /// Replays every change onto a fresh `Canvas` of the recorded size.
+    pub fn replay(&self) -> Canvas {
+        self.replay_until(self.duration())
+    }
+
+    /// Replays the changes made up to and including the given time onto a fresh `Canvas`.
+    pub fn replay_until(&self, time: Duration) -> Canvas {
+        let mut canvas = Canvas::new(self.size.0, self.size.1);
+        for &(_, edit) in self.entries.iter().take_while(|&&(t, _)| t <= time) {
+            edit.apply(&mut canvas);
+        }
+        canvas
+    }
+
+    /// Turns the drawing into an `Animation` with frames of the given length, played `speed`
+    /// times as fast as it was drawn.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `speed` isn’t positive or `frame` is zero.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use drawille::{Edit, Journal};
+    ///
+    /// let mut journal = Journal::new(8, 4);
+    /// for x in 0..8 {
+    ///     journal.push(Duration::from_millis(x as u64 * 100), Edit::Set(x, 0));
+    /// }
+    /// let animation = journal.to_animation(2.0, Duration::from_millis(100));
+    /// assert_eq!(animation.len(), 5);
+    /// assert_eq!(animation.frames()[4].0, journal.replay().frame());
+    /// ```
+    pub fn to_animation(&self, speed: f64, frame: Duration) -> Animation {
+        assert!(speed > 0.0 && frame > Duration::from_secs(0),
+                "a replay needs a positive speed and frame length");
+        let mut animation = Animation::new();
+        let mut canvas = Canvas::new(self.size.0, self.size.1);
+        let mut entries = self.entries.iter().peekable();
+        let step = frame.mul_f64(speed);
+        let mut time = Duration::from_secs(0);
+        loop {
+            while let Some(&&(_, edit)) = entries.peek().filter(|&&&(t, _)| t <= time) {
+                edit.apply(&mut canvas);
+                entries.next();
+            }
+            animation.push(&canvas, frame);
+            if entries.peek().is_none() {
+                return animation;
+            }
+            time += step;
+        }
+    }
+
+    /// Exports the journal as text, one change per line.
+    pub fn export(&self) -> String {
+        let mut out = format!("drawille-journal {} {}\n", self.size.0, self.size.1);
+        for &(time, edit) in &self.entries {
+            let _ = write!(out, "{}.{:09} ", time.as_secs(), time.subsec_nanos());
+            let _ = match edit {
+                Edit::Set(x, y) => write!(out, "set {} {}", x, y),
+                Edit::Unset(x, y) => write!(out, "unset {} {}", x, y),
+                Edit::Toggle(x, y) => write!(out, "toggle {} {}", x, y),
+                Edit::Vline(x, y1, y2) => write!(out, "vline {} {} {}", x, y1, y2),
+                Edit::Char(x, y, c, style) => {
+                    write!(out, "char {} {} {:x} {}", x, y, c as u32, export_style(style))
+                }
+                Edit::OverlayChar(x, y, c, style) => {
+                    write!(out, "overlay {} {} {:x} {}", x, y, c as u32, export_style(style))
+                }
+                Edit::UnsetOverlayChar(x, y) => write!(out, "unset-overlay {} {}", x, y),
+                Edit::CellStyle(x, y, style) => {
+                    write!(out, "style {} {} {}", x, y, export_style(style))
+                }
+                Edit::Clear => write!(out, "clear"),
+                Edit::ClearOverlay => write!(out, "clear-overlay"),
+            };
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Imports a journal exported with `export`.
+    pub fn import(text: &str) -> Result<Journal, JournalError> {
+        let mut lines = text.lines();
+        let header: Vec<_> = lines.next().unwrap_or("").split(' ').collect();
+        let mut journal = match header[..] {
+            ["drawille-journal", w, h] => match (w.parse(), h.parse()) {
+                (Ok(w), Ok(h)) => Journal::new(w, h),
+                _ => return Err(JournalError::InvalidHeader),
+            },
+            _ => return Err(JournalError::InvalidHeader),
+        };
+        for (n, line) in lines.enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let entry = import_entry(line).ok_or(JournalError::InvalidLine(n + 2))?;
+            journal.entries.push(entry);
+        }
+        Ok(journal)
+    }
+}
+
+/// Parses a line of an exported journal.
+fn import_entry(line: &str) -> Option<(Duration, Edit)> {
+    let fields: Vec<_> = line.split(' ').collect();
+    let time = {
+        let (secs, nanos) = fields.first()?.split_once('.')?;
+        Duration::new(secs.parse().ok()?, nanos.parse().ok().filter(|&n| n < 1_000_000_000)?)
+    };
+    let n = |i: usize| fields.get(i).and_then(|f| f.parse::<u32>().ok());
+    let c = |i: usize| fields.get(i)
+        .and_then(|f| u32::from_str_radix(f, 16).ok())
+        .and_then(char::from_u32);
+    let style = |i: usize| fields.get(i).and_then(|f| import_style(f));
+    let edit = match (*fields.get(1)?, fields.len()) {
+        ("set", 4) => Edit::Set(n(2)?, n(3)?),
+        ("unset", 4) => Edit::Unset(n(2)?, n(3)?),
+        ("toggle", 4) => Edit::Toggle(n(2)?, n(3)?),
+        ("vline", 5) => Edit::Vline(n(2)?, n(3)?, n(4)?),
+        ("char", 6) => Edit::Char(n(2)?, n(3)?, c(4)?, style(5)?),
+        ("overlay", 6) => Edit::OverlayChar(n(2)?, n(3)?, c(4)?, style(5)?),
+        ("unset-overlay", 4) => Edit::UnsetOverlayChar(n(2)?, n(3)?),
+        ("style", 5) => Edit::CellStyle(n(2)?, n(3)?, style(4)?),
+        ("clear", 2) => Edit::Clear,
+        ("clear-overlay", 2) => Edit::ClearOverlay,
+        _ => return None,
+    };
+    Some((time, edit))
+}
+
+/// Writes a style as the parameters of its SGR escape sequence, or `-` if it is plain.
+fn export_style(style: Style) -> String {
+    if style.is_plain() {
+        return "-".to_string();
+    }
+    let mut sgr = String::new();
+    style.write_sgr(&mut sgr);
+    sgr.trim_start_matches("\x1b[").trim_end_matches('m').to_string()
+}
+
+/// Reads a style written by `export_style`.
+fn import_style(sgr: &str) -> Option<Style> {
+    let mut style = Style::new();
+    if sgr == "-" {
+        return Some(style);
+    }
+    let mut params = sgr.split(';').map(|p| p.parse::<u8>().ok());
+    while let Some(param) = params.next() {
+        let mut color = || match params.next()?? {
+            5 => Some(Color::Ansi256(params.next()??)),
+            2 => Some(Color::Rgb(params.next()??, params.next()??, params.next()??)),
+            _ => None,
+        };
+        match param? {
+            1 => style.bold = true,
+            4 => style.underline = true,
+            p @ 30..=37 => style.fg = Some(basic(p - 30)),
+            38 => style.fg = Some(color()?),
+            p @ 40..=47 => style.bg = Some(basic(p - 40)),
+            48 => style.bg = Some(color()?),
+            _ => return None,
+        }
+    }
+    Some(style)
+}
+
+fn basic(n: u8) -> Color {
+    [Color::Black, Color::Red, Color::Green, Color::Yellow, Color::Blue, Color::Magenta,
+     Color::Cyan, Color::White][n as usize]
+}
+
+/// The journal a `Canvas` may be recording, which has no bearing on whether two canvases
+/// are equal.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Recorder(pub(crate) Option<Box<Journal>>);
+
+impl Recorder {
+    pub(crate) fn record(&mut self, edit: Edit) {
+        if let Some(ref mut journal) = self.0 {
+            journal.record(edit);
+        }
+    }
+}
+
+impl PartialEq for Recorder {
+    fn eq(&self, _: &Recorder) -> bool {
+        true
+    }
+}
+
+impl Eq for Recorder {}
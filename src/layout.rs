@@ -0,0 +1,82 @@
+use std::iter;
+
+use canvas::Canvas;
+
+/// Joins canvases side by side into a single frame, with `separator` between each of them.
+///
+/// Shorter canvases are padded with blank rows at the bottom. Padding is computed from the
+/// canvases’ cells rather than the rendered strings, so colours don’t throw the layout off.
+///
+/// ```
+/// use drawille::{hconcat, Canvas};
+///
+/// let mut a = Canvas::new(0, 0);
+/// a.set_char(0, 0, 'a');
+/// let mut b = Canvas::new(0, 4);
+/// b.set_char(0, 4, 'b');
+/// assert_eq!(hconcat(&[&a, &b], "|"), "a| \n |b");
+/// ```
+pub fn hconcat(canvases: &[&Canvas], separator: &str) -> String {
+    grid(canvases, canvases.len(), separator, None)
+}
+
+/// Stacks canvases on top of each other into a single frame, optionally separated by a line made
+/// of the given character.
+///
+/// Narrower canvases are padded with blanks on the right.
+pub fn vconcat(canvases: &[&Canvas], separator: Option<char>) -> String {
+    grid(canvases, 1, "", separator)
+}
+
+/// Lays canvases out in a grid with the given number of columns, filling it row by row, and
+/// joins them into a single frame.
+///
+/// Every column is as wide as its widest canvas, and every row as tall as its tallest one.
+/// `column_separator` is placed between the columns, and rows are separated by a line made of
+/// `row_separator`, if given.
+pub fn grid(canvases: &[&Canvas], columns: usize, column_separator: &str,
+            row_separator: Option<char>) -> String {
+    if canvases.is_empty() || columns == 0 {
+        return String::new();
+    }
+    let sizes: Vec<(usize, usize)> = canvases.iter().map(|c| {
+        let (w, h) = c.extents();
+        (w as usize, h as usize)
+    }).collect();
+    let mut widths = vec![0; columns];
+    for (i, &(w, _)) in sizes.iter().enumerate() {
+        widths[i % columns] = widths[i % columns].max(w);
+    }
+    let columns = columns.min(canvases.len());
+    let total_width = widths[..columns].iter().sum::<usize>()
+        + column_separator.chars().count() * (columns - 1);
+
+    let mut lines = Vec::new();
+    for (n, (cells, sizes)) in canvases.chunks(columns).zip(sizes.chunks(columns)).enumerate() {
+        if n > 0 {
+            if let Some(sep) = row_separator {
+                lines.push(iter::repeat(sep).take(total_width).collect());
+            }
+        }
+        let rendered: Vec<Vec<String>> = cells.iter().map(|c| c.rows()).collect();
+        let height = sizes.iter().map(|&(_, h)| h).max().unwrap_or(0);
+        for y in 0..height {
+            let mut line = String::new();
+            for (i, rows) in rendered.iter().enumerate() {
+                if i > 0 {
+                    line.push_str(column_separator);
+                }
+                let pad = match rows.get(y) {
+                    Some(row) => {
+                        line.push_str(row);
+                        widths[i] - sizes[i].0
+                    }
+                    None => widths[i],
+                };
+                line.extend(iter::repeat(' ').take(pad));
+            }
+            lines.push(line);
+        }
+    }
+    lines.join("\n")
+}
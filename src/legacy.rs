@@ -0,0 +1,135 @@
+use std::fmt::Write;
+
+use canvas::Canvas;
+use style::{Color, Style};
+
+/// The colours of mIRC’s 16-colour palette, by their code.
+static MIRC: [(u8, u8, u8); 16] = [
+    (255, 255, 255), (0, 0, 0), (0, 0, 127), (0, 147, 0),
+    (255, 0, 0), (127, 0, 0), (156, 0, 156), (252, 127, 0),
+    (255, 255, 0), (0, 252, 0), (0, 147, 147), (0, 255, 255),
+    (0, 0, 252), (255, 0, 255), (127, 127, 127), (210, 210, 210),
+];
+
+impl Canvas {
+    /// Renders the `Canvas` as ANSI art, as read by BBS-era viewers and `.ans` editors.
+    ///
+    /// Only the original sixteen colours are used, with bright foregrounds made by bold and
+    /// backgrounds limited to the eight dark colours. Every change of style starts with a
+    /// reset, lines end in CRLF, and the text ends with an end-of-file marker as the format
+    /// expects. The Braille itself is UTF-8, which modern viewers accept.
+    ///
+    /// ```
+    /// use drawille::{Canvas, Color, Span, Style};
+    ///
+    /// let mut canvas = Canvas::new(4, 4);
+    /// canvas.text_styled(0, 0, 4, &[Span::new("hi", Style::new().fg(Color::Rgb(255, 0, 0)))]);
+    /// assert!(canvas.to_ansi_art().starts_with("\x1b[0;1;31mhi\x1b[0m \r\n"));
+    /// ```
+    pub fn to_ansi_art(&self) -> String {
+        let mut out = String::new();
+        self.write_legacy(&mut out, "\r\n", |style, out| {
+            out.push_str("\x1b[0");
+            let fg = style.fg.map(|c| nearest(&c, &ansi_palette()));
+            if style.bold || fg.is_some_and(|i| i >= 8) {
+                out.push_str(";1");
+            }
+            if style.underline {
+                out.push_str(";4");
+            }
+            if let Some(i) = fg {
+                let _ = write!(out, ";{}", 30 + i % 8);
+            }
+            if let Some(ref bg) = style.bg {
+                let _ = write!(out, ";{}", 40 + nearest(bg, &ansi_palette()) % 8);
+            }
+            out.push('m');
+        }, "\x1b[0m");
+        out.push_str("\x1b[0m\x1a");
+        out
+    }
+
+    /// Renders the `Canvas` with IRC formatting codes, one message per line, for pasting into
+    /// chat clients.
+    ///
+    /// Colours are matched to the nearest of mIRC’s sixteen.
+    ///
+    /// ```
+    /// use drawille::{Canvas, Color, Span, Style};
+    ///
+    /// let mut canvas = Canvas::new(4, 4);
+    /// canvas.text_styled(0, 0, 4, &[Span::new("hi", Style::new().fg(Color::Red).bold())]);
+    /// assert!(canvas.to_irc().starts_with("\x02\x0304hi\x0f \n"));
+    /// ```
+    pub fn to_irc(&self) -> String {
+        let mut out = String::new();
+        self.write_legacy(&mut out, "\n", |style, out| {
+            if style.bold {
+                out.push('\x02');
+            }
+            if style.underline {
+                out.push('\x1f');
+            }
+            match (style.fg, style.bg) {
+                (fg, Some(ref bg)) => {
+                    // A background needs a foreground before it, so default to black.
+                    let fg = fg.map(|c| nearest(&c, &MIRC)).unwrap_or(1);
+                    let _ = write!(out, "\x03{:02},{:02}", fg, nearest(bg, &MIRC));
+                }
+                (Some(ref fg), None) => {
+                    let _ = write!(out, "\x03{:02}", nearest(fg, &MIRC));
+                }
+                (None, None) => {}
+            }
+        }, "\x0f");
+        out
+    }
+
+    /// Renders every row, starting styled runs with `start` and ending them with `reset`.
+    fn write_legacy<F>(&self, out: &mut String, newline: &str, start: F, reset: &str)
+        where F: Fn(&Style, &mut String)
+    {
+        let (columns, rows) = self.extents();
+        for y in 0..rows {
+            if y > 0 {
+                out.push_str(newline);
+            }
+            let mut current = Style::default();
+            for x in 0..columns {
+                let (c, style) = self.cell(x as u16, y as u16);
+                if style != current {
+                    if !current.is_plain() {
+                        out.push_str(reset);
+                    }
+                    if !style.is_plain() {
+                        start(&style, out);
+                    }
+                    current = style;
+                }
+                out.push(c);
+            }
+            if !current.is_plain() {
+                out.push_str(reset);
+            }
+        }
+    }
+}
+
+/// Returns the sixteen colours of the ANSI palette.
+fn ansi_palette() -> [(u8, u8, u8); 16] {
+    let mut palette = [(0, 0, 0); 16];
+    for (i, rgb) in palette.iter_mut().enumerate() {
+        *rgb = Color::Ansi256(i as u8).to_rgb();
+    }
+    palette
+}
+
+/// Returns the index of the colour in `palette` closest to `color`.
+fn nearest(color: &Color, palette: &[(u8, u8, u8)]) -> usize {
+    let (r, g, b) = color.to_rgb();
+    let distance = |&(pr, pg, pb): &(u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, pr) + d(g, pg) + d(b, pb)
+    };
+    (0..palette.len()).min_by_key(|&i| distance(&palette[i])).unwrap_or(0)
+}
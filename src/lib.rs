@@ -22,12 +22,160 @@
 //! }
 //! ```
 
+#[cfg(feature = "ttf")]
+extern crate ab_glyph;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "input")]
+extern crate crossterm;
 extern crate fnv;
+#[cfg(feature = "futures")]
+extern crate futures_core;
+#[cfg(feature = "futures")]
+extern crate futures_io;
+#[cfg(feature = "futures")]
+extern crate futures_sink;
+#[cfg(feature = "image")]
+extern crate image;
+#[cfg(feature = "rand_core")]
+extern crate rand_core;
 
+mod animation;
+mod art;
+mod cache;
 mod canvas;
+pub mod chart;
+mod checked;
+mod choropleth;
+#[cfg(feature = "coastline")]
+mod coastline;
+mod console;
+mod contour;
+pub mod coords;
+mod cow;
+mod curves;
+mod damage;
+pub mod demo;
+mod dither;
+mod draw;
+pub mod ease;
+#[cfg(feature = "figlet")]
+mod figlet;
+#[cfg(feature = "ttf")]
+mod font;
+pub mod fractal;
+mod gauge;
+mod geo;
+mod graph;
+mod html;
+mod import;
+mod inline;
+#[cfg(feature = "input")]
+mod input;
+mod isometric;
+mod journal;
+mod layout;
+mod legacy;
+mod lsystem;
+#[cfg(feature = "rand_core")]
+mod maze;
+mod morphology;
+mod numerals;
+mod onion;
+mod output;
+mod particles;
+mod path;
+mod pie;
+mod plasma;
+mod probe;
+mod quiver;
+mod radar;
+#[cfg(feature = "raycast")]
+mod raycast;
+#[cfg(feature = "repl")]
+mod repl;
+#[cfg(feature = "rand_core")]
+mod random;
+mod scene;
+mod scope;
+mod script;
+mod shared;
+mod sixel;
+mod spectrogram;
+#[cfg(feature = "futures")]
+mod stream;
+mod stroke;
+mod style;
+mod surface;
+mod svg;
+pub mod testing;
+mod texture;
+mod thumbnail;
+mod timeline;
+mod timer;
+mod traversal;
+mod tree;
 mod turtle;
+mod world;
 
-pub use canvas::Canvas;
-pub use turtle::Turtle;
+pub use animation::Animation;
+#[doc(hidden)]
+pub use art::__art_pixels;
+pub use art::{Art, ArtError};
+pub use cache::FrameCache;
+pub use canvas::{Canvas, CanvasStats, OutOfBounds, TextDirection};
+pub use checked::DrawError;
+pub use choropleth::Choropleth;
+pub use console::enable_ansi;
+pub use dither::{Density, Dither};
+pub use draw::Draw;
+pub use contour::Contour;
+pub use coords::Size;
+#[cfg(feature = "figlet")]
+pub use figlet::{FigFont, FigletError};
+#[cfg(feature = "ttf")]
+pub use font::{FontError, FontMode, TtfFont};
+pub use gauge::Gauge;
+pub use geo::{great_circle, Projection, Viewport};
+pub use graph::Graph;
+pub use import::ImportMode;
+#[cfg(feature = "input")]
+pub use input::{Input, InputEvent, Key, MouseAction, MouseButton};
+pub use isometric::Isometric;
+pub use journal::{Edit, Journal, JournalError};
+pub use layout::{grid, hconcat, vconcat};
+pub use lsystem::LSystem;
+#[cfg(feature = "rand_core")]
+pub use maze::{Maze, Wall};
+pub use onion::OnionSkin;
+pub use output::OutputMode;
+pub use particles::{Particle, ParticleSystem};
+pub use path::Path;
+pub use pie::{Pie, Slice};
+pub use plasma::Plasma;
+pub use probe::{measure_width, probe, Capabilities, ColorDepth, PixelMode};
+pub use quiver::Quiver;
+pub use radar::Radar;
+#[cfg(feature = "raycast")]
+pub use raycast::Raycaster;
+#[cfg(feature = "repl")]
+pub use repl::repl;
+pub use scene::{Node, Shape, Transform};
+pub use scope::Scope;
+pub use script::{Command, Script, ScriptError};
+pub use shared::SharedCanvas;
+pub use spectrogram::Spectrogram;
+#[cfg(feature = "futures")]
+pub use stream::{FrameSink, FrameStream};
+pub use stroke::LineStyle;
+pub use style::{Color, Span, Style, StyledCell};
+pub use surface::{Shading, Surface};
+pub use svg::SvgError;
+pub use texture::{Noise, NoiseTexture};
+pub use timeline::Timeline;
+pub use timer::FrameTimer;
+pub use traversal::Traversal;
+pub use tree::Tree;
+pub use turtle::{Origin, Turtle};
 
 
@@ -0,0 +1,90 @@
+use turtle::Turtle;
+
+/// A Lindenmayer system: a string of symbols rewritten by rules again and again, and then
+/// drawn by a `Turtle`, which makes many fractal curves and plants a few lines to describe.
+///
+/// When drawn, `F` and `G` move forward drawing a line, `f` moves forward without drawing, `+`
+/// and `-` turn left and right by the angle, and `[` and `]` save and restore the turtle’s
+/// position and heading. Every other symbol is only there to be rewritten.
+///
+/// The string grows exponentially with the depth, so a few more levels can take far longer.
+///
+/// ```
+/// use drawille::{LSystem, Turtle};
+///
+/// let koch = LSystem::new("F", 60.0).rule('F', "F+F--F+F");
+/// assert_eq!(koch.expand(1), "F+F--F+F");
+/// let mut turtle = Turtle::new(0.0, 0.0).record(true);
+/// koch.draw(&mut turtle, 3, 2.0);
+/// assert_eq!(turtle.segments().len(), 64);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LSystem {
+    axiom: String,
+    rules: Vec<(char, String)>,
+    angle: f32,
+}
+
+impl LSystem {
+    /// Creates a new `LSystem` that starts from `axiom` and turns by `angle` degrees, with no
+    /// rules yet.
+    pub fn new<S: Into<String>>(axiom: S, angle: f32) -> LSystem {
+        LSystem { axiom: axiom.into(), rules: Vec::new(), angle }
+    }
+
+    /// Adds a rule that rewrites a symbol into others, replacing any earlier rule for it, and
+    /// return it for use again.
+    pub fn rule<S: Into<String>>(mut self, symbol: char, replacement: S) -> LSystem {
+        self.rules.retain(|rule| rule.0 != symbol);
+        self.rules.push((symbol, replacement.into()));
+        self
+    }
+
+    /// Returns the string after rewriting the axiom `depth` times.
+    pub fn expand(&self, depth: u32) -> String {
+        let mut current = self.axiom.clone();
+        for _ in 0..depth {
+            let mut next = String::with_capacity(current.len() * 2);
+            for c in current.chars() {
+                match self.rules.iter().find(|rule| rule.0 == c) {
+                    Some(rule) => next.push_str(&rule.1),
+                    None => next.push(c),
+                }
+            }
+            current = next;
+        }
+        current
+    }
+
+    /// Draws the string after rewriting the axiom `depth` times with a `Turtle`, taking `step`
+    /// pixels for each move forward.
+    ///
+    /// The turtle starts from where it is, heading where it faces, and its brush is left as
+    /// it was.
+    pub fn draw(&self, turtle: &mut Turtle, depth: u32, step: f32) {
+        let brush = turtle.brush;
+        let mut stack = Vec::new();
+        for c in self.expand(depth).chars() {
+            match c {
+                'F' | 'G' => turtle.forward(step),
+                'f' => {
+                    turtle.up();
+                    turtle.forward(step);
+                    turtle.brush = brush;
+                }
+                '+' => turtle.left(self.angle),
+                '-' => turtle.right(self.angle),
+                '[' => stack.push((turtle.x, turtle.y, turtle.rotation)),
+                ']' => {
+                    if let Some((x, y, rotation)) = stack.pop() {
+                        turtle.up();
+                        turtle.teleport(x, y);
+                        turtle.brush = brush;
+                        turtle.rotation = rotation;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
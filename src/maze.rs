@@ -0,0 +1,240 @@
+use std::char;
+use std::collections::VecDeque;
+
+use rand_core::RngCore;
+
+use canvas::{Canvas, PIXEL_MAP};
+use style::Style;
+
+/// One of the four walls of a cell of a `Maze`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Wall {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Wall {
+    fn bit(self) -> u8 {
+        match self {
+            Wall::North => 1,
+            Wall::East => 2,
+            Wall::South => 4,
+            Wall::West => 8,
+        }
+    }
+
+    fn opposite(self) -> Wall {
+        match self {
+            Wall::North => Wall::South,
+            Wall::East => Wall::West,
+            Wall::South => Wall::North,
+            Wall::West => Wall::East,
+        }
+    }
+}
+
+static WALLS: [Wall; 4] = [Wall::North, Wall::East, Wall::South, Wall::West];
+
+/// A rectangular maze in which every cell can be reached from every other one in exactly one way.
+///
+/// ```
+/// extern crate drawille;
+/// extern crate rand_core;
+///
+/// use drawille::{Canvas, Maze, Style};
+/// # use rand_core::{impls, Error, RngCore};
+/// # struct Counter(u64);
+/// # impl RngCore for Counter {
+/// #     fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+/// #     fn next_u64(&mut self) -> u64 { self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1); self.0 >> 16 }
+/// #     fn fill_bytes(&mut self, dest: &mut [u8]) { impls::fill_bytes_via_next(self, dest) }
+/// #     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> { Ok(self.fill_bytes(dest)) }
+/// # }
+///
+/// fn main() {
+///     let maze = Maze::generate(8, 4, &mut Counter(1));
+///     let path = maze.solve((0, 0), (7, 3)).unwrap();
+///
+///     let mut canvas = Canvas::new(0, 0);
+///     maze.draw(&mut canvas, 0, 0, 4);
+///     let walls = canvas.frame();
+///     maze.draw_path(&mut canvas, 0, 0, 4, &path, Style::default());
+///     assert_eq!(path.len(), 25);
+///     assert_eq!(canvas.frame(), [
+/// "⡏⡍⡏⠉⠉⡭⠭⠭⠭⠭⠭⡍⡏⠉⠉⠉⡇",
+/// "⡇⡇⡏⡭⠥⠇⡏⠉⡏⡭⠥⠇⡏⡭⠥⡄⡇",
+/// "⡇⠧⠥⠇⡏⡭⠥⠤⠥⠇⡏⡭⠥⠇⡇⡇⡇",
+/// "⡏⠉⠉⠉⠁⠧⠭⠭⠭⠭⠥⠇⡏⠉⠁⠇⡇",
+/// "⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠁"].join("\n"));
+///     // The solution is only on the overlay, so the maze can be shown without it again.
+///     canvas.clear_overlay();
+///     assert_eq!(canvas.frame(), walls);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Maze {
+    width: usize,
+    height: usize,
+    /// The walls that are still standing around each cell, in row-major order.
+    walls: Vec<u8>,
+}
+
+impl Maze {
+    /// Generates a new maze of the given size in cells, using the recursive backtracker
+    /// algorithm.
+    ///
+    /// The same `rng` state always produces the same maze.
+    pub fn generate<R: RngCore>(width: usize, height: usize, rng: &mut R) -> Maze {
+        let mut maze = Maze { width, height, walls: vec![0xf; width * height] };
+        if width == 0 || height == 0 {
+            return maze;
+        }
+        let mut visited = vec![false; width * height];
+        let mut stack = vec![(0, 0)];
+        visited[0] = true;
+        while let Some(&(x, y)) = stack.last() {
+            let unvisited: Vec<_> = WALLS.iter()
+                .filter_map(|&wall| maze.neighbour(x, y, wall).map(|n| (wall, n)))
+                .filter(|&(_, (nx, ny))| !visited[ny * width + nx])
+                .collect();
+            if unvisited.is_empty() {
+                stack.pop();
+                continue;
+            }
+            let (wall, (nx, ny)) = unvisited[rng.next_u32() as usize % unvisited.len()];
+            maze.walls[y * width + x] &= !wall.bit();
+            maze.walls[ny * width + nx] &= !wall.opposite().bit();
+            visited[ny * width + nx] = true;
+            stack.push((nx, ny));
+        }
+        maze
+    }
+
+    /// Returns the width of the maze, in cells.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the maze, in cells.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Detects whether the given wall of a cell is standing, which it never is for a cell
+    /// outside the maze.
+    pub fn has_wall(&self, x: usize, y: usize, wall: Wall) -> bool {
+        x < self.width && y < self.height && self.walls[y * self.width + x] & wall.bit() != 0
+    }
+
+    /// Finds the path between two cells, from `from` to `to` inclusive, or `None` if either is
+    /// outside the maze.
+    pub fn solve(&self, from: (usize, usize), to: (usize, usize)) -> Option<Vec<(usize, usize)>> {
+        if from.0 >= self.width || from.1 >= self.height || to.0 >= self.width
+            || to.1 >= self.height {
+            return None;
+        }
+        let mut previous = vec![None; self.width * self.height];
+        let mut queue = VecDeque::new();
+        queue.push_back(from);
+        previous[from.1 * self.width + from.0] = Some(from);
+        while let Some((x, y)) = queue.pop_front() {
+            if (x, y) == to {
+                break;
+            }
+            for &wall in &WALLS {
+                if self.has_wall(x, y, wall) {
+                    continue;
+                }
+                if let Some((nx, ny)) = self.neighbour(x, y, wall) {
+                    if previous[ny * self.width + nx].is_none() {
+                        previous[ny * self.width + nx] = Some((x, y));
+                        queue.push_back((nx, ny));
+                    }
+                }
+            }
+        }
+
+        let mut path = vec![to];
+        let mut cell = to;
+        while cell != from {
+            cell = previous[cell.1 * self.width + cell.0]?;
+            path.push(cell);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Draws the walls of the maze onto the `Canvas`, with its top-left corner at the given
+    /// coordinates and each cell `cell_size` pixels wide and high.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, cell_size: u32) {
+        for cy in 0..self.height {
+            for cx in 0..self.width {
+                let (left, top) = (x + cx as u32 * cell_size, y + cy as u32 * cell_size);
+                let (right, bottom) = (left + cell_size, top + cell_size);
+                if self.has_wall(cx, cy, Wall::North) {
+                    canvas.line(left, top, right, top);
+                }
+                if self.has_wall(cx, cy, Wall::West) {
+                    canvas.line(left, top, left, bottom);
+                }
+                if cx + 1 == self.width && self.has_wall(cx, cy, Wall::East) {
+                    canvas.line(right, top, right, bottom);
+                }
+                if cy + 1 == self.height && self.has_wall(cx, cy, Wall::South) {
+                    canvas.line(left, bottom, right, bottom);
+                }
+            }
+        }
+    }
+
+    /// Draws a path through the maze, such as one found by `solve`, as a line through the
+    /// centres of its cells. The position and cell size should match those given to `draw`.
+    ///
+    /// The path goes on the text overlay of the `Canvas`, in the given style, with each cell
+    /// it passes through showing the walls beneath it as well. The maze itself is left
+    /// untouched, so `Canvas::clear_overlay` hides the solution again.
+    pub fn draw_path(&self, canvas: &mut Canvas, x: u32, y: u32, cell_size: u32,
+                     path: &[(usize, usize)], style: Style) {
+        let centre = |&(cx, cy): &(usize, usize)| {
+            (x + cx as u32 * cell_size + cell_size / 2, y + cy as u32 * cell_size + cell_size / 2)
+        };
+        let mut trail = Canvas::new(0, 0);
+        for pair in path.windows(2) {
+            let ((x1, y1), (x2, y2)) = (centre(&pair[0]), centre(&pair[1]));
+            trail.line(x1, y1, x2, y2);
+        }
+        if path.len() == 1 {
+            let (cx, cy) = centre(&path[0]);
+            trail.set(cx, cy);
+        }
+        let mut cells: Vec<(u32, u32)> = trail.pixels().map(|(px, py)| (px / 2, py / 4)).collect();
+        cells.sort_unstable();
+        cells.dedup();
+        for (col, row) in cells {
+            let (left, top) = (col * 2, row * 4);
+            let mut bits = 0;
+            for (dy, row_bits) in PIXEL_MAP.iter().enumerate() {
+                for (dx, &bit) in row_bits.iter().enumerate() {
+                    let (px, py) = (left + dx as u32, top + dy as u32);
+                    if trail.get(px, py) || canvas.get(px, py) {
+                        bits |= bit;
+                    }
+                }
+            }
+            let c = char::from_u32(0x2800 + bits as u32).unwrap();
+            canvas.set_overlay_styled_char(left, top, c, style);
+        }
+    }
+
+    fn neighbour(&self, x: usize, y: usize, wall: Wall) -> Option<(usize, usize)> {
+        match wall {
+            Wall::North if y > 0 => Some((x, y - 1)),
+            Wall::East if x + 1 < self.width => Some((x + 1, y)),
+            Wall::South if y + 1 < self.height => Some((x, y + 1)),
+            Wall::West if x > 0 => Some((x - 1, y)),
+            _ => None,
+        }
+    }
+}
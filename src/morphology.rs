@@ -0,0 +1,76 @@
+use fnv::FnvHashSet;
+
+use canvas::Canvas;
+
+/// The offsets of the eight neighbours in the 3×3 structuring element.
+static NEIGHBOURS: [(i64, i64); 8] = [(-1, -1), (0, -1), (1, -1),
+                                      (-1, 0),           (1, 0),
+                                      (-1, 1),  (0, 1),  (1, 1)];
+
+fn neighbours(x: u32, y: u32) -> impl Iterator<Item = Option<(u32, u32)>> {
+    NEIGHBOURS.iter().map(move |&(dx, dy)| {
+        let (nx, ny) = (x as i64 + dx, y as i64 + dy);
+        if nx < 0 || ny < 0 || nx > u32::MAX as i64 || ny > u32::MAX as i64 {
+            None
+        } else {
+            Some((nx as u32, ny as u32))
+        }
+    })
+}
+
+impl Canvas {
+    /// Erodes the pixels of the `Canvas` with a 3×3 structuring element.
+    ///
+    /// A pixel stays set only if all eight of its neighbours are set too, so thin lines and
+    /// isolated specks disappear.
+    pub fn erode(&mut self) {
+        let set: FnvHashSet<_> = self.pixels().collect();
+        for &(x, y) in &set {
+            if !is_interior(&set, x, y) {
+                self.unset(x, y);
+            }
+        }
+    }
+
+    /// Dilates the pixels of the `Canvas` with a 3×3 structuring element.
+    ///
+    /// Every neighbour of a set pixel becomes set, which thickens lines and closes small gaps.
+    pub fn dilate(&mut self) {
+        let set: Vec<_> = self.pixels().collect();
+        for (x, y) in set {
+            for (nx, ny) in neighbours(x, y).flatten() {
+                self.set(nx, ny);
+            }
+        }
+    }
+
+    /// Reduces filled shapes to their outlines.
+    ///
+    /// Only the pixels that would be removed by `erode` are kept, leaving a one-pixel border
+    /// around every shape.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(6, 4);
+    /// for x in 0..6 {
+    ///     for y in 0..4 {
+    ///         canvas.set(x, y);
+    ///     }
+    /// }
+    /// canvas.outline();
+    /// assert!(canvas.get(0, 0) && !canvas.get(2, 1) && canvas.get(5, 3));
+    /// ```
+    pub fn outline(&mut self) {
+        let set: FnvHashSet<_> = self.pixels().collect();
+        for &(x, y) in &set {
+            if is_interior(&set, x, y) {
+                self.unset(x, y);
+            }
+        }
+    }
+}
+
+fn is_interior(set: &FnvHashSet<(u32, u32)>, x: u32, y: u32) -> bool {
+    neighbours(x, y).all(|n| n.is_some_and(|n| set.contains(&n)))
+}
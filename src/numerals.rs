@@ -0,0 +1,81 @@
+use std::fmt;
+
+use canvas::Canvas;
+
+/// The height of every numeral, in pixels.
+const HEIGHT: u32 = 5;
+
+/// Returns the width of a character of the numeral font and its rows, top first, each with its
+/// leftmost pixel in the highest of its `width` bits, or `None` if the font has no such
+/// character.
+fn glyph(c: char) -> Option<(u32, [u8; 5])> {
+    Some(match c {
+        '0' => (3, [0b111, 0b101, 0b101, 0b101, 0b111]),
+        '1' => (3, [0b010, 0b110, 0b010, 0b010, 0b111]),
+        '2' => (3, [0b111, 0b001, 0b111, 0b100, 0b111]),
+        '3' => (3, [0b111, 0b001, 0b111, 0b001, 0b111]),
+        '4' => (3, [0b101, 0b101, 0b111, 0b001, 0b001]),
+        '5' => (3, [0b111, 0b100, 0b111, 0b001, 0b111]),
+        '6' => (3, [0b111, 0b100, 0b111, 0b101, 0b111]),
+        '7' => (3, [0b111, 0b001, 0b001, 0b010, 0b010]),
+        '8' => (3, [0b111, 0b101, 0b111, 0b101, 0b111]),
+        '9' => (3, [0b111, 0b101, 0b111, 0b001, 0b111]),
+        '-' => (3, [0b000, 0b000, 0b111, 0b000, 0b000]),
+        '+' => (3, [0b000, 0b010, 0b111, 0b010, 0b000]),
+        'e' | 'E' => (3, [0b000, 0b111, 0b111, 0b100, 0b111]),
+        '%' => (3, [0b101, 0b001, 0b010, 0b100, 0b101]),
+        '.' => (1, [0b0, 0b0, 0b0, 0b0, 0b1]),
+        ',' => (1, [0b0, 0b0, 0b0, 0b1, 0b1]),
+        ':' => (1, [0b0, 0b1, 0b0, 0b1, 0b0]),
+        ' ' => (2, [0b00; 5]),
+        _ => return None,
+    })
+}
+
+impl Canvas {
+    /// Draws a number in a tiny font of numerals 3×5 pixels, with the top-left corner of its
+    /// first character at the given pixel, and returns how many pixels wide it is.
+    ///
+    /// Unlike the characters of `text`, numbers drawn this way can sit anywhere, not just on
+    /// the grid of cells, such as next to a peak in a plot. Anything that formats as digits,
+    /// signs, points, commas, colons, spaces, `e` and `%` can be drawn; other characters are
+    /// left out. Characters are a pixel apart.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(0, 0);
+    /// assert_eq!(canvas.draw_number(1, 1, -2.5), 13);
+    /// assert_eq!(canvas.frame(), ["⢀⣀⢐⣲ ⢰⣒",
+    ///                             "  ⠘⠒⠐⠐⠚"].join("\n"));
+    /// ```
+    pub fn draw_number<T: fmt::Display>(&mut self, x: u32, y: u32, value: T) -> u32 {
+        let mut left = x;
+        for c in value.to_string().chars() {
+            let (width, rows) = match glyph(c) {
+                Some(glyph) => glyph,
+                None => continue,
+            };
+            if left > x {
+                left += 1;
+            }
+            for (dy, &row) in rows.iter().enumerate() {
+                for dx in 0..width {
+                    if row >> (width - 1 - dx) & 1 != 0 {
+                        self.set(left + dx, y + dy as u32);
+                    }
+                }
+            }
+            left += width;
+        }
+        left - x
+    }
+
+    /// Returns how many pixels wide `draw_number` would draw a number, and how high, such as
+    /// to right-align or centre it.
+    pub fn number_size<T: fmt::Display>(value: T) -> (u32, u32) {
+        let widths: Vec<u32> = value.to_string().chars().filter_map(glyph).map(|g| g.0).collect();
+        let gaps = widths.len().saturating_sub(1) as u32;
+        (widths.iter().sum::<u32>() + gaps, if widths.is_empty() { 0 } else { HEIGHT })
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::VecDeque;
+
+use canvas::Canvas;
+use dither::Density;
+
+/// Onion skinning for hand-tuning animations: the last few frames shown faintly under the
+/// current one, each older frame at a lower density of dots.
+///
+/// With `layers` frames remembered, the one before the current frame is drawn at a level of
+/// `layers / (layers + 1)`, the one before that a step of `1 / (layers + 1)` lower, and so on.
+/// Only the pixels of earlier frames are kept; their text and styles are left out.
+///
+/// ```
+/// use drawille::{Canvas, OnionSkin};
+///
+/// let mut onion = OnionSkin::new(3);
+/// let mut canvas = Canvas::new(0, 0);
+/// for frame in 0..4 {
+///     canvas.clear();
+///     canvas.fill_level(frame * 8, 0, 8, 8, 1.0, &Default::default());
+///     onion.push(&canvas);
+/// }
+/// // The current square in full, then the three before it at 3/4, 1/2 and 1/4.
+/// assert_eq!(onion.render(&canvas).pixels().count(), 64 + 48 + 32 + 16);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct OnionSkin {
+    layers: usize,
+    density: Density,
+    /// The pixels of the frames pushed so far, newest first, including the current one.
+    frames: VecDeque<Vec<(u32, u32)>>,
+}
+
+impl OnionSkin {
+    /// Creates a new `OnionSkin` that shows the given number of earlier frames, with an
+    /// ordered dither.
+    pub fn new(layers: usize) -> OnionSkin {
+        OnionSkin { layers, density: Density::default(), frames: VecDeque::new() }
+    }
+
+    /// Sets how the earlier frames are thinned out into dots, and return it for use again.
+    ///
+    /// An ordered dither, the default, sets a subset of an older frame’s dots wherever the
+    /// frames overlap, which keeps them easy to tell apart.
+    pub fn density(mut self, density: Density) -> OnionSkin {
+        self.density = density;
+        self
+    }
+
+    /// Records a frame of the animation, forgetting any that are too old to be shown.
+    pub fn push(&mut self, canvas: &Canvas) {
+        self.frames.push_front(canvas.pixels().collect());
+        self.frames.truncate(self.layers + 1);
+    }
+
+    /// Returns how many frames are remembered, at most one more than the number of layers.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Detects whether no frames have been pushed since the `OnionSkin` was created or cleared.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Forgets every frame, such as when moving on to another animation.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    /// Returns a copy of the current frame with the earlier frames drawn under it.
+    ///
+    /// The `Canvas` given is taken to be the last frame pushed, so that frame isn’t drawn
+    /// again as a skin of itself. It may have changed since it was pushed, such as a frame that
+    /// is still being drawn.
+    pub fn render(&self, current: &Canvas) -> Canvas {
+        let mut canvas = current.clone();
+        let step = 1.0 / (self.layers + 1) as f64;
+        for (age, frame) in self.frames.iter().enumerate().skip(1) {
+            let level = 1.0 - age as f64 * step;
+            for &(x, y) in frame {
+                if self.density.is_set(x, y, level) {
+                    canvas.set(x, y);
+                }
+            }
+        }
+        canvas
+    }
+}
@@ -0,0 +1,83 @@
+use std::env;
+
+use canvas::Canvas;
+
+/// A way of showing a `Canvas` on a terminal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OutputMode {
+    /// Braille text, as rendered by `Canvas::frame`, which works nearly everywhere.
+    Braille,
+    /// Sixel graphics, with each pixel drawn as a square of the given size.
+    Sixel(u32),
+    /// An image in the Kitty graphics protocol, with each pixel drawn as a square of the given
+    /// size.
+    Kitty(u32),
+    /// A PNG image in iTerm2’s inline image protocol, with each pixel drawn as a square of the
+    /// given size.
+    ///
+    /// Without the `image` feature, which encodes the PNG, this falls back to Braille.
+    Iterm2(u32),
+}
+
+impl OutputMode {
+    /// Picks the prettiest mode that the current terminal is known to support, from environment
+    /// variables, with images drawing each pixel as a square of the given size.
+    ///
+    /// Kitty, WezTerm and Ghostty get the Kitty protocol and iTerm2 gets its own, when the
+    /// `image` feature is enabled. Everything else, including terminals that might support
+    /// Sixel but can’t be told apart from ones that don’t, gets Braille.
+    pub fn detect(scale: u32) -> OutputMode {
+        OutputMode::from_env(scale, |name| env::var(name).ok())
+    }
+
+    /// Picks a mode like `detect`, from the given lookup of environment variables.
+    ///
+    /// ```
+    /// use drawille::OutputMode;
+    ///
+    /// let kitty = OutputMode::from_env(2, |name| match name {
+    ///     "TERM" => Some("xterm-kitty".to_string()),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(kitty, OutputMode::Kitty(2));
+    /// assert_eq!(OutputMode::from_env(2, |_| None), OutputMode::Braille);
+    /// ```
+    pub fn from_env<F: Fn(&str) -> Option<String>>(scale: u32, var: F) -> OutputMode {
+        let term = var("TERM").unwrap_or_default();
+        let program = var("TERM_PROGRAM").unwrap_or_default();
+        if var("KITTY_WINDOW_ID").is_some() || term.contains("kitty") || term.contains("ghostty")
+            || program == "WezTerm" || program == "ghostty" {
+            return OutputMode::Kitty(scale);
+        }
+        #[cfg(feature = "image")]
+        {
+            if program == "iTerm.app" {
+                return OutputMode::Iterm2(scale);
+            }
+        }
+        OutputMode::Braille
+    }
+}
+
+impl Canvas {
+    /// Renders the `Canvas` in the given mode, ready to be written to the terminal.
+    ///
+    /// ```
+    /// use drawille::{Canvas, OutputMode};
+    ///
+    /// let mut canvas = Canvas::new(10, 10);
+    /// canvas.line(0, 0, 9, 9);
+    /// assert_eq!(canvas.output(OutputMode::Braille), canvas.frame());
+    /// ```
+    pub fn output(&self, mode: OutputMode) -> String {
+        match mode {
+            OutputMode::Braille => self.frame(),
+            OutputMode::Sixel(scale) => self.to_sixel(scale),
+            OutputMode::Kitty(scale) => self.to_kitty(scale),
+            #[cfg(feature = "image")]
+            OutputMode::Iterm2(scale) => self.to_iterm2(scale),
+            #[cfg(not(feature = "image"))]
+            OutputMode::Iterm2(_) => self.frame(),
+        }
+    }
+}
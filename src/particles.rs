@@ -0,0 +1,146 @@
+use std::collections::VecDeque;
+
+use canvas::Canvas;
+
+/// A single particle of a `ParticleSystem`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Particle {
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    /// How long the particle has existed for.
+    pub age: f32,
+    /// How long the particle exists for before it is removed.
+    pub lifetime: f32,
+    trail: VecDeque<(f32, f32)>,
+}
+
+impl Particle {
+    /// Returns the previous positions of the particle, oldest first.
+    pub fn trail(&self) -> impl Iterator<Item = &(f32, f32)> {
+        self.trail.iter()
+    }
+}
+
+/// A set of particles that move with constant acceleration and disappear after their lifetime,
+/// drawn as dots with optional trails.
+///
+/// ```
+/// use drawille::{Canvas, ParticleSystem};
+///
+/// let mut particles = ParticleSystem::new().gravity(0.0, 10.0);
+/// particles.spawn(0.0, 0.0, 10.0, 0.0, 2.0);
+/// particles.step(1.0);
+/// assert_eq!(particles.len(), 1);
+///
+/// let mut canvas = Canvas::new(20, 20);
+/// particles.draw(&mut canvas);
+/// assert!(canvas.get(10, 10));
+///
+/// particles.step(1.0);
+/// assert!(particles.is_empty());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParticleSystem {
+    particles: Vec<Particle>,
+    gravity: (f32, f32),
+    trail_length: usize,
+}
+
+impl ParticleSystem {
+    /// Creates a new, empty `ParticleSystem` without gravity or trails.
+    pub fn new() -> ParticleSystem {
+        ParticleSystem::default()
+    }
+
+    /// Sets the acceleration applied to every particle, and return the system for use again.
+    pub fn gravity(mut self, ax: f32, ay: f32) -> ParticleSystem {
+        self.gravity = (ax, ay);
+        self
+    }
+
+    /// Makes every particle remember its last `length` positions, drawn as a trail behind it,
+    /// and return the system for use again.
+    pub fn trails(mut self, length: usize) -> ParticleSystem {
+        self.trail_length = length;
+        self
+    }
+
+    /// Adds a particle at the given position and velocity, which lives for `lifetime`.
+    pub fn spawn(&mut self, x: f32, y: f32, vx: f32, vy: f32, lifetime: f32) {
+        self.particles.push(Particle {
+            x, y, vx, vy,
+            age: 0.0,
+            lifetime,
+            trail: VecDeque::with_capacity(self.trail_length),
+        });
+    }
+
+    /// Advances every particle by `dt`, removing those that have outlived their lifetime.
+    pub fn step(&mut self, dt: f32) {
+        let (ax, ay) = self.gravity;
+        let trail_length = self.trail_length;
+        self.particles.retain_mut(|p| {
+            if trail_length > 0 {
+                if p.trail.len() == trail_length {
+                    p.trail.pop_front();
+                }
+                p.trail.push_back((p.x, p.y));
+            }
+            p.vx += ax * dt;
+            p.vy += ay * dt;
+            p.x += p.vx * dt;
+            p.y += p.vy * dt;
+            p.age += dt;
+            p.age < p.lifetime
+        });
+    }
+
+    /// Draws every particle onto the `Canvas`, along with its trail.
+    ///
+    /// Particles at negative coordinates are not drawn.
+    pub fn draw(&self, canvas: &mut Canvas) {
+        let pixel = |x: f32, y: f32| {
+            if x.round() < 0.0 || y.round() < 0.0 {
+                None
+            } else {
+                Some((x.round() as u32, y.round() as u32))
+            }
+        };
+        for p in &self.particles {
+            let mut points = p.trail.iter().cloned()
+                .chain(Some((p.x, p.y)))
+                .map(|(x, y)| pixel(x, y));
+            let mut last = points.next().unwrap_or(None);
+            for point in points {
+                match (last, point) {
+                    (Some((x1, y1)), Some((x2, y2))) => canvas.line(x1, y1, x2, y2),
+                    (None, Some((x, y))) => canvas.set(x, y),
+                    _ => {}
+                }
+                last = point;
+            }
+            if p.trail.is_empty() {
+                if let Some((x, y)) = last {
+                    canvas.set(x, y);
+                }
+            }
+        }
+    }
+
+    /// Returns the particles that are currently alive.
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    /// Returns the number of particles that are currently alive.
+    pub fn len(&self) -> usize {
+        self.particles.len()
+    }
+
+    /// Detects whether every particle has died.
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+}
@@ -0,0 +1,186 @@
+use std::cmp;
+
+use canvas::Canvas;
+use turtle::Turtle;
+use world::clip;
+
+/// A path of straight lines through a sequence of points, which can be measured and walked
+/// along at a constant speed.
+///
+/// ```
+/// use drawille::Path;
+///
+/// let path = Path::new(vec![(0.0, 0.0), (30.0, 0.0), (30.0, 10.0)]);
+/// assert_eq!(path.length(), 40.0);
+/// assert_eq!(path.point_at(0.5), Some((20.0, 0.0)));
+/// assert_eq!(path.point_at_distance(35.0), Some((30.0, 5.0)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path {
+    points: Vec<(f64, f64)>,
+    /// The distance along the path to each point.
+    distances: Vec<f64>,
+}
+
+impl Path {
+    /// Creates a new `Path` through the given points.
+    pub fn new(points: Vec<(f64, f64)>) -> Path {
+        let mut path = Path { points: Vec::with_capacity(points.len()), distances: Vec::new() };
+        for p in points {
+            path.push(p);
+        }
+        path
+    }
+
+    /// Extends the `Path` with a line to the given point.
+    pub fn push(&mut self, point: (f64, f64)) {
+        let distance = match self.points.last() {
+            Some(&(x, y)) => self.length() + (point.0 - x).hypot(point.1 - y),
+            None => 0.0,
+        };
+        self.points.push(point);
+        self.distances.push(distance);
+    }
+
+    /// Returns the points the `Path` passes through, in order.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Returns the total length of the `Path`.
+    pub fn length(&self) -> f64 {
+        self.distances.last().cloned().unwrap_or(0.0)
+    }
+
+    /// Returns the point a fraction `t` of the way along the `Path`, by length, or `None` if it
+    /// has no points.
+    ///
+    /// `t` is clamped to between 0 and 1, so stepping it evenly moves along the path at a
+    /// constant speed however its points are spaced.
+    pub fn point_at(&self, t: f64) -> Option<(f64, f64)> {
+        self.point_at_distance(t.clamp(0.0, 1.0) * self.length())
+    }
+
+    /// Returns the point at the given distance along the `Path`, clamped to its ends, or
+    /// `None` if it has no points.
+    pub fn point_at_distance(&self, distance: f64) -> Option<(f64, f64)> {
+        let (i, t) = self.locate(distance)?;
+        let (x1, y1) = self.points[i];
+        Some(match self.points.get(i + 1) {
+            Some(&(x2, y2)) => (x1 + (x2 - x1) * t, y1 + (y2 - y1) * t),
+            None => (x1, y1),
+        })
+    }
+
+    /// Returns the direction the `Path` heads in a fraction `t` of the way along it, in degrees
+    /// clockwise from the positive x axis (with y growing downwards, as for a `Turtle`), or
+    /// `None` if it has fewer than two distinct points.
+    ///
+    /// ```
+    /// use drawille::Path;
+    ///
+    /// let path = Path::new(vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)]);
+    /// assert_eq!(path.direction_at(0.75), Some(90.0));
+    /// ```
+    pub fn direction_at(&self, t: f64) -> Option<f64> {
+        let (i, _) = self.locate(t.clamp(0.0, 1.0) * self.length())?;
+        // Skip over any lines of no length, looking ahead first and then behind.
+        let i = (i..self.points.len() - 1).chain((0..i).rev())
+            .find(|&j| self.distances[j + 1] > self.distances[j])?;
+        let ((x1, y1), (x2, y2)) = (self.points[i], self.points[i + 1]);
+        Some((y2 - y1).atan2(x2 - x1).to_degrees())
+    }
+
+    /// Finds the line holding the point at `distance`, returning the index of its first point
+    /// and how far along it the point lies, from 0 to 1.
+    fn locate(&self, distance: f64) -> Option<(usize, f64)> {
+        if self.points.is_empty() {
+            return None;
+        }
+        let distance = distance.clamp(0.0, self.length());
+        // The last point at or before the distance, leaving a line after it where possible.
+        let i = self.distances.partition_point(|&d| d <= distance).saturating_sub(1);
+        let i = cmp::min(i, self.points.len().saturating_sub(2));
+        let span = match self.distances.get(i + 1) {
+            Some(&next) => next - self.distances[i],
+            None => 0.0,
+        };
+        let t = if span > 0.0 { (distance - self.distances[i]) / span } else { 0.0 };
+        Some((i, t.min(1.0)))
+    }
+
+    /// Returns a `Path` through `count` points spaced evenly along this one, from its start to
+    /// its end.
+    ///
+    /// ```
+    /// use drawille::Path;
+    ///
+    /// let path = Path::new(vec![(0.0, 0.0), (1.0, 0.0), (10.0, 0.0)]).resample(3);
+    /// assert_eq!(path.points(), [(0.0, 0.0), (5.0, 0.0), (10.0, 0.0)]);
+    /// ```
+    pub fn resample(&self, count: usize) -> Path {
+        let points = match count {
+            0 => Vec::new(),
+            1 => self.point_at(0.0).into_iter().collect(),
+            _ => (0..count)
+                .filter_map(|i| self.point_at(i as f64 / (count - 1) as f64))
+                .collect(),
+        };
+        Path::new(points)
+    }
+
+    /// Returns a `Path` through points `spacing` apart along this one, starting at its start
+    /// and always ending at its end.
+    pub fn resample_spacing(&self, spacing: f64) -> Path {
+        if spacing <= 0.0 || self.points.is_empty() {
+            return self.clone();
+        }
+        let count = (self.length() / spacing).ceil() as usize;
+        let mut points: Vec<_> = (0..count)
+            .filter_map(|i| self.point_at_distance(i as f64 * spacing))
+            .collect();
+        points.extend(self.point_at(1.0));
+        Path::new(points)
+    }
+
+    /// Draws the `Path` onto the `Canvas`, in pixel coordinates, clipped to its given
+    /// dimensions.
+    pub fn draw(&self, canvas: &mut Canvas) {
+        let (w, h) = canvas.pixel_extent();
+        for pair in self.points.windows(2) {
+            let round = |p: (f64, f64)| (p.0.round(), p.1.round());
+            if let Some(((x1, y1), (x2, y2))) = clip(round(pair[0]), round(pair[1]), w, h) {
+                canvas.line(x1 as u32, y1 as u32, x2 as u32, y2 as u32);
+            }
+        }
+    }
+}
+
+impl Turtle {
+    /// Returns the lines the `Turtle` has recorded as paths in its own coordinates, one for
+    /// each run of joined-up lines.
+    ///
+    /// ```
+    /// use drawille::Turtle;
+    ///
+    /// let mut turtle = Turtle::new(0.0, 0.0).record(true);
+    /// turtle.forward(10.0);
+    /// turtle.right(90.0);
+    /// turtle.forward(5.0);
+    /// assert_eq!(turtle.paths()[0].length(), 15.0);
+    /// ```
+    pub fn paths(&self) -> Vec<Path> {
+        let mut paths: Vec<Path> = Vec::new();
+        let mut end = None;
+        for &(x1, y1, x2, y2) in self.segments() {
+            if end != Some((x1, y1)) {
+                paths.push(Path::new(vec![(x1 as f64, y1 as f64)]));
+            }
+            if let Some(path) = paths.last_mut() {
+                path.push((x2 as f64, y2 as f64));
+            }
+            end = Some((x2, y2));
+        }
+        paths
+    }
+}
@@ -0,0 +1,184 @@
+use canvas::Canvas;
+use chart::{Fill, Theme};
+use gauge::polar;
+use style::{Color, Span, Style};
+
+/// One slice of a `Pie`: a named value, drawn in proportion to the total of all of them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Slice {
+    name: String,
+    value: f64,
+    color: Option<Color>,
+    fill: Option<Fill>,
+    exploded: bool,
+}
+
+impl Slice {
+    /// Creates a new `Slice` of a value. Values that are negative or not finite count as 0.
+    pub fn new<S: Into<String>>(name: S, value: f64) -> Slice {
+        Slice { name: name.into(), value, color: None, fill: None, exploded: false }
+    }
+
+    /// Sets the colour of a `Slice` instead of taking the next one from the palette, and
+    /// return it for use again.
+    pub fn color(mut self, color: Color) -> Slice {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets how a `Slice` is shaded instead of taking the next `Fill` in turn, and return it
+    /// for use again.
+    pub fn fill(mut self, fill: Fill) -> Slice {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// Pulls a `Slice` out from the centre, to draw attention to it, and return it for use
+    /// again.
+    pub fn exploded(mut self, exploded: bool) -> Slice {
+        self.exploded = exploded;
+        self
+    }
+
+    /// Returns the value of the `Slice` as it is counted.
+    fn weight(&self) -> f64 {
+        if self.value.is_finite() { self.value.max(0.0) } else { 0.0 }
+    }
+}
+
+/// A pie chart, or with a hole in the middle a donut chart, with each slice labelled with its
+/// name and percentage of the whole.
+///
+/// Slices go clockwise from twelve o’clock in order. Since a cell can only have one colour,
+/// slices that meet within a cell also differ in how densely they are shaded: solidly, with a
+/// checkerboard and sparsely in turn, so that they stay apart without colour too.
+///
+/// ```
+/// use drawille::{Canvas, Pie, Slice};
+///
+/// let pie = Pie::new(vec![
+///     Slice::new("rent", 50.0),
+///     Slice::new("food", 30.0).exploded(true),
+///     Slice::new("fun", 20.0),
+/// ]).hole(0.5);
+/// let mut canvas = Canvas::new(0, 0);
+/// pie.draw(&mut canvas, 60, 40, 30);
+/// assert!(canvas.frame().contains("food 30%"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pie {
+    slices: Vec<Slice>,
+    hole: f64,
+    labels: bool,
+    colored: bool,
+    theme: Theme,
+}
+
+impl Pie {
+    /// Creates a new `Pie` of the given slices, coloured from the default palette and
+    /// labelled.
+    pub fn new(slices: Vec<Slice>) -> Pie {
+        Pie { slices, hole: 0.0, labels: true, colored: true, theme: Theme::new() }
+    }
+
+    /// Cuts a hole in the middle of the `Pie`, as a fraction of its radius from 0 to 1, making
+    /// it a donut chart, and return it for use again.
+    pub fn hole(mut self, hole: f64) -> Pie {
+        self.hole = hole.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Shows or hides the labels around the `Pie`, and return it for use again.
+    pub fn labels(mut self, labels: bool) -> Pie {
+        self.labels = labels;
+        self
+    }
+
+    /// Colours the slices, or leaves them plain to tell apart by their shading alone, and
+    /// return it for use again.
+    pub fn colored(mut self, colored: bool) -> Pie {
+        self.colored = colored;
+        self
+    }
+
+    /// Takes the palette of another `Theme` for the slices, and return it for use again.
+    pub fn theme(mut self, theme: Theme) -> Pie {
+        self.theme = theme;
+        self
+    }
+
+    /// Returns the slices of the `Pie`.
+    pub fn slices(&self) -> &[Slice] {
+        &self.slices
+    }
+
+    /// Returns the start and end angle of each slice, in degrees clockwise from twelve o’clock.
+    pub fn angles(&self) -> Vec<(f64, f64)> {
+        let total: f64 = self.slices.iter().map(Slice::weight).sum();
+        let mut start = 0.0;
+        self.slices.iter().map(|slice| {
+            let sweep = if total > 0.0 { slice.weight() / total * 360.0 } else { 0.0 };
+            start += sweep;
+            (start - sweep, start)
+        }).collect()
+    }
+
+    /// Draws the `Pie` onto the `Canvas` with its centre and radius in pixels.
+    ///
+    /// Exploded slices are drawn an eighth of the radius further out, and labels a few pixels
+    /// beyond the edge, so leave some room around the `Pie`. Whatever falls above or left of
+    /// the `Canvas` is left out.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, radius: u32) {
+        let total: f64 = self.slices.iter().map(Slice::weight).sum();
+        let r = radius as f64;
+        let fills = [Fill::Solid, Fill::Checker, Fill::Sparse];
+        for (i, (slice, (start, end))) in self.slices.iter().zip(self.angles()).enumerate() {
+            if end <= start {
+                continue;
+            }
+            let mid = (start + end) / 2.0;
+            let shift = if slice.exploded { r / 8.0 } else { 0.0 };
+            let (cx, cy) = polar(x, y, shift, mid);
+            // The last slice meets the first, so it mustn’t be shaded the same.
+            let turn = if i > 0 && i == self.slices.len() - 1 && i % 3 == 0 { 1 } else { i % 3 };
+            let fill = slice.fill.unwrap_or(fills[turn]);
+            let style = match self.colored {
+                true => Style::new().fg(slice.color.unwrap_or(self.theme.color(i))),
+                false => Style::default(),
+            };
+            let (left, top) = ((cx - r).floor().max(0.0) as u32, (cy - r).floor().max(0.0) as u32);
+            for py in top..=(cy + r).ceil().max(0.0) as u32 {
+                for px in left..=(cx + r).ceil().max(0.0) as u32 {
+                    let (dx, dy) = (px as f64 - cx, py as f64 - cy);
+                    let distance = dx.hypot(dy);
+                    if distance > r || distance < r * self.hole {
+                        continue;
+                    }
+                    let angle = dx.atan2(-dy).to_degrees();
+                    let angle = if angle < 0.0 { angle + 360.0 } else { angle };
+                    if angle >= start && angle < end && fill.covers(px, py) {
+                        canvas.set_styled(px, py, style);
+                    }
+                }
+            }
+            if self.labels {
+                let label = format!("{} {:.0}%", slice.name, slice.weight() / total * 100.0);
+                let (lx, ly) = polar(x, y, r + shift + 4.0, mid);
+                // Labels on the left end at the pie, those on the right start there, and those
+                // near the top and bottom are centred, above or below it.
+                let width = label.chars().count() as f64 * 2.0;
+                let lx = match mid {
+                    m if m > 20.0 && m < 160.0 => lx,
+                    m if m > 200.0 && m < 340.0 => lx - width,
+                    _ => lx - width / 2.0,
+                };
+                let ly = ly - 2.0 - 2.0 * mid.to_radians().cos();
+                let (lx, ly) = (lx.round(), ly.round());
+                if lx >= 0.0 && ly >= 0.0 {
+                    canvas.text_styled(lx as u32, ly as u32, width as u32,
+                                       &[Span::new(&label, style)]);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use canvas::{Canvas, PIXEL_MAP};
+use dither::Density;
+use style::{Color, Style};
+
+/// The old-school plasma effect: overlapping sine waves that drift over time, shown as dot
+/// density and, with a palette, as colours cycling through it.
+///
+/// Drawing replaces everything in its rectangle, so a plasma can be redrawn every frame
+/// without clearing the `Canvas` first. Whole cells are computed and stored at once, which
+/// makes this a fair test of how fast full-screen updates can go.
+///
+/// ```
+/// use drawille::{Canvas, Color, Density, Plasma};
+///
+/// let plasma = Plasma::new().palette(vec![Color::Blue, Color::Magenta, Color::Red]);
+/// let mut canvas = Canvas::new(80, 40);
+/// for frame in 0..30 {
+///     plasma.draw(&mut canvas, 0, 0, 80, 40, frame as f64 / 30.0);
+/// }
+/// // Only the last frame shows, each pixel dithered from its level.
+/// for (x, y) in (0..80).flat_map(|x| (0..40).map(move |y| (x, y))) {
+///     let level = plasma.level(x as f64, y as f64, 29.0 / 30.0);
+///     assert_eq!(canvas.get(x, y), Density::default().is_set(x, y, level));
+/// }
+/// assert!(canvas.frame().starts_with("\x1b[31m"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Plasma {
+    scale: f64,
+    density: Density,
+    palette: Vec<Color>,
+    cycle: f64,
+}
+
+impl Plasma {
+    /// Creates a new `Plasma` with waves about 50 pixels long, an ordered dither and no colour.
+    pub fn new() -> Plasma {
+        Plasma { scale: 8.0, density: Density::default(), palette: Vec::new(), cycle: 0.25 }
+    }
+
+    /// Sets the size of the waves, 8 by default, and return it for use again.
+    pub fn scale(mut self, scale: f64) -> Plasma {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets how levels are turned into dots, and return it for use again.
+    pub fn density(mut self, density: Density) -> Plasma {
+        self.density = density;
+        self
+    }
+
+    /// Colours each cell from the palette by its level, and return it for use again. An empty
+    /// palette, the default, leaves the cells unstyled.
+    pub fn palette(mut self, palette: Vec<Color>) -> Plasma {
+        self.palette = palette;
+        self
+    }
+
+    /// Sets how many times a second the colours cycle through the whole palette, 0.25 by
+    /// default, and return it for use again.
+    pub fn cycle(mut self, cycle: f64) -> Plasma {
+        self.cycle = cycle;
+        self
+    }
+
+    /// Returns the level of the plasma at a pixel and a time in seconds, from 0 to 1.
+    pub fn level(&self, x: f64, y: f64, time: f64) -> f64 {
+        let (across, down, diagonal) = self.waves(x, y, x + y, time);
+        (across + down + diagonal + self.ripple(x, y, time) + 4.0) / 8.0
+    }
+
+    /// Returns the waves that depend only on x, only on y, and only on their sum.
+    fn waves(&self, x: f64, y: f64, sum: f64, time: f64) -> (f64, f64, f64) {
+        ((x / self.scale + time).sin(), (y / self.scale * 1.3 - time * 0.7).sin(),
+         (sum / self.scale * 0.7 + time * 0.5).sin())
+    }
+
+    /// Returns the wave rippling out from the origin.
+    fn ripple(&self, x: f64, y: f64, time: f64) -> f64 {
+        let (x, y) = (x / self.scale, y / self.scale);
+        ((x * x + y * y).sqrt() * 0.8 - time).sin()
+    }
+
+    /// Draws the plasma at a time in seconds into a rectangle, given by its top-left corner
+    /// and size in pixels, setting and unsetting every pixel in it.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, width: u32, height: u32,
+                time: f64) {
+        if width == 0 || height == 0 {
+            return;
+        }
+        let (right, bottom) = (x + width, y + height);
+        // Three of the four waves are the same along a column, a row or a diagonal, so they
+        // are worked out once each rather than for every pixel.
+        let across: Vec<f64> = (x..right).map(|px| self.waves(px as f64, 0.0, 0.0, time).0)
+            .collect();
+        let down: Vec<f64> = (y..bottom).map(|py| self.waves(0.0, py as f64, 0.0, time).1)
+            .collect();
+        let diagonal: Vec<f64> = (x + y..right + bottom)
+            .map(|sum| self.waves(0.0, 0.0, sum as f64, time).2)
+            .collect();
+        for row in y / 4..=(bottom - 1) / 4 {
+            for col in x / 2..=(right - 1) / 2 {
+                let (mut bits, mut inside, mut sum) = (0u8, 0u8, 0.0);
+                for dy in 0..4 {
+                    for dx in 0..2 {
+                        let (px, py) = (col * 2 + dx, row * 4 + dy);
+                        if px < x || px >= right || py < y || py >= bottom {
+                            continue;
+                        }
+                        let (i, j) = ((px - x) as usize, (py - y) as usize);
+                        let level = (across[i] + down[j] + diagonal[i + j]
+                            + self.ripple(px as f64, py as f64, time) + 4.0) / 8.0;
+                        let bit = PIXEL_MAP[dy as usize][dx as usize];
+                        inside |= bit;
+                        sum += level;
+                        if self.density.is_set(px, py, level) {
+                            bits |= bit;
+                        }
+                    }
+                }
+                let whole = inside == 0xff && col <= u16::MAX as u32 && row <= u16::MAX as u32;
+                if !(whole && canvas.put_cell(col as u16, row as u16, bits)) {
+                    self.draw_cell(canvas, col, row, inside, bits);
+                }
+                if !self.palette.is_empty() {
+                    let n = self.palette.len();
+                    let level = sum / inside.count_ones() as f64 + time * self.cycle;
+                    let color = self.palette[((level * n as f64).floor() as i64)
+                        .rem_euclid(n as i64) as usize];
+                    canvas.set_cell_style(col * 2, row * 4, Style::new().fg(color));
+                }
+            }
+        }
+    }
+
+    /// Sets and unsets the pixels of a cell one at a time, for those `inside` the rectangle.
+    fn draw_cell(&self, canvas: &mut Canvas, col: u32, row: u32, inside: u8, bits: u8) {
+        for (dy, bits_row) in PIXEL_MAP.iter().enumerate() {
+            for (dx, &bit) in bits_row.iter().enumerate() {
+                let (px, py) = (col * 2 + dx as u32, row * 4 + dy as u32);
+                if inside & bit == 0 {
+                    continue;
+                }
+                if bits & bit != 0 {
+                    canvas.set(px, py);
+                } else {
+                    canvas.unset(px, py);
+                }
+            }
+        }
+    }
+}
+
+impl Default for Plasma {
+    fn default() -> Plasma {
+        Plasma::new()
+    }
+}
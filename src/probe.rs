@@ -0,0 +1,162 @@
+use std::env;
+use std::io::{self, Read, Write};
+
+/// A kind of character cell that pixels can be drawn with, from the finest to the coarsest.
+///
+/// A `Canvas` always draws Braille; the other modes tell applications what to fall back to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PixelMode {
+    /// Braille patterns, with 2×4 pixels per cell.
+    Braille,
+    /// Sextants from Unicode’s Symbols for Legacy Computing, with 2×3 pixels per cell.
+    Sextant,
+    /// Half blocks, with 1×2 pixels per cell, which nearly every font has.
+    HalfBlock,
+    /// Plain ASCII, for terminals that can’t show anything else.
+    Ascii,
+}
+
+/// How many colours a terminal can show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ColorDepth {
+    /// No colours at all, or the user asked for none.
+    None,
+    /// The eight basic colours and their bright versions.
+    Ansi16,
+    /// The 256-colour palette.
+    Ansi256,
+    /// 24-bit RGB.
+    TrueColor,
+}
+
+/// What a terminal is likely able to show, as guessed by `probe`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Capabilities {
+    /// Whether the terminal is likely to render Braille patterns.
+    pub braille: bool,
+    /// Whether the terminal is likely to render sextants.
+    pub sextants: bool,
+    /// Whether the terminal is likely to render half blocks and other common block elements.
+    pub blocks: bool,
+    /// How many colours the terminal is likely to show.
+    pub color: ColorDepth,
+}
+
+impl Capabilities {
+    /// Guesses the capabilities of a terminal from the given lookup of environment variables.
+    ///
+    /// This is what `probe` uses with the real environment, and lets the guesses be checked for
+    /// specific terminals:
+    ///
+    /// ```
+    /// use drawille::{Capabilities, ColorDepth, PixelMode};
+    ///
+    /// let kitty = Capabilities::from_env(|name| match name {
+    ///     "TERM" => Some("xterm-kitty".to_string()),
+    ///     "COLORTERM" => Some("truecolor".to_string()),
+    ///     "LANG" => Some("en_GB.UTF-8".to_string()),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(kitty.color, ColorDepth::TrueColor);
+    /// assert_eq!(kitty.pixel_mode(), PixelMode::Braille);
+    ///
+    /// let console = Capabilities::from_env(|name| match name {
+    ///     "TERM" => Some("linux".to_string()),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(console.pixel_mode(), PixelMode::Ascii);
+    /// ```
+    pub fn from_env<F: Fn(&str) -> Option<String>>(var: F) -> Capabilities {
+        let has = |name: &str, needle: &str| {
+            var(name).is_some_and(|v| v.to_ascii_lowercase().contains(needle))
+        };
+        let term = var("TERM").unwrap_or_default().to_ascii_lowercase();
+        let program = var("TERM_PROGRAM").unwrap_or_default();
+        let windows_terminal = var("WT_SESSION").is_some();
+
+        // The first of these variables that is set decides the locale.
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"].iter().filter_map(|&name| var(name))
+            .find(|v| !v.is_empty())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let utf8 = locale.contains("utf-8") || locale.contains("utf8") || windows_terminal
+            || (cfg!(target_os = "macos") && !program.is_empty());
+        // The Linux console and bare Windows consoles have fonts without Braille.
+        let basic = term == "dumb" || term == "linux" || (cfg!(windows) && !windows_terminal
+            && term.is_empty());
+
+        let modern = windows_terminal || term.contains("kitty") || term.contains("foot")
+            || term.contains("wezterm") || program == "WezTerm" || program == "ghostty"
+            || term.contains("ghostty") || var("KITTY_WINDOW_ID").is_some();
+
+        let color = if var("NO_COLOR").is_some_and(|v| !v.is_empty()) || term == "dumb" {
+            ColorDepth::None
+        } else if has("COLORTERM", "truecolor") || has("COLORTERM", "24bit") || windows_terminal
+            || modern || program == "iTerm.app" {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        };
+
+        Capabilities {
+            braille: utf8 && !basic,
+            sextants: utf8 && !basic && modern,
+            blocks: utf8 && term != "dumb",
+            color,
+        }
+    }
+
+    /// Returns the finest pixel mode that the terminal is likely to render.
+    pub fn pixel_mode(&self) -> PixelMode {
+        if self.braille {
+            PixelMode::Braille
+        } else if self.sextants {
+            PixelMode::Sextant
+        } else if self.blocks {
+            PixelMode::HalfBlock
+        } else {
+            PixelMode::Ascii
+        }
+    }
+}
+
+/// Guesses what the current terminal can show from environment variables such as `TERM`,
+/// `COLORTERM`, `NO_COLOR` and the locale.
+///
+/// These are only heuristics: fonts in particular can’t be seen from the environment. Use
+/// `measure_width` for a definite answer on whether a character is drawn as a single cell.
+pub fn probe() -> Capabilities {
+    Capabilities::from_env(|name| env::var(name).ok())
+}
+
+/// Asks the terminal how many cells it advances the cursor by when writing `text`, using cursor
+/// position reports.
+///
+/// A terminal whose font lacks a character often draws a wide replacement, or nothing at all,
+/// so a Braille pattern that doesn’t measure 1 is unlikely to display well. The terminal must
+/// be in raw mode so that its reply can be read from `input` straight away; the text is written
+/// at the start of the current line, which should be cleared afterwards.
+pub fn measure_width<R: Read, W: Write>(input: &mut R, output: &mut W, text: &str)
+    -> io::Result<u32>
+{
+    write!(output, "\r{}\x1b[6n", text)?;
+    output.flush()?;
+    // The reply is `ESC [ row ; column R`.
+    let mut reply = Vec::new();
+    let mut byte = [0];
+    while reply.len() < 32 {
+        input.read_exact(&mut byte)?;
+        reply.push(byte[0]);
+        if byte[0] == b'R' {
+            break;
+        }
+    }
+    let reply = String::from_utf8_lossy(&reply);
+    let column = reply.rsplit(';').next()
+        .and_then(|c| c.trim_end_matches('R').parse::<u32>().ok())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData,
+                                      "invalid cursor position report"))?;
+    Ok(column.saturating_sub(1))
+}
@@ -0,0 +1,117 @@
+use canvas::Canvas;
+use world::clip;
+
+/// Arrows showing a 2D vector field sampled on a grid.
+///
+/// ```
+/// use drawille::{Canvas, Quiver};
+///
+/// // A vortex around the centre of a 9×9 grid.
+/// let (mut u, mut v) = (Vec::new(), Vec::new());
+/// for i in 0..81 {
+///     let (x, y) = ((i % 9) as f64 - 4.0, (i / 9) as f64 - 4.0);
+///     u.push(-y);
+///     v.push(x);
+/// }
+///
+/// let mut canvas = Canvas::new(90, 90);
+/// Quiver::new(u, v, 9).draw(&mut canvas, 0, 0, 90, 90);
+/// // Along the top the flow runs to the right, so the arrow there points right, with its barbs
+/// // trailing back from its head.
+/// assert!((42..=48).all(|x| canvas.get(x, 5)));
+/// assert!(canvas.get(46, 4) && canvas.get(46, 6) && !canvas.get(43, 4));
+/// // The still middle gets only a dot.
+/// assert!(canvas.get(45, 45) && !canvas.get(45, 44) && !canvas.get(46, 45));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Quiver {
+    u: Vec<f64>,
+    v: Vec<f64>,
+    columns: usize,
+    scale: Option<f64>,
+}
+
+impl Quiver {
+    /// Creates a new `Quiver` of a field given as its horizontal components `u` and vertical
+    /// components `v`, each as rows of `columns` samples.
+    ///
+    /// Like pixel coordinates, positive `v` points down. Arrows are scaled automatically so that
+    /// the longest one nearly spans the gap between grid points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` and `v` have different lengths, or `columns` is zero or doesn’t divide them.
+    pub fn new(u: Vec<f64>, v: Vec<f64>, columns: usize) -> Quiver {
+        assert_eq!(u.len(), v.len(), "u and v have different lengths");
+        assert!(columns > 0 && u.len() % columns == 0,
+                "field of {} values isn’t made of rows of {}", u.len(), columns);
+        Quiver { u, v, columns, scale: None }
+    }
+
+    /// Sets how many pixels long an arrow of length 1 is, instead of scaling arrows
+    /// automatically, and return the `Quiver` for use again.
+    pub fn scale(mut self, scale: f64) -> Quiver {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Returns the number of rows of samples in the field.
+    pub fn rows(&self) -> usize {
+        self.u.len() / self.columns
+    }
+
+    /// Draws an arrow for every sample onto the `Canvas`, spreading the grid over a rectangle
+    /// given by its top-left corner and size in pixels. Samples sit at the centres of equal
+    /// parts of the rectangle.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, width: u32, height: u32) {
+        let (columns, rows) = (self.columns, self.rows());
+        if columns == 0 || rows == 0 {
+            return;
+        }
+        let (sx, sy) = (width as f64 / columns as f64, height as f64 / rows as f64);
+        let scale = self.scale.unwrap_or_else(|| {
+            let longest = self.u.iter().zip(&self.v)
+                .map(|(u, v)| u.hypot(*v))
+                .filter(|l| l.is_finite())
+                .fold(0.0, f64::max);
+            if longest > 0.0 { sx.min(sy) * 0.9 / longest } else { 0.0 }
+        });
+        for j in 0..rows {
+            for i in 0..columns {
+                let (u, v) = (self.u[j * columns + i] * scale, self.v[j * columns + i] * scale);
+                if !(u.is_finite() && v.is_finite()) {
+                    continue;
+                }
+                let cx = x as f64 + (i as f64 + 0.5) * sx;
+                let cy = y as f64 + (j as f64 + 0.5) * sy;
+                arrow(canvas, (cx - u / 2.0, cy - v / 2.0), (cx + u / 2.0, cy + v / 2.0));
+            }
+        }
+    }
+}
+
+/// Draws an arrow from `tail` to `head`, with a head about a third of its length.
+fn arrow(canvas: &mut Canvas, tail: (f64, f64), head: (f64, f64)) {
+    let (dx, dy) = (head.0 - tail.0, head.1 - tail.1);
+    let length = dx.hypot(dy);
+    if length < 0.5 {
+        segment(canvas, head, head);
+        return;
+    }
+    segment(canvas, tail, head);
+    let barb = (length / 3.0).max(1.5);
+    let angle = dy.atan2(dx);
+    for &turn in &[2.6, -2.6] {
+        let a = angle + turn;
+        segment(canvas, head, (head.0 + barb * a.cos(), head.1 + barb * a.sin()));
+    }
+}
+
+/// Draws a line between two points, leaving out any part at negative coordinates.
+fn segment(canvas: &mut Canvas, p1: (f64, f64), p2: (f64, f64)) {
+    let limit = u32::MAX as f64;
+    if let Some(((x1, y1), (x2, y2))) = clip(p1, p2, limit, limit) {
+        let (x1, y1) = (x1.round() as u32, y1.round() as u32);
+        canvas.line(x1, y1, x2.round() as u32, y2.round() as u32);
+    }
+}
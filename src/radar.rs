@@ -0,0 +1,198 @@
+use fnv::FnvHashMap;
+
+use canvas::Canvas;
+use dither::Dither;
+use gauge::{polar, segment};
+use style::{Color, Style};
+
+/// A radar screen: a circular grid with a line sweeping round it, leaving a fading trail, and
+/// blips placed by bearing and distance that light up as the line passes and then fade.
+///
+/// Bearings are in degrees clockwise from north, at the top, as on a compass. Since dots can
+/// only be on or off, the fading is shown by thinning them out, and optionally by colour.
+///
+/// ```
+/// use drawille::{Canvas, Radar};
+///
+/// let mut radar = Radar::new(100.0).compass(true);
+/// radar.add(45.0, 60.0);
+/// radar.add(200.0, 30.0);
+///
+/// let mut canvas = Canvas::new(0, 0);
+/// radar.draw(&mut canvas, 40, 40, 36, 60.0);
+/// assert_eq!(radar.strength(45.0, 350.0), 0.0);
+/// assert!(radar.strength(45.0, 60.0) > 0.8);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Radar {
+    range: f64,
+    rings: u32,
+    spokes: u32,
+    trail: f64,
+    compass: bool,
+    colored: bool,
+    blips: Vec<(f64, f64)>,
+}
+
+impl Radar {
+    /// Creates a new `Radar` without blips, showing distances up to `range` with three rings,
+    /// four spokes and a trail 90° long.
+    pub fn new(range: f64) -> Radar {
+        Radar {
+            range,
+            rings: 3,
+            spokes: 4,
+            trail: 90.0,
+            compass: false,
+            colored: false,
+            blips: Vec::new(),
+        }
+    }
+
+    /// Sets the number of rings in the grid, the outermost one at the edge of the screen, and
+    /// return the `Radar` for use again.
+    pub fn rings(mut self, rings: u32) -> Radar {
+        self.rings = rings;
+        self
+    }
+
+    /// Sets the number of dotted spokes in the grid, spread evenly from north, and return the
+    /// `Radar` for use again.
+    pub fn spokes(mut self, spokes: u32) -> Radar {
+        self.spokes = spokes;
+        self
+    }
+
+    /// Sets how many degrees the trail and the blips take to fade away behind the sweep, and
+    /// return the `Radar` for use again.
+    pub fn trail(mut self, degrees: f64) -> Radar {
+        self.trail = degrees;
+        self
+    }
+
+    /// Labels north, east, south and west just outside the screen, and return the `Radar` for
+    /// use again.
+    pub fn compass(mut self, compass: bool) -> Radar {
+        self.compass = compass;
+        self
+    }
+
+    /// Colours the trail and the blips green, darker as they fade, and return the `Radar` for
+    /// use again.
+    pub fn colored(mut self, colored: bool) -> Radar {
+        self.colored = colored;
+        self
+    }
+
+    /// Adds a blip at the given bearing, in degrees clockwise from north, and distance from
+    /// the centre. Blips beyond the range are not shown.
+    pub fn add(&mut self, bearing: f64, distance: f64) {
+        self.blips.push((bearing, distance));
+    }
+
+    /// Returns the blips as their bearings and distances, in the order they were added.
+    pub fn blips(&self) -> &[(f64, f64)] {
+        &self.blips
+    }
+
+    /// Removes every blip.
+    pub fn clear(&mut self) {
+        self.blips.clear();
+    }
+
+    /// Returns how brightly something at `bearing` shows when the sweep is at `sweep`, from 1
+    /// just as the sweep passes it down to 0 once it is a whole trail behind.
+    pub fn strength(&self, bearing: f64, sweep: f64) -> f64 {
+        let behind = (sweep - bearing).rem_euclid(360.0);
+        if self.trail > 0.0 { (1.0 - behind / self.trail).max(0.0) } else { 0.0 }
+    }
+
+    /// Draws the `Radar` centred at the given pixel coordinates, with its edge `radius` pixels
+    /// out and the sweep at the given bearing.
+    ///
+    /// Parts of the `Radar` that would lie at negative coordinates are not drawn.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, radius: u32, sweep: f64) {
+        let r = radius as f64;
+        let mut points = Vec::new();
+        for ring in 1..=self.rings {
+            let ring = r * ring as f64 / self.rings as f64;
+            let steps = (ring * 2.0 * ::std::f64::consts::PI).ceil().max(8.0) as u32;
+            let point = |i: u32| polar(x, y, ring, i as f64 * 360.0 / steps as f64);
+            for i in 0..steps {
+                segment(&mut points, point(i), point(i + 1));
+            }
+        }
+        for spoke in 0..self.spokes {
+            let mut spoke_points = Vec::new();
+            let bearing = spoke as f64 * 360.0 / self.spokes as f64;
+            segment(&mut spoke_points, (x as f64, y as f64), polar(x, y, r, bearing));
+            points.extend(spoke_points.into_iter().step_by(2));
+        }
+        segment(&mut points, (x as f64, y as f64), polar(x, y, r, sweep));
+        for (px, py) in points {
+            canvas.set(px, py);
+        }
+
+        // The strongest light in each cell, to colour it by.
+        let mut cells = FnvHashMap::default();
+        let mut light = |canvas: &mut Canvas, px: u32, py: u32, strength: f64| {
+            canvas.set(px, py);
+            let cell = cells.entry((px / 2, py / 4)).or_insert(0f64);
+            *cell = cell.max(strength);
+        };
+
+        // The trail is at most a third as dense as a blip, so that blips stand out within it.
+        let (x0, y0) = (x.saturating_sub(radius), y.saturating_sub(radius));
+        for py in y0..=y + radius {
+            for px in x0..=x + radius {
+                let (dx, dy) = (px as f64 - x as f64, py as f64 - y as f64);
+                if dx * dx + dy * dy > r * r {
+                    continue;
+                }
+                let strength = self.strength(dx.atan2(-dy).to_degrees(), sweep);
+                if strength > 0.0 && strength / 3.0 > Dither::Bayer4.threshold(px, py) {
+                    light(canvas, px, py, strength);
+                }
+            }
+        }
+
+        for &(bearing, distance) in &self.blips {
+            let strength = self.strength(bearing, sweep);
+            if strength <= 0.0 || distance > self.range || self.range <= 0.0 {
+                continue;
+            }
+            let (bx, by) = polar(x, y, r * distance.max(0.0) / self.range, bearing);
+            let (bx, by) = (bx.round() - 1.0, by.round() - 1.0);
+            // A fading blip loses its dots one at a time, from a 3×3 square down to its centre.
+            for (i, &(dx, dy)) in BLIP.iter().enumerate() {
+                let (px, py) = (bx + dx as f64, by + dy as f64);
+                if strength * BLIP.len() as f64 > i as f64 && px >= 0.0 && py >= 0.0 {
+                    light(canvas, px as u32, py as u32, strength);
+                }
+            }
+        }
+
+        if self.colored {
+            for ((col, row), strength) in cells {
+                let green = (64.0 + 191.0 * strength).round() as u8;
+                canvas.set_cell_style(col * 2, row * 4, Style::new().fg(Color::Rgb(0, green, 0)));
+            }
+        }
+
+        if self.compass {
+            let r = r + 6.0;
+            for &(bearing, label) in &[(0.0, 'N'), (90.0, 'E'), (180.0, 'S'), (270.0, 'W')] {
+                let (lx, ly) = polar(x, y, r, bearing);
+                let (lx, ly) = ((lx - 1.0).round(), (ly - 2.0).round());
+                if lx >= 0.0 && ly >= 0.0 {
+                    canvas.set_char(lx as u32, ly as u32, label);
+                }
+            }
+        }
+    }
+}
+
+/// The offsets of a blip’s dots, in the order they appear as it brightens.
+static BLIP: [(u32, u32); 9] = [
+    (1, 1), (0, 1), (2, 1), (1, 0), (1, 2), (0, 0), (2, 2), (2, 0), (0, 2),
+];
@@ -0,0 +1,116 @@
+use std::f64::consts::PI;
+
+use rand_core::RngCore;
+
+use canvas::Canvas;
+use particles::ParticleSystem;
+
+/// Returns a random number from 0 up to but not including 1.
+fn unit<R: RngCore>(rng: &mut R) -> f64 {
+    // 53 random bits fill the mantissa of an `f64` exactly.
+    (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Returns a random number from a normal distribution with the given mean and standard
+/// deviation, using the Box–Muller transform.
+fn normal<R: RngCore>(rng: &mut R, mean: f64, deviation: f64) -> f64 {
+    let (u, v) = (1.0 - unit(rng), unit(rng));
+    mean + deviation * (-2.0 * u.ln()).sqrt() * (2.0 * PI * v).cos()
+}
+
+// Every method here draws its randomness from the `rng` passed to it, so passing one seeded
+// the same way reproduces its output exactly, as golden-file tests need.
+impl Canvas {
+    /// Sets `count` pixels chosen uniformly at random from a rectangle, given by its top-left
+    /// corner and size.
+    ///
+    /// Pixels may be chosen more than once, so slightly fewer than `count` may end up set.
+    pub fn scatter<R: RngCore>(&mut self, rng: &mut R, x: u32, y: u32, width: u32, height: u32,
+                               count: usize) {
+        if width == 0 || height == 0 {
+            return;
+        }
+        for _ in 0..count {
+            let px = x + (unit(rng) * width as f64) as u32;
+            let py = y + (unit(rng) * height as f64) as u32;
+            self.set(px, py);
+        }
+    }
+
+    /// Sets `count` pixels scattered around `(x, y)` in a normal distribution, with the given
+    /// standard deviation in pixels, such as for a cluster in a scatter plot.
+    ///
+    /// Pixels that would land at negative coordinates are not drawn.
+    pub fn scatter_normal<R: RngCore>(&mut self, rng: &mut R, x: f64, y: f64, deviation: f64,
+                                      count: usize) {
+        for _ in 0..count {
+            let (px, py) = (normal(rng, x, deviation).round(), normal(rng, y, deviation).round());
+            if px >= 0.0 && py >= 0.0 {
+                self.set(px as u32, py as u32);
+            }
+        }
+    }
+
+    /// Creates a new `Canvas` from a greyscale image like `from_luma`, dithering it randomly:
+    /// each pixel is set with a chance equal to its brightness.
+    ///
+    /// Unlike a threshold, this keeps smooth gradients, at the cost of a grainy look.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `luma` holds fewer than `width * height` bytes.
+    pub fn from_luma_random<R: RngCore>(width: u32, height: u32, luma: &[u8], rng: &mut R)
+        -> Canvas
+    {
+        let (w, h) = (width as usize, height as usize);
+        assert!(luma.len() >= w * h, "luma buffer is smaller than width * height");
+        let mut canvas = Canvas::new(width, height);
+        for y in 0..h {
+            for x in 0..w {
+                // Compare against a random byte, so that 0 is never set and 255 nearly always.
+                if luma[y * w + x] > (rng.next_u32() >> 24) as u8 {
+                    canvas.set(x as u32, y as u32);
+                }
+            }
+        }
+        canvas
+    }
+}
+
+impl ParticleSystem {
+    /// Spawns `count` particles at `(x, y)`, flying off in random directions at up to `speed`,
+    /// each living for between half of `lifetime` and all of it.
+    ///
+    /// ```
+    /// extern crate drawille;
+    /// extern crate rand_core;
+    ///
+    /// use drawille::ParticleSystem;
+    /// # use rand_core::{impls, Error, RngCore};
+    /// # struct Counter(u64);
+    /// # impl RngCore for Counter {
+    /// #     fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+    /// #     fn next_u64(&mut self) -> u64 { self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1); self.0 >> 16 }
+    /// #     fn fill_bytes(&mut self, dest: &mut [u8]) { impls::fill_bytes_via_next(self, dest) }
+    /// #     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> { Ok(self.fill_bytes(dest)) }
+    /// # }
+    ///
+    /// fn main() {
+    ///     let (mut a, mut b) = (ParticleSystem::new(), ParticleSystem::new());
+    ///     a.burst(&mut Counter(7), 10.0, 10.0, 50, 5.0, 1.0);
+    ///     b.burst(&mut Counter(7), 10.0, 10.0, 50, 5.0, 1.0);
+    ///     assert_eq!(a, b);
+    ///     assert_eq!(a.len(), 50);
+    /// }
+    /// ```
+    pub fn burst<R: RngCore>(&mut self, rng: &mut R, x: f32, y: f32, count: usize, speed: f32,
+                             lifetime: f32) {
+        for _ in 0..count {
+            let angle = unit(rng) * 2.0 * PI;
+            let v = speed as f64 * unit(rng);
+            let life = lifetime as f64 * (0.5 + 0.5 * unit(rng));
+            let (vx, vy) = ((v * angle.cos()) as f32, (v * angle.sin()) as f32);
+            self.spawn(x, y, vx, vy, life as f32);
+        }
+    }
+}
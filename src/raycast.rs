@@ -0,0 +1,210 @@
+use canvas::Canvas;
+use dither::Density;
+
+/// A first-person view into a grid of walls, as in the early 3D maze games: a ray is cast from
+/// the viewer for each column of pixels, and the wall it hits drawn as a column whose height
+/// shrinks with its distance.
+///
+/// Walls are shaded by a density of dots that thins out with distance, and those facing north
+/// or south a little more thinly than those facing east or west, so that corners stand out.
+/// The floor is shaded faintly, getting denser towards the viewer, and the ceiling is left
+/// clear.
+///
+/// Positions are in cells of the map, with `x` across its columns and `y` down its rows, so
+/// that the middle of the top-left cell is `(0.5, 0.5)`. Angles are in degrees and, with `y`
+/// down, turn clockwise as seen on the map, from 0 facing along the rows to the east.
+///
+/// ```
+/// use drawille::{Canvas, Raycaster};
+///
+/// let map = Raycaster::new(&[
+///     "#####",
+///     "#   #",
+///     "#   #",
+///     "#   #",
+///     "#####",
+/// ]);
+/// assert_eq!(map.cast(2.5, 2.5, 0.0), Some(1.5));
+/// assert!(map.is_wall(4.2, 2.5) && !map.is_wall(2.5, 2.5));
+///
+/// let mut canvas = Canvas::new(80, 48);
+/// map.draw(&mut canvas, 1.5, 2.5, 0.0);
+/// // The wall ahead, two and a half cells away, fills the middle of the view, and the ceiling
+/// // above it is clear.
+/// let column = (0..48).filter(|&y| canvas.get(40, y)).count();
+/// assert!(column > 20 && !canvas.get(40, 0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Raycaster {
+    width: usize,
+    height: usize,
+    walls: Vec<bool>,
+    fov: f64,
+    depth: f64,
+    density: Density,
+    floor: bool,
+}
+
+impl Raycaster {
+    /// Creates a new `Raycaster` of a map given as rows of text, in which spaces and dots are
+    /// open floor and any other character is a wall, with a field of view of 60°.
+    ///
+    /// Rows shorter than the longest are open beyond their end, and everything outside the map
+    /// counts as wall, so a view never sees out of it.
+    pub fn new(map: &[&str]) -> Raycaster {
+        let width = map.iter().map(|row| row.chars().count()).max().unwrap_or(0);
+        let mut walls = vec![false; width * map.len()];
+        for (y, row) in map.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                walls[y * width + x] = c != ' ' && c != '.';
+            }
+        }
+        Raycaster {
+            width,
+            height: map.len(),
+            walls,
+            fov: 60.0,
+            depth: 16.0,
+            density: Density::default(),
+            floor: true,
+        }
+    }
+
+    /// Sets how wide the view is from its left edge to its right in degrees, and return it for
+    /// use again.
+    pub fn fov(mut self, degrees: f64) -> Raycaster {
+        self.fov = degrees.clamp(1.0, 179.0);
+        self
+    }
+
+    /// Sets how many cells away the view reaches, beyond which walls fade out completely, and
+    /// return it for use again.
+    pub fn depth(mut self, cells: f64) -> Raycaster {
+        self.depth = cells.max(0.0);
+        self
+    }
+
+    /// Sets how distances are turned into densities of dots, and return it for use again.
+    pub fn density(mut self, density: Density) -> Raycaster {
+        self.density = density;
+        self
+    }
+
+    /// Shades or leaves out the floor, and return it for use again.
+    pub fn floor(mut self, floor: bool) -> Raycaster {
+        self.floor = floor;
+        self
+    }
+
+    /// Returns the width and height of the map in cells.
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Returns whether a position is inside a wall or outside the map, such as for keeping the
+    /// viewer from walking through walls.
+    pub fn is_wall(&self, x: f64, y: f64) -> bool {
+        self.wall_cell(x.floor() as i64, y.floor() as i64)
+    }
+
+    /// Returns how far a ray from a position at an angle travels before it hits a wall, or
+    /// `None` if there is none within the depth of the view.
+    pub fn cast(&self, x: f64, y: f64, angle: f64) -> Option<f64> {
+        self.hit(x, y, angle).map(|hit| hit.0)
+    }
+
+    /// Returns whether a cell is a wall or outside the map.
+    fn wall_cell(&self, x: i64, y: i64) -> bool {
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return true;
+        }
+        self.walls[y as usize * self.width + x as usize]
+    }
+
+    /// Steps a ray from cell to cell through the grid until it enters a wall, returning the
+    /// distance travelled and whether the face hit looks north or south.
+    fn hit(&self, x: f64, y: f64, angle: f64) -> Option<(f64, bool)> {
+        let (dy, dx) = angle.to_radians().sin_cos();
+        let (mut cx, mut cy) = (x.floor() as i64, y.floor() as i64);
+        if self.wall_cell(cx, cy) {
+            return Some((0.0, false));
+        }
+        // How far along the ray each step of a cell across or down is, and how far to the
+        // first such step.
+        let (step_x, step_y) = ((1.0 / dx).abs(), (1.0 / dy).abs());
+        let (sx, mut next_x) = match dx < 0.0 {
+            true => (-1, (x - cx as f64) * step_x),
+            false => (1, (cx as f64 + 1.0 - x) * step_x),
+        };
+        let (sy, mut next_y) = match dy < 0.0 {
+            true => (-1, (y - cy as f64) * step_y),
+            false => (1, (cy as f64 + 1.0 - y) * step_y),
+        };
+        loop {
+            let (distance, side) = match next_x < next_y {
+                true => {
+                    cx += sx;
+                    next_x += step_x;
+                    (next_x - step_x, false)
+                }
+                false => {
+                    cy += sy;
+                    next_y += step_y;
+                    (next_y - step_y, true)
+                }
+            };
+            if distance > self.depth {
+                return None;
+            }
+            if self.wall_cell(cx, cy) {
+                return Some((distance, side));
+            }
+        }
+    }
+
+    /// Draws the view from a position facing an angle onto the whole of the `Canvas`, as
+    /// given by its dimensions.
+    pub fn draw(&self, canvas: &mut Canvas, x: f64, y: f64, angle: f64) {
+        let (w, h) = canvas.pixel_extent();
+        if w <= 0.0 || h <= 0.0 {
+            return;
+        }
+        let half = (self.fov / 2.0).to_radians().tan();
+        // How far away the screen is from the viewer, in pixels, for the view to be as wide
+        // as the field of view.
+        let screen = (w + 1.0) / 2.0 / half;
+        let middle = h / 2.0;
+        for px in 0..=w as u32 {
+            let offset = (px as f64 / w * 2.0 - 1.0) * half;
+            let ray = angle + offset.atan().to_degrees();
+            let mut floor = middle;
+            if let Some((distance, side)) = self.hit(x, y, ray) {
+                // The distance straight ahead rather than along the ray, so that flat walls
+                // stay flat instead of bulging like a fish-eye lens.
+                let straight = (distance * (ray - angle).to_radians().cos()).max(1e-6);
+                let half_height = screen / straight / 2.0;
+                let fade = 1.0 - straight / self.depth;
+                let level = if side { fade * 0.7 } else { fade };
+                let (top, bottom) = (middle - half_height, middle + half_height);
+                for py in top.max(0.0).round() as u32..=bottom.min(h).round() as u32 {
+                    // The top and bottom of each wall are always drawn, to outline it.
+                    let edge = (py as f64 - top).abs() < 1.0 || (py as f64 - bottom).abs() < 1.0;
+                    if edge || self.density.is_set(px, py, level) {
+                        canvas.set(px, py);
+                    }
+                }
+                floor = bottom;
+            }
+            if self.floor {
+                for py in floor.floor() as u32 + 1..=h as u32 {
+                    // The floor at a row is as far away as a wall whose bottom is there.
+                    let distance = screen / 2.0 / (py as f64 - middle);
+                    let level = 0.4 / (1.0 + distance);
+                    if distance < self.depth && self.density.is_set(px, py, level) {
+                        canvas.set(px, py);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::io::{self, BufRead, Write};
+
+use script::Script;
+use turtle::Turtle;
+
+/// Runs a Logo-style teaching environment: reads turtle script a line at a time from `input`,
+/// runs it on the `Turtle`, and redraws the turtle’s `Canvas` to `out` after every line.
+///
+/// Besides the commands of `Script::parse`, a line can be `CS` or `CLEARSCREEN`, which erases
+/// the drawing and sends the turtle back to where it started, or `BYE`, which ends the loop as
+/// the end of `input` does. A line that doesn’t parse is reported and otherwise ignored, so a
+/// typo never loses the drawing.
+///
+/// ```
+/// use drawille::{repl, Turtle};
+///
+/// let input = "REPEAT 4 [F 10; R 90]\nFD\nBYE\nF 10\n";
+/// let mut out = Vec::new();
+/// let mut turtle = Turtle::new(0.0, 0.0).record(true);
+/// repl(input.as_bytes(), &mut out, &mut turtle).unwrap();
+/// let out = String::from_utf8(out).unwrap();
+/// assert!(out.contains("FD is missing an argument on line 1"));
+/// assert_eq!(turtle.segments().len(), 4);
+/// ```
+pub fn repl<R: BufRead, W: Write>(input: R, out: &mut W, turtle: &mut Turtle)
+    -> io::Result<()>
+{
+    let home = (turtle.x, turtle.y, turtle.rotation);
+    let mut message = String::new();
+    let mut frame = String::new();
+    render(out, turtle, &mut frame, &message)?;
+    for line in input.lines() {
+        let line = line?;
+        message.clear();
+        match &line.trim().to_ascii_uppercase()[..] {
+            "BYE" => break,
+            "CS" | "CLEARSCREEN" => {
+                turtle.clear();
+                turtle.x = home.0;
+                turtle.y = home.1;
+                turtle.rotation = home.2;
+            }
+            _ => match Script::parse(&line) {
+                Ok(script) => script.run(turtle),
+                Err(e) => message = e.to_string(),
+            },
+        }
+        render(out, turtle, &mut frame, &message)?;
+    }
+    Ok(())
+}
+
+/// Clears the terminal and writes the turtle’s `Canvas`, any message, and a prompt.
+fn render<W: Write>(out: &mut W, turtle: &Turtle, frame: &mut String, message: &str)
+    -> io::Result<()>
+{
+    frame.clear();
+    turtle.cvs.frame_into(frame);
+    write!(out, "\x1b[2J\x1b[H{}\n{}\n? ", frame, message)?;
+    out.flush()
+}
@@ -0,0 +1,226 @@
+use std::f64;
+
+use art::Art;
+use canvas::Canvas;
+use world::clip;
+
+/// A 2D affine transform, mapping the local coordinates of a `Node` to those of its parent.
+///
+/// Coordinates are in pixels with y growing downwards, so positive angles turn clockwise on
+/// screen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    /// The matrix `[a c e; b d f; 0 0 1]`, stored as `[a, b, c, d, e, f]`.
+    matrix: [f64; 6],
+}
+
+impl Transform {
+    /// Returns the transform that leaves every point where it is.
+    pub fn identity() -> Transform {
+        Transform { matrix: [1.0, 0.0, 0.0, 1.0, 0.0, 0.0] }
+    }
+
+    /// Returns a transform moving points by `(x, y)`.
+    pub fn translate(x: f64, y: f64) -> Transform {
+        Transform { matrix: [1.0, 0.0, 0.0, 1.0, x, y] }
+    }
+
+    /// Returns a transform turning points clockwise by `angle` degrees around the origin.
+    pub fn rotate(angle: f64) -> Transform {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        Transform { matrix: [cos, sin, -sin, cos, 0.0, 0.0] }
+    }
+
+    /// Returns a transform stretching points away from the origin by `x` and `y`.
+    pub fn scale(x: f64, y: f64) -> Transform {
+        Transform { matrix: [x, 0.0, 0.0, y, 0.0, 0.0] }
+    }
+
+    /// Returns the transform that applies `self` and then `outer`.
+    ///
+    /// ```
+    /// use drawille::Transform;
+    ///
+    /// let t = Transform::rotate(90.0).then(&Transform::translate(10.0, 0.0));
+    /// let (x, y) = t.apply((1.0, 0.0));
+    /// assert!((x - 10.0).abs() < 1e-9 && (y - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn then(&self, outer: &Transform) -> Transform {
+        let [a1, b1, c1, d1, e1, f1] = self.matrix;
+        let [a2, b2, c2, d2, e2, f2] = outer.matrix;
+        Transform {
+            matrix: [a2 * a1 + c2 * b1, b2 * a1 + d2 * b1,
+                     a2 * c1 + c2 * d1, b2 * c1 + d2 * d1,
+                     a2 * e1 + c2 * f1 + e2, b2 * e1 + d2 * f1 + f2],
+        }
+    }
+
+    /// Maps a point through the transform.
+    pub fn apply(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        let [a, b, c, d, e, f] = self.matrix;
+        (a * x + c * y + e, b * x + d * y + f)
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Transform {
+        Transform::identity()
+    }
+}
+
+/// Something a `Node` draws, in its local coordinates.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Shape {
+    Point((f64, f64)),
+    Line((f64, f64), (f64, f64)),
+    /// Straight lines joining each point to the next.
+    Polyline(Vec<(f64, f64)>),
+    /// A circle with the given centre and radius, which becomes an ellipse if the node is
+    /// scaled unevenly.
+    Circle((f64, f64), f64),
+}
+
+/// A node of a scene graph: shapes, sprites and child nodes, all placed by one transform
+/// relative to the node’s parent.
+///
+/// Moving or turning a node carries its children along, so an articulated figure only needs
+/// each joint’s own angle updating from frame to frame.
+///
+/// ```
+/// use drawille::{Canvas, Node, Shape, Transform};
+///
+/// let hand = |length: f64, angle: f64| {
+///     Node::new()
+///         .transform(Transform::rotate(angle))
+///         .shape(Shape::Line((0.0, 0.0), (0.0, -length)))
+/// };
+/// let clock = Node::new()
+///     .transform(Transform::translate(20.0, 20.0))
+///     .shape(Shape::Circle((0.0, 0.0), 18.0))
+///     .child(hand(10.0, 90.0))
+///     .child(hand(16.0, 0.0));
+///
+/// let mut canvas = Canvas::new(40, 40);
+/// clock.draw(&mut canvas);
+/// assert!(canvas.get(30, 20) && canvas.get(20, 4));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Node {
+    transform: Transform,
+    shapes: Vec<Shape>,
+    sprites: Vec<((f64, f64), Art)>,
+    children: Vec<Node>,
+}
+
+impl Node {
+    /// Creates a new, empty `Node` with the identity transform.
+    pub fn new() -> Node {
+        Node::default()
+    }
+
+    /// Sets the node’s transform, and return it for use again.
+    pub fn transform(mut self, transform: Transform) -> Node {
+        self.transform = transform;
+        self
+    }
+
+    /// Adds a shape to the node, and return it for use again.
+    pub fn shape(mut self, shape: Shape) -> Node {
+        self.shapes.push(shape);
+        self
+    }
+
+    /// Adds a sprite with its top-left corner at the given point, and return it for use again.
+    pub fn sprite(mut self, at: (f64, f64), art: Art) -> Node {
+        self.sprites.push((at, art));
+        self
+    }
+
+    /// Adds a child node, and return it for use again.
+    pub fn child(mut self, child: Node) -> Node {
+        self.children.push(child);
+        self
+    }
+
+    /// Replaces the node’s transform, such as to animate it.
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    /// Returns the node’s transform.
+    pub fn get_transform(&self) -> Transform {
+        self.transform
+    }
+
+    /// Returns the node’s shapes, for editing.
+    pub fn shapes_mut(&mut self) -> &mut Vec<Shape> {
+        &mut self.shapes
+    }
+
+    /// Returns the node’s children.
+    pub fn children(&self) -> &[Node] {
+        &self.children
+    }
+
+    /// Returns the node’s children, for editing.
+    pub fn children_mut(&mut self) -> &mut Vec<Node> {
+        &mut self.children
+    }
+
+    /// Draws the node and all its descendants onto the `Canvas`, clipped to its given
+    /// dimensions.
+    ///
+    /// Sprites are moved with their node but can’t be turned or stretched, and those that
+    /// would start at negative coordinates are not drawn.
+    pub fn draw(&self, canvas: &mut Canvas) {
+        self.draw_with(canvas, &Transform::identity());
+    }
+
+    fn draw_with(&self, canvas: &mut Canvas, parent: &Transform) {
+        let transform = self.transform.then(parent);
+        for shape in &self.shapes {
+            match *shape {
+                Shape::Point(p) => line(canvas, &transform, p, p),
+                Shape::Line(p1, p2) => line(canvas, &transform, p1, p2),
+                Shape::Polyline(ref points) => {
+                    for pair in points.windows(2) {
+                        line(canvas, &transform, pair[0], pair[1]);
+                    }
+                }
+                Shape::Circle((cx, cy), r) => {
+                    // Enough segments that each is about a pixel long once transformed.
+                    let [a, b, c, d, _, _] = transform.matrix;
+                    let stretch = (a * a + b * b).max(c * c + d * d).sqrt();
+                    let steps = ((r * stretch * f64::consts::PI * 2.0).ceil() as u32).max(8);
+                    let point = |i: u32| {
+                        let t = i as f64 / steps as f64 * f64::consts::PI * 2.0;
+                        (cx + r * t.cos(), cy + r * t.sin())
+                    };
+                    for i in 0..steps {
+                        line(canvas, &transform, point(i), point(i + 1));
+                    }
+                }
+            }
+        }
+        for &(at, ref art) in &self.sprites {
+            let (x, y) = transform.apply(at);
+            let (x, y) = (x.round(), y.round());
+            if x >= 0.0 && y >= 0.0 {
+                art.draw(canvas, x as u32, y as u32);
+            }
+        }
+        for child in &self.children {
+            child.draw_with(canvas, &transform);
+        }
+    }
+}
+
+/// Draws a line between two local points, transformed and clipped to the `Canvas`.
+fn line(canvas: &mut Canvas, transform: &Transform, p1: (f64, f64), p2: (f64, f64)) {
+    let (w, h) = canvas.pixel_extent();
+    let (p1, p2) = (transform.apply(p1), transform.apply(p2));
+    let round = |p: (f64, f64)| (p.0.round(), p.1.round());
+    if let Some(((x1, y1), (x2, y2))) = clip(round(p1), round(p2), w, h) {
+        canvas.line(x1.round() as u32, y1.round() as u32, x2.round() as u32, y2.round() as u32);
+    }
+}
@@ -0,0 +1,95 @@
+use canvas::Canvas;
+
+/// An oscilloscope-style view of audio samples, centred on zero.
+///
+/// The samples are split evenly between the pixel columns. Each column is filled across the
+/// RMS level of its samples, with dots above and below marking their peak, so that both the
+/// loudness and the transients of a signal are visible at any zoom.
+///
+/// ```
+/// use drawille::{Canvas, Scope};
+///
+/// let samples: Vec<f32> = (0..4800).map(|i| (i as f32 / 40.0).sin() * 0.8).collect();
+/// let mut canvas = Canvas::new(160, 40);
+/// Scope::new().db_grid(vec![-6.0, -12.0]).draw(&mut canvas, 0, 0, 160, 40, &samples);
+/// // Every column is filled across the middle by its RMS level, and nothing goes past the
+/// // peaks at 0.8, four pixels in from the top and bottom.
+/// assert!((0..160).all(|x| (16..24).all(|y| canvas.get(x, y))));
+/// assert!((0..160).any(|x| canvas.get(x, 4)) && (0..160).any(|x| canvas.get(x, 35)));
+/// assert!((0..160).all(|x| !canvas.get(x, 3) && !canvas.get(x, 36)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scope {
+    envelope: bool,
+    grid: Vec<f32>,
+}
+
+impl Scope {
+    /// Creates a new `Scope` that shows the peak envelope but no grid.
+    pub fn new() -> Scope {
+        Scope { envelope: true, grid: Vec::new() }
+    }
+
+    /// Shows or hides the peak envelope, and return the `Scope` for use again.
+    pub fn envelope(mut self, envelope: bool) -> Scope {
+        self.envelope = envelope;
+        self
+    }
+
+    /// Draws dotted lines at the given levels in decibels relative to full scale, such as
+    /// `-6.0`, and return the `Scope` for use again.
+    pub fn db_grid(mut self, levels: Vec<f32>) -> Scope {
+        self.grid = levels;
+        self
+    }
+
+    /// Draws samples between -1 and 1 onto the `Canvas`, in a rectangle given by its top-left
+    /// corner and size in pixels. Louder samples are clipped to the rectangle.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, width: u32, height: u32,
+                samples: &[f32]) {
+        if width == 0 || height == 0 {
+            return;
+        }
+        let half = (height - 1) as f32 / 2.0;
+        let centre = y as f32 + half;
+        let pixel = |amplitude: f32| (centre - amplitude.clamp(-1.0, 1.0) * half).round() as u32;
+
+        for &db in &self.grid {
+            let amplitude = 10f32.powf(db / 20.0);
+            for &row in &[pixel(amplitude), pixel(-amplitude)] {
+                for px in (x..x + width).step_by(4) {
+                    canvas.set(px, row);
+                }
+            }
+        }
+
+        if samples.is_empty() {
+            return;
+        }
+        for column in 0..width {
+            let start = column as usize * samples.len() / width as usize;
+            let end = ((column as usize + 1) * samples.len() / width as usize).max(start + 1);
+            let bucket = &samples[start..end.min(samples.len())];
+            if bucket.is_empty() {
+                continue;
+            }
+            let (mut peak, mut sum) = (0f32, 0f32);
+            for &s in bucket {
+                peak = peak.max(s.abs());
+                sum += s * s;
+            }
+            let rms = (sum / bucket.len() as f32).sqrt();
+            canvas.vline(x + column, pixel(rms), pixel(-rms));
+            if self.envelope {
+                canvas.set(x + column, pixel(peak));
+                canvas.set(x + column, pixel(-peak));
+            }
+        }
+    }
+}
+
+impl Default for Scope {
+    fn default() -> Scope {
+        Scope::new()
+    }
+}
@@ -0,0 +1,255 @@
+use std::error::Error;
+use std::fmt;
+
+use turtle::Turtle;
+
+/// How deeply `REPEAT`s may be nested, so that no script can overflow the stack.
+const MAX_DEPTH: usize = 64;
+
+/// An error produced while parsing a turtle script, with the line it was found on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScriptError {
+    /// A word that isn’t a command was found where a command should be.
+    UnknownCommand(usize, String),
+    /// The command with the given name is missing its argument at the end of the script.
+    MissingArgument(usize, String),
+    /// An argument isn’t a finite number, or a repeat count isn’t a whole number.
+    InvalidNumber(usize, String),
+    /// A `REPEAT` has no `[` after its count, or its `[` has no matching `]`.
+    UnclosedRepeat(usize),
+    /// A `]` was found without a `[` to close.
+    UnmatchedBracket(usize),
+    /// `REPEAT`s are nested more deeply than is allowed.
+    TooDeep(usize),
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ScriptError::UnknownCommand(line, ref word) => {
+                write!(f, "unknown command {:?} on line {}", word, line)
+            }
+            ScriptError::MissingArgument(line, ref command) => {
+                write!(f, "{} is missing an argument on line {}", command, line)
+            }
+            ScriptError::InvalidNumber(line, ref word) => {
+                write!(f, "invalid number {:?} on line {}", word, line)
+            }
+            ScriptError::UnclosedRepeat(line) => write!(f, "unclosed REPEAT on line {}", line),
+            ScriptError::UnmatchedBracket(line) => write!(f, "unmatched ] on line {}", line),
+            ScriptError::TooDeep(line) => {
+                write!(f, "REPEATs nested more than {} deep on line {}", MAX_DEPTH, line)
+            }
+        }
+    }
+}
+
+impl Error for ScriptError {}
+
+/// A single command of a turtle script.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    /// `F n`, `FD n` or `FORWARD n`: moves forward `n` steps.
+    Forward(f32),
+    /// `B n`, `BK n` or `BACK n`: moves backward `n` steps.
+    Back(f32),
+    /// `R a`, `RT a` or `RIGHT a`: turns right by `a` degrees.
+    Right(f32),
+    /// `L a`, `LT a` or `LEFT a`: turns left by `a` degrees.
+    Left(f32),
+    /// `PU` or `PENUP`: lifts the brush.
+    PenUp,
+    /// `PD` or `PENDOWN`: puts down the brush.
+    PenDown,
+    /// `T x y`, `TELEPORT x y` or `SETXY x y`: moves straight to a position.
+    Teleport(f32, f32),
+    /// `REPEAT n [ … ]`: runs the commands in brackets `n` times.
+    Repeat(u32, Vec<Command>),
+}
+
+impl Command {
+    /// Runs the command on a `Turtle`.
+    pub fn apply(&self, turtle: &mut Turtle) {
+        match *self {
+            Command::Forward(n) => turtle.forward(n),
+            Command::Back(n) => turtle.back(n),
+            Command::Right(a) => turtle.right(a),
+            Command::Left(a) => turtle.left(a),
+            Command::PenUp => turtle.up(),
+            Command::PenDown => turtle.down(),
+            Command::Teleport(x, y) => turtle.teleport(x, y),
+            Command::Repeat(n, ref commands) => {
+                for _ in 0..n {
+                    for command in commands {
+                        command.apply(turtle);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Writes the command in the form `Script::parse` reads, with its shortest name.
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Command::Forward(n) => write!(f, "F {}", n),
+            Command::Back(n) => write!(f, "B {}", n),
+            Command::Right(a) => write!(f, "R {}", a),
+            Command::Left(a) => write!(f, "L {}", a),
+            Command::PenUp => write!(f, "PU"),
+            Command::PenDown => write!(f, "PD"),
+            Command::Teleport(x, y) => write!(f, "T {} {}", x, y),
+            Command::Repeat(n, ref commands) => {
+                write!(f, "REPEAT {} [", n)?;
+                for (i, command) in commands.iter().enumerate() {
+                    write!(f, "{}{}", if i == 0 { "" } else { "; " }, command)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// A parsed turtle script: a list of `Command`s, such as a drawing loaded from a config file.
+///
+/// Commands are separated by semicolons, newlines or just spaces, names are case-insensitive,
+/// and a `#` starts a comment that runs to the end of the line.
+///
+/// ```
+/// use drawille::{Script, Turtle};
+///
+/// let script = Script::parse("PU; T 2 2; PD\nREPEAT 4 [F 10; R 90]  # a square").unwrap();
+/// let mut turtle = Turtle::new(0.0, 0.0);
+/// script.run(&mut turtle);
+/// assert_eq!(turtle.frame(), [" ⡤⠤⠤⠤⠤⡄",
+///                             " ⡇    ⡇",
+///                             " ⡇    ⡇",
+///                             " ⠉⠉⠉⠉⠉⠁"].join("\n"));
+/// assert_eq!(script.to_string(), "PU; T 2 2; PD; REPEAT 4 [F 10; R 90]");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Script {
+    commands: Vec<Command>,
+}
+
+impl Script {
+    /// Creates a new `Script` of the given commands.
+    pub fn new(commands: Vec<Command>) -> Script {
+        Script { commands }
+    }
+
+    /// Parses a script.
+    pub fn parse(source: &str) -> Result<Script, ScriptError> {
+        let mut tokens = tokenize(source).into_iter();
+        let commands = parse_block(&mut tokens, 0, None)?;
+        Ok(Script { commands })
+    }
+
+    /// Returns the commands of the script.
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// Runs every command of the script on a `Turtle`, in order.
+    pub fn run(&self, turtle: &mut Turtle) {
+        for command in &self.commands {
+            command.apply(turtle);
+        }
+    }
+}
+
+/// Writes the script in the form `parse` reads, on one line.
+impl fmt::Display for Script {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, command) in self.commands.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { "" } else { "; " }, command)?;
+        }
+        Ok(())
+    }
+}
+
+type Tokens<'a> = ::std::vec::IntoIter<(usize, &'a str)>;
+
+/// Splits a script into words and brackets, each with the line it is on.
+fn tokenize(source: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    for (i, line) in source.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("");
+        let mut start = None;
+        for (j, c) in line.char_indices() {
+            let separator = c.is_whitespace() || c == ';' || c == '[' || c == ']';
+            if separator {
+                if let Some(s) = start.take() {
+                    tokens.push((i + 1, &line[s..j]));
+                }
+                if c == '[' || c == ']' {
+                    tokens.push((i + 1, &line[j..j + 1]));
+                }
+            } else if start.is_none() {
+                start = Some(j);
+            }
+        }
+        if let Some(s) = start {
+            tokens.push((i + 1, &line[s..]));
+        }
+    }
+    tokens
+}
+
+/// Parses commands up to the end of the script, or up to the `]` closing the `REPEAT` on the
+/// line `opened`.
+fn parse_block(tokens: &mut Tokens, depth: usize, opened: Option<usize>)
+    -> Result<Vec<Command>, ScriptError>
+{
+    let mut commands = Vec::new();
+    while let Some((line, word)) = tokens.next() {
+        let name = word.to_ascii_uppercase();
+        let mut number = || -> Result<f32, ScriptError> {
+            match tokens.next() {
+                Some((line, arg)) => match arg.parse::<f32>() {
+                    Ok(n) if n.is_finite() => Ok(n),
+                    _ => Err(ScriptError::InvalidNumber(line, arg.to_string())),
+                },
+                None => Err(ScriptError::MissingArgument(line, name.clone())),
+            }
+        };
+        let command = match &name[..] {
+            "F" | "FD" | "FORWARD" => Command::Forward(number()?),
+            "B" | "BK" | "BACK" => Command::Back(number()?),
+            "R" | "RT" | "RIGHT" => Command::Right(number()?),
+            "L" | "LT" | "LEFT" => Command::Left(number()?),
+            "PU" | "PENUP" => Command::PenUp,
+            "PD" | "PENDOWN" => Command::PenDown,
+            "T" | "TELEPORT" | "SETXY" => {
+                let x = number()?;
+                Command::Teleport(x, number()?)
+            }
+            "REPEAT" => {
+                let count = match tokens.next() {
+                    Some((line, arg)) => arg.parse::<u32>()
+                        .map_err(|_| ScriptError::InvalidNumber(line, arg.to_string()))?,
+                    None => return Err(ScriptError::MissingArgument(line, name)),
+                };
+                match tokens.next() {
+                    Some((_, "[")) => {}
+                    _ => return Err(ScriptError::UnclosedRepeat(line)),
+                }
+                if depth + 1 >= MAX_DEPTH {
+                    return Err(ScriptError::TooDeep(line));
+                }
+                Command::Repeat(count, parse_block(tokens, depth + 1, Some(line))?)
+            }
+            "]" => match opened {
+                Some(_) => return Ok(commands),
+                None => return Err(ScriptError::UnmatchedBracket(line)),
+            },
+            _ => return Err(ScriptError::UnknownCommand(line, word.to_string())),
+        };
+        commands.push(command);
+    }
+    match opened {
+        Some(line) => Err(ScriptError::UnclosedRepeat(line)),
+        None => Ok(commands),
+    }
+}
@@ -0,0 +1,141 @@
+use std::cmp;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use canvas::{line_points, Canvas};
+use style::Style;
+
+/// A `Canvas` that many threads can draw onto at once.
+///
+/// The canvas is split into horizontal bands, each behind its own lock, so threads drawing in
+/// different bands never wait for each other. Any thread can take a `snapshot` to render.
+///
+/// ```
+/// use std::thread;
+/// use drawille::SharedCanvas;
+///
+/// let canvas = SharedCanvas::new(40, 32, 4);
+/// thread::scope(|s| {
+///     for i in 0..8 {
+///         let canvas = &canvas;
+///         s.spawn(move || canvas.line(0, i * 4, 39, i * 4));
+///     }
+/// });
+/// assert!(canvas.snapshot().get(39, 28));
+/// ```
+#[derive(Debug)]
+pub struct SharedCanvas {
+    bands: Vec<Mutex<Canvas>>,
+    band_height: u32,
+    width: u32,
+    height: u32,
+}
+
+impl SharedCanvas {
+    /// Creates a new `SharedCanvas` with the given width and height, split into `bands` bands
+    /// of equal height.
+    ///
+    /// Each band is a whole number of rows of cells, so there may be fewer bands than asked for
+    /// on short canvases. Pixels below the given height fall into the last band.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bands` is zero.
+    pub fn new(width: u32, height: u32, bands: u32) -> SharedCanvas {
+        assert!(bands > 0, "a SharedCanvas needs at least one band");
+        let rows = height.div_ceil(4).max(1);
+        let band_height = rows.div_ceil(bands) * 4;
+        let count = rows.div_ceil(band_height / 4);
+        let bands = (0..count)
+            .map(|i| {
+                let top = i * band_height;
+                Mutex::new(Canvas::new(width, cmp::min(band_height, height.saturating_sub(top))))
+            })
+            .collect();
+        SharedCanvas { bands, band_height, width, height }
+    }
+
+    /// Returns the number of bands the canvas is split into.
+    pub fn bands(&self) -> usize {
+        self.bands.len()
+    }
+
+    /// Returns the height of each band, in pixels.
+    pub fn band_height(&self) -> u32 {
+        self.band_height
+    }
+
+    /// Returns the index of the band holding the pixel row `y`, and the row within it.
+    fn locate(&self, y: u32) -> (usize, u32) {
+        let band = cmp::min((y / self.band_height) as usize, self.bands.len() - 1);
+        (band, y - band as u32 * self.band_height)
+    }
+
+    /// Locks a band, ignoring poisoning since a half-drawn shape leaves the canvas valid.
+    fn lock(&self, band: usize) -> MutexGuard<'_, Canvas> {
+        self.bands[band].lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Sets a pixel at the specified coordinates.
+    pub fn set(&self, x: u32, y: u32) {
+        let (band, y) = self.locate(y);
+        self.lock(band).set(x, y);
+    }
+
+    /// Sets a pixel at the specified coordinates, and gives its cell the provided style.
+    pub fn set_styled(&self, x: u32, y: u32, style: Style) {
+        let (band, y) = self.locate(y);
+        self.lock(band).set_styled(x, y, style);
+    }
+
+    /// Deletes a pixel at the specified coordinates.
+    pub fn unset(&self, x: u32, y: u32) {
+        let (band, y) = self.locate(y);
+        self.lock(band).unset(x, y);
+    }
+
+    /// Draws a line from `(x1, y1)` to `(x2, y2)`, exactly as `Canvas::line` would.
+    ///
+    /// Each band the line crosses is locked once, rather than once per pixel.
+    pub fn line(&self, x1: u32, y1: u32, x2: u32, y2: u32) {
+        let mut current: Option<(usize, MutexGuard<'_, Canvas>)> = None;
+        for (x, y) in line_points(x1, y1, x2, y2) {
+            let (band, y) = self.locate(y);
+            match current {
+                Some((locked, ref mut canvas)) if locked == band => canvas.set(x, y),
+                _ => {
+                    // Release the previous band before waiting on the next.
+                    drop(current.take());
+                    let mut canvas = self.lock(band);
+                    canvas.set(x, y);
+                    current = Some((band, canvas));
+                }
+            }
+        }
+    }
+
+    /// Clears every band.
+    pub fn clear(&self) {
+        for band in 0..self.bands.len() {
+            self.lock(band).clear();
+        }
+    }
+
+    /// Copies the current contents into a single `Canvas`.
+    ///
+    /// Bands are copied one at a time, so drawing can carry on in the others meanwhile. Each
+    /// band is consistent, but a shape drawn across several while the snapshot is taken may
+    /// be caught half-drawn.
+    pub fn snapshot(&self) -> Canvas {
+        let mut canvas = Canvas::new(self.width, self.height);
+        for band in 0..self.bands.len() {
+            let rows = (band as u32 * self.band_height / 4) as u16;
+            canvas.paste_rows(&self.lock(band), rows);
+        }
+        canvas
+    }
+
+    /// Renders a snapshot of the current contents, as `Canvas::frame` would.
+    pub fn frame(&self) -> String {
+        self.snapshot().frame()
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::Write;
+
+use canvas::Canvas;
+use style::Color;
+
+/// The colour of pixels in cells without a foreground colour of their own.
+const DEFAULT_COLOR: Color = Color::White;
+
+impl Canvas {
+    /// Returns the colour of every pixel of the `Canvas` that is set, row by row, with each pixel
+    /// of the dot grid enlarged to a `scale`×`scale` square of image pixels, along with the
+    /// image’s width and height.
+    ///
+    /// Pixels take the foreground colour of their cell, or `default` if it has none. Letters
+    /// aren’t rasterised.
+    pub(crate) fn rasterize(&self, scale: u32, default: Color) -> (Vec<Option<Color>>, u32, u32) {
+        let scale = scale.max(1);
+        let (columns, rows) = self.extents();
+        let (width, height) = (columns * 2 * scale, rows * 4 * scale);
+        // Index in `usize`, as the number of image pixels can be more than a `u32` holds.
+        let mut image = vec![None; width as usize * height as usize];
+        for (x, y) in self.screen_pixels() {
+            let (_, style) = self.cell((x / 2) as u16, (y / 4) as u16);
+            let color = style.fg.unwrap_or(default);
+            for dy in 0..scale {
+                let row = (y * scale + dy) as usize * width as usize;
+                for dx in 0..scale {
+                    image[row + (x * scale + dx) as usize] = Some(color);
+                }
+            }
+        }
+        (image, width, height)
+    }
+
+    /// Renders the `Canvas` as Sixel graphics, for terminals such as xterm, mlterm and WezTerm
+    /// that can show them, with each pixel drawn as a `scale`×`scale` square.
+    ///
+    /// Unset pixels are transparent, and set ones take the foreground colour of their cell, or
+    /// white if it has none. Letters aren’t drawn.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(2, 4);
+    /// canvas.set(0, 0);
+    /// let sixel = canvas.to_sixel(1);
+    /// assert!(sixel.starts_with("\x1bP0;1;0q\"1;1;4;8"));
+    /// assert!(sixel.ends_with("\x1b\\"));
+    /// ```
+    pub fn to_sixel(&self, scale: u32) -> String {
+        let (image, width, height) = self.rasterize(scale, DEFAULT_COLOR);
+        let mut palette: Vec<Color> = Vec::new();
+        for color in image.iter().flatten() {
+            if !palette.contains(color) {
+                palette.push(*color);
+            }
+        }
+
+        let mut out = String::new();
+        // Transparent background, with square pixels.
+        let _ = write!(out, "\x1bP0;1;0q\"1;1;{};{}", width, height);
+        for (i, color) in palette.iter().enumerate() {
+            let (r, g, b) = color.to_rgb();
+            let percent = |v: u8| (v as u32 * 100 + 127) / 255;
+            let _ = write!(out, "#{};2;{};{};{}", i, percent(r), percent(g), percent(b));
+        }
+        let mut sixels = vec![0u8; width as usize];
+        for band in 0..height.div_ceil(6) {
+            let mut first = true;
+            for (i, color) in palette.iter().enumerate() {
+                let mut used = false;
+                for (x, sixel) in sixels.iter_mut().enumerate() {
+                    *sixel = 0;
+                    for bit in 0..6 {
+                        let y = band * 6 + bit;
+                        if y < height && image[y as usize * width as usize + x] == Some(*color) {
+                            *sixel |= 1 << bit;
+                            used = true;
+                        }
+                    }
+                }
+                if !used {
+                    continue;
+                }
+                if !first {
+                    // Return to the start of the band to overlay the next colour.
+                    out.push('$');
+                }
+                first = false;
+                let _ = write!(out, "#{}", i);
+                write_run_length(&mut out, &sixels);
+            }
+            out.push('-');
+        }
+        out.push_str("\x1b\\");
+        out
+    }
+}
+
+/// Writes sixels as characters, compressing repeats with `!count`.
+fn write_run_length(out: &mut String, sixels: &[u8]) {
+    // Trailing empty sixels don’t need drawing.
+    let end = sixels.iter().rposition(|&s| s != 0).map_or(0, |i| i + 1);
+    let mut i = 0;
+    while i < end {
+        let run = sixels[i..end].iter().take_while(|&&s| s == sixels[i]).count();
+        let c = (63 + sixels[i]) as char;
+        if run > 3 {
+            let _ = write!(out, "!{}{}", run, c);
+        } else {
+            for _ in 0..run {
+                out.push(c);
+            }
+        }
+        i += run;
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::VecDeque;
+
+use canvas::Canvas;
+use dither::Dither;
+use style::{Color, Style};
+
+/// The colours of increasingly strong cells, when colour is enabled.
+static HEAT: [Color; 5] = [Color::Blue, Color::Cyan, Color::Green, Color::Yellow, Color::Red];
+
+/// A scrolling spectrogram: each pushed frame of magnitudes becomes a pixel column, with the
+/// oldest dropping off the left once the history is full.
+///
+/// Magnitudes are shown as dot density using an ordered dither, and optionally as colour.
+///
+/// ```
+/// use drawille::{Canvas, Spectrogram};
+///
+/// let mut spectrogram = Spectrogram::new(100).colored(true);
+/// for t in 0..150 {
+///     let frame: Vec<f32> = (0..64).map(|bin| {
+///         if (bin as i32 - (t / 3) as i32).abs() < 3 { 1.0 } else { 0.1 }
+///     }).collect();
+///     spectrogram.push(&frame);
+/// }
+/// assert_eq!(spectrogram.len(), 100);
+///
+/// let mut canvas = Canvas::new(100, 32);
+/// spectrogram.draw(&mut canvas, 0, 0, 32);
+/// // The tone rises over time, from a third of the way up the oldest column on the left to two
+/// // thirds of the way up the newest on the right, over quiet bins dithered sparsely.
+/// assert!((22..=24).all(|y| canvas.get(0, y)) && (6..=8).all(|y| canvas.get(99, y)));
+/// assert!((14..=16).all(|y| canvas.get(50, y)));
+/// assert!((0..32).filter(|&y| canvas.get(50, y)).count() < 16);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spectrogram {
+    history: usize,
+    columns: VecDeque<Vec<f32>>,
+    range: (f32, f32),
+    colored: bool,
+}
+
+impl Spectrogram {
+    /// Creates a new, empty `Spectrogram` keeping the last `history` frames, for magnitudes
+    /// between 0 and 1.
+    pub fn new(history: usize) -> Spectrogram {
+        Spectrogram {
+            history,
+            columns: VecDeque::with_capacity(history),
+            range: (0.0, 1.0),
+            colored: false,
+        }
+    }
+
+    /// Sets the magnitudes shown as empty and full, and return the `Spectrogram` for use again.
+    ///
+    /// Passing magnitudes in decibels with a range such as `(-80.0, 0.0)` often shows more
+    /// detail than linear ones.
+    pub fn range(mut self, min: f32, max: f32) -> Spectrogram {
+        self.range = (min, max);
+        self
+    }
+
+    /// Colours each cell by its average magnitude as well, and return the `Spectrogram` for use
+    /// again.
+    pub fn colored(mut self, colored: bool) -> Spectrogram {
+        self.colored = colored;
+        self
+    }
+
+    /// Adds a frame of magnitudes, from the lowest frequency bin to the highest.
+    pub fn push(&mut self, magnitudes: &[f32]) {
+        if self.history == 0 {
+            return;
+        }
+        if self.columns.len() == self.history {
+            self.columns.pop_front();
+        }
+        self.columns.push_back(magnitudes.to_vec());
+    }
+
+    /// Returns the number of frames in the history.
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Detects whether no frames have been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Removes every frame from the history.
+    pub fn clear(&mut self) {
+        self.columns.clear();
+    }
+
+    /// Draws the history onto the `Canvas`, one pixel column per frame starting from the oldest
+    /// at `x`, with low frequencies at the bottom of `height` pixels from `y`.
+    ///
+    /// Each pixel row shows the loudest of the bins that fall within it.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, height: u32) {
+        if height == 0 {
+            return;
+        }
+        let (min, max) = self.range;
+        let level = |m: f32| {
+            if max > min { ((m - min) / (max - min)).clamp(0.0, 1.0) } else { 0.0 }
+        };
+        for (i, column) in self.columns.iter().enumerate() {
+            if column.is_empty() {
+                continue;
+            }
+            let px = x + i as u32;
+            for row in 0..height {
+                // Row 0 is the top, so it holds the highest bins.
+                let from = (height - 1 - row) as usize * column.len() / height as usize;
+                let to = ((height - row) as usize * column.len() / height as usize).max(from + 1);
+                let value = column[from..to.min(column.len())].iter().cloned()
+                    .fold(0.0, |v: f32, m| v.max(level(m)));
+                let py = y + row;
+                if value as f64 > Dither::Bayer4.threshold(px, py) {
+                    canvas.set(px, py);
+                }
+            }
+        }
+        if self.colored {
+            self.color_cells(canvas, x, y, height, &level);
+        }
+    }
+
+    /// Colours every cell drawn by `draw` by the average level of its pixels.
+    fn color_cells<F: Fn(f32) -> f32>(&self, canvas: &mut Canvas, x: u32, y: u32, height: u32,
+                                      level: &F) {
+        let width = self.columns.len() as u32;
+        for cy in (y / 4)..=((y + height - 1) / 4) {
+            for cx in (x / 2)..=((x + width.max(1) - 1) / 2) {
+                let (mut sum, mut n) = (0.0, 0);
+                for py in (cy * 4).max(y)..(cy * 4 + 4).min(y + height) {
+                    for px in (cx * 2).max(x)..(cx * 2 + 2).min(x + width) {
+                        let column = &self.columns[(px - x) as usize];
+                        if column.is_empty() {
+                            continue;
+                        }
+                        let row = height - 1 - (py - y);
+                        let bin = row as usize * column.len() / height as usize;
+                        sum += level(column[bin]);
+                        n += 1;
+                    }
+                }
+                if n > 0 && sum > 0.0 {
+                    let heat = ((sum / n as f32) * HEAT.len() as f32) as usize;
+                    let color = HEAT[heat.min(HEAT.len() - 1)];
+                    canvas.set_cell_style(cx * 2, cy * 4, Style::new().fg(color));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use std::vec;
+
+use futures_core::Stream;
+use futures_io::AsyncWrite;
+use futures_sink::Sink;
+
+use animation::Animation;
+
+/// A `Stream` of the frames of an `Animation`, each with how long it is shown for.
+///
+/// Frames are always ready, so pacing them is up to the consumer, such as by waiting out each
+/// duration on a timer of its runtime.
+#[derive(Debug)]
+pub struct FrameStream {
+    frames: vec::IntoIter<(String, Duration)>,
+}
+
+impl Animation {
+    /// Turns the `Animation` into a `Stream` of its frames.
+    ///
+    /// ```
+    /// extern crate drawille;
+    /// extern crate futures_core;
+    ///
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll, Waker};
+    /// use std::time::Duration;
+    /// use drawille::{Animation, Canvas};
+    /// use futures_core::Stream;
+    ///
+    /// # fn main() {
+    /// let mut animation = Animation::new();
+    /// animation.push(&Canvas::new(2, 4), Duration::from_millis(40));
+    /// let mut stream = animation.into_stream();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// match Pin::new(&mut stream).poll_next(&mut cx) {
+    ///     Poll::Ready(Some((_, duration))) => assert_eq!(duration, Duration::from_millis(40)),
+    ///     _ => unreachable!(),
+    /// }
+    /// assert!(Pin::new(&mut stream).poll_next(&mut cx) == Poll::Ready(None));
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> FrameStream {
+        FrameStream { frames: self.frames.into_iter() }
+    }
+}
+
+impl Stream for FrameStream {
+    type Item = (String, Duration);
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.frames.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.frames.size_hint()
+    }
+}
+
+/// A `Sink` that writes frames, such as those from `Canvas::frame`, to an `AsyncWrite` without
+/// blocking.
+///
+/// Each frame is drawn from the top-left corner of the screen, over the one before it. Only
+/// one frame is buffered at a time, so sending waits for the previous one to be written.
+///
+/// ```
+/// extern crate drawille;
+/// extern crate futures_sink;
+///
+/// use std::pin::Pin;
+/// use std::task::{Context, Waker};
+/// use drawille::{Canvas, FrameSink};
+/// use futures_sink::Sink;
+///
+/// # fn main() {
+/// let mut canvas = Canvas::new(2, 4);
+/// canvas.set(0, 0);
+/// let mut sink = FrameSink::new(Vec::new());
+/// let mut cx = Context::from_waker(Waker::noop());
+/// Pin::new(&mut sink).start_send(canvas.frame()).unwrap();
+/// assert!(Pin::new(&mut sink).poll_flush(&mut cx).is_ready());
+/// assert_eq!(sink.into_inner(), "\x1b[H⠁ \n  ".as_bytes());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FrameSink<W> {
+    writer: W,
+    buffer: Vec<u8>,
+    written: usize,
+}
+
+impl<W> FrameSink<W> {
+    /// Creates a new `FrameSink` writing to `writer`.
+    pub fn new(writer: W) -> FrameSink<W> {
+        FrameSink { writer, buffer: Vec::new(), written: 0 }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Unwraps the underlying writer, dropping any frame not yet written.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: AsyncWrite + Unpin> FrameSink<W> {
+    /// Writes as much of the buffered frame as the writer will take.
+    fn poll_write_buffer(&mut self, cx: &mut Context) -> Poll<io::Result<()>> {
+        while self.written < self.buffer.len() {
+            let n = match Pin::new(&mut self.writer).poll_write(cx, &self.buffer[self.written..]) {
+                Poll::Ready(Ok(n)) => n,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            };
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.written += n;
+        }
+        self.buffer.clear();
+        self.written = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> Sink<String> for FrameSink<W> {
+    type Error = io::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        self.get_mut().poll_write_buffer(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, frame: String) -> io::Result<()> {
+        let sink = self.get_mut();
+        sink.buffer.extend_from_slice(b"\x1b[H");
+        sink.buffer.extend_from_slice(frame.as_bytes());
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        let sink = self.get_mut();
+        match sink.poll_write_buffer(cx) {
+            Poll::Ready(Ok(())) => Pin::new(&mut sink.writer).poll_flush(cx),
+            other => other,
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        let sink = self.get_mut();
+        match sink.poll_write_buffer(cx) {
+            Poll::Ready(Ok(())) => Pin::new(&mut sink.writer).poll_close(cx),
+            other => other,
+        }
+    }
+}
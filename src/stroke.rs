@@ -0,0 +1,167 @@
+use canvas::{line_points, Canvas};
+use style::{Color, Style};
+
+/// How a line is stroked: its dash pattern and the style of the cells it passes through.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LineStyle {
+    on: u32,
+    off: u32,
+    phase: u32,
+    style: Style,
+}
+
+impl LineStyle {
+    /// Creates a new solid, unstyled `LineStyle`.
+    pub fn solid() -> LineStyle {
+        LineStyle::pattern(1, 0)
+    }
+
+    /// Creates a new dashed `LineStyle`, with dashes of 4 pixels and gaps of 2.
+    pub fn dashed() -> LineStyle {
+        LineStyle::pattern(4, 2)
+    }
+
+    /// Creates a new dotted `LineStyle`, setting every other pixel.
+    pub fn dotted() -> LineStyle {
+        LineStyle::pattern(1, 1)
+    }
+
+    /// Creates a new `LineStyle` that repeatedly sets `on` pixels and then skips `off` pixels.
+    pub fn pattern(on: u32, off: u32) -> LineStyle {
+        LineStyle { on: on.max(1), off, phase: 0, style: Style::default() }
+    }
+
+    /// Sets the colour of a `LineStyle`, and returns it for use again.
+    pub fn color(mut self, color: Color) -> LineStyle {
+        self.style.fg = Some(color);
+        self
+    }
+
+    /// Sets the cell style of a `LineStyle`, and returns it for use again.
+    pub fn style(mut self, style: Style) -> LineStyle {
+        self.style = style;
+        self
+    }
+
+    /// Sets how many pixels into the dash pattern a `LineStyle` starts, and returns it for use
+    /// again.
+    ///
+    /// Advancing the phase by one every frame makes the dashes march along the line, such as
+    /// round a selection rectangle.
+    ///
+    /// ```
+    /// use drawille::{Canvas, LineStyle};
+    ///
+    /// let mut canvas = Canvas::new(8, 4);
+    /// canvas.line_styled(0, 0, 7, 0, &LineStyle::dotted().phase(1));
+    /// assert_eq!(canvas.rows()[0], "⠈⠈⠈⠈ ");
+    /// ```
+    pub fn phase(mut self, phase: u32) -> LineStyle {
+        self.phase = phase;
+        self
+    }
+
+    /// Returns how many pixels into the dash pattern the line starts.
+    pub fn dash_phase(&self) -> u32 {
+        self.phase
+    }
+
+    /// Returns the style given to the cells the line passes through.
+    pub fn cell_style(&self) -> Style {
+        self.style
+    }
+
+    /// Returns the lengths of the dashes and of the gaps between them.
+    pub fn dash(&self) -> (u32, u32) {
+        (self.on, self.off)
+    }
+
+    /// Detects whether the pixel `n` steps along the line is part of a dash.
+    fn is_on(&self, n: u32) -> bool {
+        let period = self.on + self.off;
+        // Subtract the phase, so that the pattern moves forwards as the phase grows.
+        self.off == 0 || (n % period + period - self.phase % period) % period < self.on
+    }
+}
+
+impl Default for LineStyle {
+    fn default() -> LineStyle {
+        LineStyle::solid()
+    }
+}
+
+impl Canvas {
+    /// Sets a pixel at the specified coordinates, and gives its cell the provided style.
+    ///
+    /// A cell has a single style, so this recolours any other pixels in the same cell too.
+    pub fn set_styled(&mut self, x: u32, y: u32, style: Style) {
+        self.set(x, y);
+        self.set_cell_style(x, y, style);
+    }
+
+    /// Draws a line from `(x1, y1)` to `(x2, y2)` onto the `Canvas`, with the given dash pattern
+    /// and style.
+    ///
+    /// ```
+    /// use drawille::{Canvas, LineStyle};
+    ///
+    /// let mut canvas = Canvas::new(8, 4);
+    /// canvas.line_styled(0, 0, 7, 0, &LineStyle::dotted());
+    /// assert_eq!(canvas.rows()[0], "⠁⠁⠁⠁ ");
+    /// ```
+    pub fn line_styled(&mut self, x1: u32, y1: u32, x2: u32, y2: u32, style: &LineStyle) {
+        self.stroke(x1, y1, x2, y2, style, 0);
+    }
+
+    /// Draws lines joining each point to the next onto the `Canvas`, with the dash pattern
+    /// carrying on from one line to the next.
+    pub fn polyline_styled(&mut self, points: &[(u32, u32)], style: &LineStyle) {
+        let mut offset = 0;
+        for pair in points.windows(2) {
+            let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+            offset = self.stroke(x1, y1, x2, y2, style, offset);
+        }
+    }
+
+    /// Draws the outline of a rectangle with its top-left corner at `(x, y)`, with the dash
+    /// pattern running clockwise round it without a break at the corners.
+    ///
+    /// ```
+    /// use drawille::{Canvas, LineStyle};
+    ///
+    /// let mut canvas = Canvas::new(0, 0);
+    /// for frame in 0..3 {
+    ///     canvas.clear();
+    ///     canvas.rect_styled(0, 0, 10, 8, &LineStyle::dashed().phase(frame));
+    /// }
+    /// assert!(!canvas.get(0, 0) && canvas.get(2, 0));
+    /// ```
+    pub fn rect_styled(&mut self, x: u32, y: u32, width: u32, height: u32, style: &LineStyle) {
+        if width == 0 || height == 0 {
+            return;
+        }
+        // Whatever runs past the largest coordinate is left out.
+        let (x2, y2) = (x.saturating_add(width - 1), y.saturating_add(height - 1));
+        if y2 == y {
+            self.line_styled(x, y, x2, y, style);
+        } else {
+            // Stop short of the starting corner, so that it isn’t drawn twice.
+            self.polyline_styled(&[(x, y), (x2, y), (x2, y2), (x, y2), (x, y + 1)], style);
+        }
+    }
+
+    /// Draws a styled line, starting `offset` pixels into the dash pattern, and returns the
+    /// offset at which the next line of a polyline should continue.
+    pub(crate) fn stroke(&mut self, x1: u32, y1: u32, x2: u32, y2: u32, style: &LineStyle,
+                         offset: u32) -> u32 {
+        let mut n = offset;
+        for (x, y) in line_points(x1, y1, x2, y2) {
+            if style.is_on(n) {
+                self.set_styled(x, y, style.style);
+            }
+            n = n.wrapping_add(1);
+        }
+        // The last pixel is shared with the start of the next line.
+        n.wrapping_sub(1)
+    }
+}
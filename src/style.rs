@@ -0,0 +1,176 @@
+use std::fmt::Write;
+
+/// A terminal colour, rendered using ANSI escape sequences.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    /// A colour from the 256-colour xterm palette.
+    Ansi256(u8),
+    /// A 24-bit ‘true colour’.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Returns the red, green and blue components of the colour, using xterm’s default palette
+    /// for the named and 256-colour ones.
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        const BASIC: [(u8, u8, u8); 16] = [
+            (0, 0, 0), (205, 0, 0), (0, 205, 0), (205, 205, 0),
+            (0, 0, 238), (205, 0, 205), (0, 205, 205), (229, 229, 229),
+            (127, 127, 127), (255, 0, 0), (0, 255, 0), (255, 255, 0),
+            (92, 92, 255), (255, 0, 255), (0, 255, 255), (255, 255, 255),
+        ];
+        match *self {
+            Color::Black => BASIC[0],
+            Color::Red => BASIC[1],
+            Color::Green => BASIC[2],
+            Color::Yellow => BASIC[3],
+            Color::Blue => BASIC[4],
+            Color::Magenta => BASIC[5],
+            Color::Cyan => BASIC[6],
+            Color::White => BASIC[7],
+            Color::Ansi256(n) if n < 16 => BASIC[n as usize],
+            Color::Ansi256(n) if n < 232 => {
+                // A 6×6×6 cube, whose levels are 0 and then 95 to 255 in steps of 40.
+                let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+                let n = n - 16;
+                (level(n / 36), level(n / 6 % 6), level(n % 6))
+            }
+            Color::Ansi256(n) => {
+                let grey = 8 + (n - 232) * 10;
+                (grey, grey, grey)
+            }
+            Color::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    fn write_sgr(&self, base: u8, out: &mut String) {
+        let _ = match *self {
+            Color::Black => write!(out, "{}", base),
+            Color::Red => write!(out, "{}", base + 1),
+            Color::Green => write!(out, "{}", base + 2),
+            Color::Yellow => write!(out, "{}", base + 3),
+            Color::Blue => write!(out, "{}", base + 4),
+            Color::Magenta => write!(out, "{}", base + 5),
+            Color::Cyan => write!(out, "{}", base + 6),
+            Color::White => write!(out, "{}", base + 7),
+            Color::Ansi256(n) => write!(out, "{};5;{}", base + 8, n),
+            Color::Rgb(r, g, b) => write!(out, "{};2;{};{};{}", base + 8, r, g, b),
+        };
+    }
+}
+
+/// The colour and attributes with which a cell of a `Canvas` is rendered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+    pub underline: bool,
+}
+
+impl Style {
+    /// Creates a new, plain `Style`.
+    pub fn new() -> Style {
+        Style::default()
+    }
+
+    /// Sets the foreground colour of a `Style`, and returns it for use again.
+    pub fn fg(mut self, color: Color) -> Style {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Sets the background colour of a `Style`, and returns it for use again.
+    pub fn bg(mut self, color: Color) -> Style {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Makes a `Style` bold, and returns it for use again.
+    pub fn bold(mut self) -> Style {
+        self.bold = true;
+        self
+    }
+
+    /// Makes a `Style` underlined, and returns it for use again.
+    pub fn underline(mut self) -> Style {
+        self.underline = true;
+        self
+    }
+
+    /// Detects whether the `Style` renders exactly like unstyled text.
+    pub fn is_plain(&self) -> bool {
+        *self == Style::default()
+    }
+
+    /// Appends the SGR escape sequence that switches the terminal to this style.
+    pub(crate) fn write_sgr(&self, out: &mut String) {
+        out.push_str("\x1b[");
+        let mut first = true;
+        let mut sep = |out: &mut String| {
+            if !first {
+                out.push(';');
+            }
+            first = false;
+        };
+        if self.bold {
+            sep(out);
+            out.push('1');
+        }
+        if self.underline {
+            sep(out);
+            out.push('4');
+        }
+        if let Some(fg) = self.fg {
+            sep(out);
+            fg.write_sgr(30, out);
+        }
+        if let Some(bg) = self.bg {
+            sep(out);
+            bg.write_sgr(40, out);
+        }
+        out.push('m');
+    }
+}
+
+/// A single character drawn with a `Style`, such as a cell of a `Canvas`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StyledCell {
+    pub c: char,
+    pub style: Style,
+}
+
+impl StyledCell {
+    /// Creates a new `StyledCell` of the given character and style.
+    pub fn new(c: char, style: Style) -> StyledCell {
+        StyledCell { c, style }
+    }
+}
+
+/// A run of text drawn with a single `Style`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span<'a> {
+    pub text: &'a str,
+    pub style: Style,
+}
+
+impl<'a> Span<'a> {
+    /// Creates a new `Span` of the given text and style.
+    pub fn new(text: &'a str, style: Style) -> Span<'a> {
+        Span { text, style }
+    }
+}
+
+impl<'a> From<&'a str> for Span<'a> {
+    fn from(text: &'a str) -> Span<'a> {
+        Span::new(text, Style::default())
+    }
+}
@@ -0,0 +1,316 @@
+use std::f64;
+
+use canvas::Canvas;
+use dither::Density;
+
+/// How the faces of a `Surface` are shaded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Shading {
+    /// Denser the higher the face, like a relief map.
+    Height,
+    /// Denser the more a face turns towards a light above and to the left of the viewer, which
+    /// shows the shape of the surface best.
+    Normal,
+}
+
+/// A 3D surface plot of a height map, `z = f(x, y)` sampled over a grid, seen from above at an
+/// angle.
+///
+/// Each square of the grid is drawn as two triangles, shaded by a density of dots, with a
+/// depth buffer so that nearer parts of the surface hide those behind them. Grid lines can be
+/// drawn over the faces too, hidden in the same way, which helps coarse grids but crowds out
+/// the shading of fine ones.
+///
+/// ```
+/// use drawille::{Canvas, Surface};
+///
+/// // A ripple spreading from the middle of a 25×25 grid.
+/// let surface = Surface::from_fn(25, 25, |x, y| {
+///     let r = ((x - 12.0).powi(2) + (y - 12.0).powi(2)).sqrt();
+///     (r / 2.0).cos() / (1.0 + r / 4.0)
+/// });
+/// let mut canvas = Canvas::new(0, 0);
+/// surface.draw(&mut canvas, 0, 0, 120, 80);
+/// assert!(canvas.pixels().count() > 1000);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Surface {
+    values: Vec<f64>,
+    columns: usize,
+    yaw: f64,
+    pitch: f64,
+    height: f64,
+    shading: Shading,
+    density: Density,
+    mesh: bool,
+}
+
+impl Surface {
+    /// Creates a new `Surface` of a height map given as rows of `columns` samples each, seen
+    /// from 30° round and 30° above, and shaded by the direction of its faces without grid
+    /// lines.
+    ///
+    /// Samples that aren’t finite leave holes in the surface.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` is zero or doesn’t divide the number of values.
+    pub fn new(values: Vec<f64>, columns: usize) -> Surface {
+        assert!(columns > 0 && values.len() % columns == 0,
+                "height map of {} values isn’t made of rows of {}", values.len(), columns);
+        Surface {
+            values,
+            columns,
+            yaw: 30.0,
+            pitch: 30.0,
+            height: 0.5,
+            shading: Shading::Normal,
+            density: Density::default(),
+            mesh: false,
+        }
+    }
+
+    /// Creates a new `Surface` by sampling a function at each point of a grid `columns` by
+    /// `rows` points, from `(0, 0)` to `(columns - 1, rows - 1)`.
+    pub fn from_fn<F: Fn(f64, f64) -> f64>(columns: usize, rows: usize, f: F) -> Surface {
+        let values = (0..rows).flat_map(|y| (0..columns).map(move |x| (x, y)))
+            .map(|(x, y)| f(x as f64, y as f64)).collect();
+        Surface::new(values, columns.max(1))
+    }
+
+    /// Sets the angle the surface is seen from, in degrees round it clockwise and above it from
+    /// 0 for side on to 90 for straight down, and return it for use again.
+    pub fn view(mut self, yaw: f64, pitch: f64) -> Surface {
+        self.yaw = yaw;
+        self.pitch = pitch.clamp(0.0, 90.0);
+        self
+    }
+
+    /// Sets how tall the surface is drawn from its lowest point to its highest, as a fraction
+    /// of the width of the grid, and return it for use again.
+    pub fn height(mut self, height: f64) -> Surface {
+        self.height = height.max(0.0);
+        self
+    }
+
+    /// Sets how the faces are shaded, and return it for use again.
+    pub fn shading(mut self, shading: Shading) -> Surface {
+        self.shading = shading;
+        self
+    }
+
+    /// Sets how shades are turned into densities of dots, and return it for use again.
+    pub fn density(mut self, density: Density) -> Surface {
+        self.density = density;
+        self
+    }
+
+    /// Draws or leaves out the grid lines over the faces, shading the faces more lightly to
+    /// make room for them, and return it for use again.
+    ///
+    /// ```
+    /// use drawille::{Canvas, Surface};
+    ///
+    /// // A saddle on a coarse grid, whose shape shows best in outline.
+    /// let saddle = Surface::from_fn(9, 9, |x, y| (x - 4.0) * (y - 4.0));
+    /// let (mut shaded, mut meshed) = (Canvas::new(0, 0), Canvas::new(0, 0));
+    /// saddle.clone().draw(&mut shaded, 0, 0, 100, 60);
+    /// saddle.mesh(true).draw(&mut meshed, 0, 0, 100, 60);
+    /// // The faces are shaded with fewer dots to make room for the grid lines.
+    /// assert!(meshed.pixels().count() < shaded.pixels().count());
+    /// ```
+    pub fn mesh(mut self, mesh: bool) -> Surface {
+        self.mesh = mesh;
+        self
+    }
+
+    /// Returns the number of rows of samples in the height map.
+    pub fn rows(&self) -> usize {
+        self.values.len() / self.columns
+    }
+
+    /// Draws the `Surface` onto the `Canvas`, scaled to fit a rectangle given by its top-left
+    /// corner and size in pixels.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, width: u32, height: u32) {
+        let (columns, rows) = (self.columns, self.rows());
+        if columns < 2 || rows < 2 || width == 0 || height == 0 {
+            return;
+        }
+        let (min, max) = self.values.iter().filter(|v| v.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |r, &v| (r.0.min(v), r.1.max(v)));
+        if min > max {
+            return;
+        }
+        let points = self.view_points(min, max);
+        // Fit the view into the rectangle, keeping its proportions.
+        let (mut left, mut right, mut bottom, mut top) =
+            (f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY, f64::NEG_INFINITY);
+        for p in points.iter().flatten() {
+            left = left.min(p[0]);
+            right = right.max(p[0]);
+            bottom = bottom.min(p[1]);
+            top = top.max(p[1]);
+        }
+        let (w, h) = ((width - 1) as f64, (height - 1) as f64);
+        let scale = match (right - left > 0.0, top - bottom > 0.0) {
+            (true, true) => (w / (right - left)).min(h / (top - bottom)),
+            (true, false) => w / (right - left),
+            (false, true) => h / (top - bottom),
+            (false, false) => 1.0,
+        };
+        let (cx, cy) = ((left + right) / 2.0, (bottom + top) / 2.0);
+        let screen: Vec<Option<[f64; 3]>> = points.iter().map(|p| p.map(|p| {
+            [w / 2.0 + (p[0] - cx) * scale, h / 2.0 - (p[1] - cy) * scale, p[2]]
+        })).collect();
+
+        let (bw, bh) = (width as usize, height as usize);
+        let mut depth = vec![f64::INFINITY; bw * bh];
+        let mut shade = vec![0.0; bw * bh];
+        let vertex = |i: usize, j: usize| screen[j * columns + i];
+        for j in 0..rows - 1 {
+            for i in 0..columns - 1 {
+                let corners = [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)];
+                for tri in &[[0, 1, 2], [0, 2, 3]] {
+                    let (a, b, c) = (corners[tri[0]], corners[tri[1]], corners[tri[2]]);
+                    if let (Some(pa), Some(pb), Some(pc)) =
+                        (vertex(a.0, a.1), vertex(b.0, b.1), vertex(c.0, c.1))
+                    {
+                        let level = match self.shading {
+                            Shading::Height => {
+                                let mean = (self.value(a) + self.value(b) + self.value(c)) / 3.0;
+                                let t = if max > min { (mean - min) / (max - min) } else { 1.0 };
+                                0.1 + 0.8 * t
+                            }
+                            Shading::Normal => lighting(points[a.1 * columns + a.0],
+                                                        points[b.1 * columns + b.0],
+                                                        points[c.1 * columns + c.0]),
+                        };
+                        fill_triangle([pa, pb, pc], bw, bh, |px, py, d| {
+                            let k = py * bw + px;
+                            if d < depth[k] {
+                                depth[k] = d;
+                                shade[k] = level;
+                            }
+                        });
+                    }
+                }
+            }
+        }
+        // Faces are shaded more lightly under grid lines, so that the lines stand out.
+        let light = if self.mesh { 0.5 } else { 1.0 };
+        for py in 0..bh {
+            for px in 0..bw {
+                let k = py * bw + px;
+                let (px, py) = (x + px as u32, y + py as u32);
+                if depth[k].is_finite() && self.density.is_set(px, py, shade[k] * light) {
+                    canvas.set(px, py);
+                }
+            }
+        }
+        if self.mesh {
+            // Lines lie on the faces they border, so allow them a little in front of the
+            // depth buffer or they would flicker in and out along their length.
+            let slack = 0.02 * (right - left).max(top - bottom).max(1e-9);
+            let edges = (0..rows).flat_map(|j| (0..columns).map(move |i| (i, j)))
+                .flat_map(|(i, j)| vec![((i, j), (i + 1, j)), ((i, j), (i, j + 1))])
+                .filter(|&(_, (ni, nj))| ni < columns && nj < rows);
+            for ((i, j), (ni, nj)) in edges {
+                let (p, q) = match (vertex(i, j), vertex(ni, nj)) {
+                    (Some(p), Some(q)) => (p, q),
+                    _ => continue,
+                };
+                let steps = (q[0] - p[0]).abs().max((q[1] - p[1]).abs()).ceil().max(1.0);
+                for s in 0..=steps as usize {
+                    let t = s as f64 / steps;
+                    let px = (p[0] + (q[0] - p[0]) * t).round();
+                    let py = (p[1] + (q[1] - p[1]) * t).round();
+                    if px < 0.0 || py < 0.0 || px > w || py > h {
+                        continue;
+                    }
+                    let k = py as usize * bw + px as usize;
+                    if p[2] + (q[2] - p[2]) * t <= depth[k] + slack {
+                        canvas.set(x + px as u32, y + py as u32);
+                    }
+                }
+            }
+        }
+    }
+
+    fn value(&self, (i, j): (usize, usize)) -> f64 {
+        self.values[j * self.columns + i]
+    }
+
+    /// Turns each sample into a point seen by the viewer: across the view to the right, up it,
+    /// and away from the viewer, in units of half the width of the grid.
+    fn view_points(&self, min: f64, max: f64) -> Vec<Option<[f64; 3]>> {
+        let (columns, rows) = (self.columns, self.rows());
+        let span = (columns - 1).max(rows - 1) as f64 / 2.0;
+        let (sin_yaw, cos_yaw) = self.yaw.to_radians().sin_cos();
+        let (sin_pitch, cos_pitch) = self.pitch.to_radians().sin_cos();
+        self.values.iter().enumerate().map(|(k, &v)| {
+            if !v.is_finite() {
+                return None;
+            }
+            // Rows run towards the viewer, so the first row is at the back.
+            let gx = ((k % columns) as f64 - (columns - 1) as f64 / 2.0) / span;
+            let gy = ((rows - 1) as f64 / 2.0 - (k / columns) as f64) / span;
+            let gz = match max > min {
+                true => ((v - min) / (max - min) - 0.5) * 2.0 * self.height,
+                false => 0.0,
+            };
+            let (rx, ry) = (gx * cos_yaw - gy * sin_yaw, gx * sin_yaw + gy * cos_yaw);
+            Some([rx, gz * cos_pitch + ry * sin_pitch, ry * cos_pitch - gz * sin_pitch])
+        }).collect()
+    }
+}
+
+/// Returns how brightly a light above and to the left of the viewer lights a triangle of points
+/// seen by the viewer, from 0.05 for facing away from it to 1 for facing it.
+fn lighting(a: Option<[f64; 3]>, b: Option<[f64; 3]>, c: Option<[f64; 3]>) -> f64 {
+    let (a, b, c) = match (a, b, c) {
+        (Some(a), Some(b), Some(c)) => (a, b, c),
+        _ => return 0.5,
+    };
+    let (u, v) = ([b[0] - a[0], b[1] - a[1], b[2] - a[2]], [c[0] - a[0], c[1] - a[1], c[2] - a[2]]);
+    let n = [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]];
+    let len = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+    if len == 0.0 {
+        return 0.5;
+    }
+    // Whichever way round the triangle was given, its normal should face the viewer.
+    let sign = if n[2] > 0.0 { -1.0 } else { 1.0 };
+    let light = [-0.4, 0.7, -0.6];
+    let light_len = (0.16f64 + 0.49 + 0.36).sqrt();
+    let dot = (n[0] * light[0] + n[1] * light[1] + n[2] * light[2]) * sign / len / light_len;
+    dot.clamp(0.05, 1.0)
+}
+
+/// Calls `plot` with each pixel of a `width` by `height` buffer whose centre falls inside a
+/// triangle of screen points, along with the depth of the triangle there.
+fn fill_triangle<F: FnMut(usize, usize, f64)>(p: [[f64; 3]; 3], width: usize, height: usize,
+                                               mut plot: F) {
+    let area = (p[1][0] - p[0][0]) * (p[2][1] - p[0][1])
+        - (p[2][0] - p[0][0]) * (p[1][1] - p[0][1]);
+    if area == 0.0 {
+        return;
+    }
+    let low = |k: usize| p.iter().map(|q| q[k]).fold(f64::INFINITY, f64::min).floor().max(0.0);
+    let high = |k: usize| p.iter().map(|q| q[k]).fold(f64::NEG_INFINITY, f64::max).ceil();
+    let (x_end, y_end) = (high(0).min(width as f64 - 1.0), high(1).min(height as f64 - 1.0));
+    if x_end < 0.0 || y_end < 0.0 {
+        return;
+    }
+    for py in low(1) as usize..=y_end as usize {
+        for px in low(0) as usize..=x_end as usize {
+            let (x, y) = (px as f64, py as f64);
+            let edge = |a: [f64; 3], b: [f64; 3]| {
+                ((b[0] - a[0]) * (y - a[1]) - (x - a[0]) * (b[1] - a[1])) / area
+            };
+            let (w0, w1, w2) = (edge(p[1], p[2]), edge(p[2], p[0]), edge(p[0], p[1]));
+            // A little slack, so that neighbouring triangles leave no gaps between them.
+            if w0 >= -1e-9 && w1 >= -1e-9 && w2 >= -1e-9 {
+                plot(px, py, w0 * p[0][2] + w1 * p[1][2] + w2 * p[2][2]);
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use std::error::Error;
+use std::fmt;
+
+use canvas::Canvas;
+use path::Path;
+
+/// The most lines a single curve is flattened into, however long it is.
+const MAX_CURVE_LINES: usize = 256;
+
+/// An error produced while parsing SVG path data, with the index of the character it was
+/// found at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SvgError {
+    /// A command that isn’t supported, such as an arc, or a character that isn’t a command.
+    UnsupportedCommand(usize, char),
+    /// A number is malformed, or missing where the command needs another.
+    InvalidNumber(usize),
+    /// The path data draws before its first `M` command.
+    MissingMoveTo(usize),
+}
+
+impl fmt::Display for SvgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SvgError::UnsupportedCommand(i, c) => {
+                write!(f, "unsupported path command {:?} at character {}", c, i + 1)
+            }
+            SvgError::InvalidNumber(i) => write!(f, "invalid number at character {}", i + 1),
+            SvgError::MissingMoveTo(i) => {
+                write!(f, "path data must start with a moveto, at character {}", i + 1)
+            }
+        }
+    }
+}
+
+impl Error for SvgError {}
+
+impl Path {
+    /// Parses SVG path data, as found in the `d` attribute of a `<path>` element, into one
+    /// `Path` for each subpath, with every point scaled and then moved by `offset`.
+    ///
+    /// The `M`, `L`, `H`, `V`, `C`, `Q` and `Z` commands are supported, in both their absolute
+    /// and relative forms. Curves are flattened into lines about two units long once scaled,
+    /// so scaling here rather than afterwards keeps large drawings smooth.
+    ///
+    /// ```
+    /// use drawille::Path;
+    ///
+    /// let paths = Path::from_svg("M0 0 H10 V10 Z m20 0 l5 5", 2.0, (1.0, 0.0)).unwrap();
+    /// assert_eq!(paths.len(), 2);
+    /// assert_eq!(paths[0].points(), [(1.0, 0.0), (21.0, 0.0), (21.0, 20.0), (1.0, 0.0)]);
+    /// assert_eq!(paths[1].points(), [(41.0, 0.0), (51.0, 10.0)]);
+    /// ```
+    pub fn from_svg(data: &str, scale: f64, offset: (f64, f64))
+        -> Result<Vec<Path>, SvgError>
+    {
+        let mut parser = Parser { data: data.as_bytes(), pos: 0 };
+        let mut paths: Vec<Path> = Vec::new();
+        // The current point and the start of the current subpath, in SVG coordinates.
+        let (mut current, mut start) = (None, (0.0, 0.0));
+        let mut command = None;
+        let transform = |(x, y): (f64, f64)| (x * scale + offset.0, y * scale + offset.1);
+
+        while parser.skip_separators() {
+            let at = parser.pos;
+            let c = data[at..].chars().next().unwrap_or(' ');
+            if c.is_ascii_alphabetic() {
+                parser.pos += 1;
+                command = Some(c);
+            } else if command.is_none() {
+                return Err(SvgError::MissingMoveTo(at));
+            }
+            let c = command.unwrap_or('M');
+            let relative = c.is_ascii_lowercase();
+            let (x0, y0) = match (current, c.to_ascii_uppercase()) {
+                (None, 'M') => (0.0, 0.0),
+                (None, _) => return Err(SvgError::MissingMoveTo(at)),
+                (Some(p), _) => p,
+            };
+            let point = |parser: &mut Parser| -> Result<(f64, f64), SvgError> {
+                let (x, y) = (parser.number()?, parser.number()?);
+                Ok(if relative { (x0 + x, y0 + y) } else { (x, y) })
+            };
+            // There is always a subpath ending at the current point, started by `M` or `Z`.
+            let line_to = |paths: &mut Vec<Path>, p: (f64, f64)| {
+                if let Some(path) = paths.last_mut() {
+                    path.push(transform(p));
+                }
+            };
+            let end = match c.to_ascii_uppercase() {
+                'M' => {
+                    let p = point(&mut parser)?;
+                    paths.push(Path::new(vec![transform(p)]));
+                    start = p;
+                    // Further pairs of coordinates after a moveto are lines.
+                    command = Some(if relative { 'l' } else { 'L' });
+                    p
+                }
+                'L' => {
+                    let p = point(&mut parser)?;
+                    line_to(&mut paths, p);
+                    p
+                }
+                'H' => {
+                    let x = parser.number()?;
+                    let p = (if relative { x0 + x } else { x }, y0);
+                    line_to(&mut paths, p);
+                    p
+                }
+                'V' => {
+                    let y = parser.number()?;
+                    let p = (x0, if relative { y0 + y } else { y });
+                    line_to(&mut paths, p);
+                    p
+                }
+                'C' => {
+                    let (c1, c2, p) = (point(&mut parser)?, point(&mut parser)?,
+                                       point(&mut parser)?);
+                    let controls = [transform((x0, y0)), transform(c1), transform(c2),
+                                    transform(p)];
+                    for t in curve_steps(&controls) {
+                        let u = 1.0 - t;
+                        let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t,
+                                            t * t * t);
+                        line_to(&mut paths, (a * x0 + b * c1.0 + c * c2.0 + d * p.0,
+                                             a * y0 + b * c1.1 + c * c2.1 + d * p.1));
+                    }
+                    p
+                }
+                'Q' => {
+                    let (c1, p) = (point(&mut parser)?, point(&mut parser)?);
+                    let controls = [transform((x0, y0)), transform(c1), transform(p)];
+                    for t in curve_steps(&controls) {
+                        let u = 1.0 - t;
+                        let (a, b, c) = (u * u, 2.0 * u * t, t * t);
+                        line_to(&mut paths, (a * x0 + b * c1.0 + c * p.0,
+                                             a * y0 + b * c1.1 + c * p.1));
+                    }
+                    p
+                }
+                'Z' => {
+                    if (x0, y0) != start {
+                        line_to(&mut paths, start);
+                    }
+                    // The next command, if it draws, starts a new subpath from here.
+                    paths.push(Path::new(vec![transform(start)]));
+                    command = None;
+                    start
+                }
+                _ => return Err(SvgError::UnsupportedCommand(at, c)),
+            };
+            current = Some(end);
+        }
+        paths.retain(|path| path.points().len() > 1);
+        Ok(paths)
+    }
+}
+
+/// Returns the values of t from just after 0 up to 1 at which to sample a curve with the given
+/// control points, for lines about two units long.
+fn curve_steps(controls: &[(f64, f64)]) -> impl Iterator<Item = f64> {
+    let length: f64 = controls.windows(2)
+        .map(|pair| (pair[1].0 - pair[0].0).hypot(pair[1].1 - pair[0].1))
+        .sum();
+    let steps = ((length / 2.0).ceil() as usize).clamp(1, MAX_CURVE_LINES);
+    (1..=steps).map(move |i| i as f64 / steps as f64)
+}
+
+/// Reads the numbers of SVG path data, which may be separated by whitespace, a comma, or
+/// nothing at all where a sign or a second decimal point makes the split clear.
+struct Parser<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Skips whitespace and commas, returning whether anything is left.
+    fn skip_separators(&mut self) -> bool {
+        while self.pos < self.data.len()
+            && (self.data[self.pos].is_ascii_whitespace() || self.data[self.pos] == b',')
+        {
+            self.pos += 1;
+        }
+        self.pos < self.data.len()
+    }
+
+    fn number(&mut self) -> Result<f64, SvgError> {
+        self.skip_separators();
+        let start = self.pos;
+        let digits = |parser: &mut Parser| {
+            let from = parser.pos;
+            while parser.pos < parser.data.len() && parser.data[parser.pos].is_ascii_digit() {
+                parser.pos += 1;
+            }
+            parser.pos > from
+        };
+        let sign = |parser: &mut Parser| {
+            if parser.pos < parser.data.len() && matches!(parser.data[parser.pos], b'+' | b'-') {
+                parser.pos += 1;
+            }
+        };
+        sign(self);
+        let mut any = digits(self);
+        if self.data.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            any |= digits(self);
+        }
+        if !any {
+            return Err(SvgError::InvalidNumber(start));
+        }
+        if matches!(self.data.get(self.pos), Some(b'e') | Some(b'E')) {
+            let mantissa = self.pos;
+            self.pos += 1;
+            sign(self);
+            if !digits(self) {
+                // An `e` not followed by an exponent isn’t part of the number.
+                self.pos = mantissa;
+            }
+        }
+        ::std::str::from_utf8(&self.data[start..self.pos]).ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|n: &f64| n.is_finite())
+            .ok_or(SvgError::InvalidNumber(start))
+    }
+}
+
+impl Canvas {
+    /// Draws SVG path data onto the `Canvas`, scaled and then moved by `offset` into pixel
+    /// coordinates, as `Path::from_svg` reads it, clipping whatever falls off the edges.
+    ///
+    /// Nothing is drawn if the path data doesn’t parse.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(8, 8);
+    /// canvas.draw_svg("M1 1 H7 V7 H1 Z", 1.0, (0.0, 0.0)).unwrap();
+    /// assert_eq!(canvas.frame(), ["⢰⠒⠒⢲ ",
+    ///                             "⢸⣀⣀⣸ ",
+    ///                             "     "].join("\n"));
+    /// ```
+    pub fn draw_svg(&mut self, data: &str, scale: f64, offset: (f64, f64))
+        -> Result<(), SvgError>
+    {
+        for path in Path::from_svg(data, scale, offset)? {
+            path.draw(self);
+        }
+        Ok(())
+    }
+}
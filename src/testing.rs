@@ -0,0 +1,164 @@
+//! Helpers for testing what a `Canvas` draws, against expected frames or golden files.
+//!
+//! When frames differ, the failure message shows both of them with row and column markers,
+//! and an overlay of the pixels that differ, so that a one-dot mistake is easy to find.
+//!
+//! Golden files hold expected frames on disk. Setting the `DRAWILLE_UPDATE_GOLDEN`
+//! environment variable makes `assert_golden` write the actual frames instead, to create or
+//! update them.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate drawille;
+//!
+//! use drawille::Canvas;
+//!
+//! fn main() {
+//!     let mut canvas = Canvas::new(4, 4);
+//!     canvas.line(0, 0, 3, 3);
+//!     assert_frame_eq!(canvas.frame(), "⠑⢄ \n   ");
+//! }
+//! ```
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The environment variable that makes `assert_golden` write golden files rather than check
+/// them.
+pub const UPDATE_VAR: &str = "DRAWILLE_UPDATE_GOLDEN";
+
+/// Asserts that two frames are equal, panicking with a report of where they differ if not.
+///
+/// Either side can be anything that converts to `&str`, such as the `String` returned by
+/// `Canvas::frame`. A custom message can follow, as with `assert_eq!`.
+#[macro_export]
+macro_rules! assert_frame_eq {
+    ($actual:expr, $expected:expr) => {
+        $crate::testing::assert_frames(AsRef::<str>::as_ref(&$actual),
+                                       AsRef::<str>::as_ref(&$expected), None)
+    };
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {
+        $crate::testing::assert_frames(AsRef::<str>::as_ref(&$actual),
+                                       AsRef::<str>::as_ref(&$expected),
+                                       Some(&format!($($arg)+)))
+    };
+}
+
+/// Panics with a report of where the frames differ, if they do. This is what
+/// `assert_frame_eq!` calls.
+#[track_caller]
+pub fn assert_frames(actual: &str, expected: &str, message: Option<&str>) {
+    if let Some(report) = diff(actual, expected) {
+        match message {
+            Some(message) => panic!("frames differ: {}\n{}", message, report),
+            None => panic!("frames differ\n{}", report),
+        }
+    }
+}
+
+/// Returns a report of where two frames differ, or `None` if they are equal.
+///
+/// The report shows the expected frame, the actual one, and an overlay in which each
+/// differing Braille cell holds only the dots that differ, each differing letter is shown as
+/// it is in the actual frame, and equal cells are blank. Rows that differ are marked with `>`.
+///
+/// ```
+/// use drawille::testing;
+///
+/// assert_eq!(testing::diff("⠁⠂", "⠁⠂"), None);
+/// let report = testing::diff("⠃x", "⠁y").unwrap();
+/// assert!(report.contains("> 0 │⠂x│"));
+/// ```
+pub fn diff(actual: &str, expected: &str) -> Option<String> {
+    if actual == expected {
+        return None;
+    }
+    let grid = |frame: &str| -> Vec<Vec<char>> {
+        frame.split('\n').map(|row| row.trim_end_matches('\r').chars().collect()).collect()
+    };
+    let (actual, expected) = (grid(actual), grid(expected));
+    let rows = actual.len().max(expected.len());
+    let columns = actual.iter().chain(&expected).map(Vec::len).max().unwrap_or(0);
+    let cell = |grid: &[Vec<char>], x: usize, y: usize| {
+        grid.get(y).and_then(|row| row.get(x)).cloned()
+    };
+
+    let mut overlay = Vec::with_capacity(rows);
+    let mut differing = Vec::with_capacity(rows);
+    for y in 0..rows {
+        let row: Vec<char> = (0..columns).map(|x| {
+            match (cell(&actual, x, y), cell(&expected, x, y)) {
+                (a, e) if a == e => ' ',
+                (Some(a), Some(e)) if is_braille(a) && is_braille(e) => {
+                    let bits = (a as u32 - 0x2800) ^ (e as u32 - 0x2800);
+                    ::std::char::from_u32(0x2800 + bits).unwrap()
+                }
+                (Some(a), _) => a,
+                (None, _) => '∅',
+            }
+        }).collect();
+        differing.push(actual.get(y) != expected.get(y));
+        overlay.push(row);
+    }
+
+    let width = rows.saturating_sub(1).to_string().len();
+    let mut report = String::new();
+    let ruler: String = (0..columns).map(|x| char::from(b'0' + (x % 10) as u8)).collect();
+    for (title, grid) in [("expected", &expected), ("actual", &actual), ("diff", &overlay)] {
+        let _ = writeln!(report, "{}:", title);
+        let _ = writeln!(report, "  {:>width$} │{}│", "", ruler, width = width);
+        for (y, &differs) in differing.iter().enumerate() {
+            let marker = if differs { '>' } else { ' ' };
+            let row: String = grid.get(y).map_or(String::new(), |row| row.iter().collect());
+            let _ = writeln!(report, "{} {:>width$} │{:<columns$}│", marker, y, row,
+                             width = width, columns = columns);
+        }
+    }
+    Some(report)
+}
+
+/// Reads the expected frame from a golden file.
+pub fn read_golden<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    let frame = fs::read_to_string(path)?;
+    // Files usually end with a newline that frames don’t.
+    Ok(frame.strip_suffix('\n').unwrap_or(&frame).to_string())
+}
+
+/// Writes a frame to a golden file, creating any directories it needs.
+pub fn write_golden<P: AsRef<Path>>(path: P, frame: &str) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, format!("{}\n", frame))
+}
+
+/// Asserts that a frame matches the one in a golden file, or writes it there if the
+/// `DRAWILLE_UPDATE_GOLDEN` environment variable is set.
+///
+/// # Panics
+///
+/// Panics with a report of the differences if the frames differ, or if the golden file can’t
+/// be read or written.
+#[track_caller]
+pub fn assert_golden<P: AsRef<Path>>(path: P, actual: &str) {
+    let path = path.as_ref();
+    if env::var_os(UPDATE_VAR).is_some() {
+        if let Err(e) = write_golden(path, actual) {
+            panic!("could not write golden file {}: {}", path.display(), e);
+        }
+        return;
+    }
+    match read_golden(path) {
+        Ok(expected) => assert_frames(actual, &expected, Some(&path.display().to_string())),
+        Err(e) => panic!("could not read golden file {}: {} (set {} to create it)",
+                         path.display(), e, UPDATE_VAR),
+    }
+}
+
+fn is_braille(c: char) -> bool {
+    ('\u{2800}'..='\u{28ff}').contains(&c)
+}
@@ -0,0 +1,148 @@
+#[cfg(feature = "rand_core")]
+use rand_core::RngCore;
+
+use canvas::Canvas;
+
+/// A kind of procedural noise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Noise {
+    /// Random values on a grid, smoothly interpolated. Blotchy and cheap.
+    Value,
+    /// Perlin’s gradient noise, which has fewer grid artefacts than `Value`.
+    Perlin,
+}
+
+impl Noise {
+    /// Samples the noise at the given position, returning a value between 0 and 1.
+    ///
+    /// The noise varies over a distance of about 1, and is the same for the same seed every time.
+    pub fn sample(&self, x: f64, y: f64, seed: u32) -> f64 {
+        let (x0, y0) = (x.floor(), y.floor());
+        let (fx, fy) = (x - x0, y - y0);
+        let (ix, iy) = (x0 as i64, y0 as i64);
+        let (sx, sy) = (fade(fx), fade(fy));
+        let corner = |dx: i64, dy: i64| {
+            let h = hash(ix + dx, iy + dy, seed);
+            match *self {
+                Noise::Value => h as f64 / u32::MAX as f64,
+                Noise::Perlin => {
+                    let angle = h as f64 / u32::MAX as f64 * 2.0 * ::std::f64::consts::PI;
+                    angle.cos() * (fx - dx as f64) + angle.sin() * (fy - dy as f64)
+                }
+            }
+        };
+        let top = lerp(corner(0, 0), corner(1, 0), sx);
+        let bottom = lerp(corner(0, 1), corner(1, 1), sx);
+        let v = lerp(top, bottom, sy);
+        match *self {
+            Noise::Value => v,
+            // Gradient noise lies within ±√½; stretch it over 0 to 1.
+            Noise::Perlin => (v * ::std::f64::consts::SQRT_2 * 0.5 + 0.5).clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// A noise texture that can fill regions of a `Canvas`.
+///
+/// ```
+/// use drawille::{Canvas, Noise, NoiseTexture};
+///
+/// let texture = NoiseTexture::new(Noise::Perlin, 7).scale(16.0);
+/// let mut canvas = Canvas::new(80, 40);
+/// texture.fill(&mut canvas, 0, 0, 80, 40);
+/// // Pixels are set where the noise is above the threshold of one half.
+/// assert_eq!(canvas.get(30, 20), Noise::Perlin.sample(30.0 / 16.0, 20.0 / 16.0, 7) > 0.5);
+/// let set = canvas.pixels().count();
+/// assert!(set > 80 * 40 / 4 && set < 80 * 40 * 3 / 4);
+///
+/// // The texture is anchored to the `Canvas`, so two halves line up into the whole.
+/// let mut halves = Canvas::new(80, 40);
+/// texture.fill(&mut halves, 0, 0, 40, 40);
+/// texture.fill(&mut halves, 40, 0, 40, 40);
+/// assert_eq!(halves.frame(), canvas.frame());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NoiseTexture {
+    noise: Noise,
+    seed: u32,
+    scale: f64,
+    threshold: f64,
+}
+
+impl NoiseTexture {
+    /// Creates a new `NoiseTexture` of the given kind and seed, with features about 8 pixels
+    /// across and half of its pixels set.
+    pub fn new(noise: Noise, seed: u32) -> NoiseTexture {
+        NoiseTexture { noise, seed, scale: 8.0, threshold: 0.5 }
+    }
+
+    /// Sets the size of the texture’s features in pixels, and return it for use again.
+    pub fn scale(mut self, scale: f64) -> NoiseTexture {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the noise value above which pixels are set, and return the texture for use again.
+    ///
+    /// Higher thresholds give sparser textures.
+    pub fn threshold(mut self, threshold: f64) -> NoiseTexture {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Fills a rectangle of the `Canvas` with the texture, given by its top-left corner and size
+    /// in pixels.
+    ///
+    /// The texture is anchored to the `Canvas` rather than the rectangle, so neighbouring fills
+    /// line up.
+    pub fn fill(&self, canvas: &mut Canvas, x: u32, y: u32, width: u32, height: u32) {
+        // Whatever runs past the largest coordinate is left out.
+        for py in y..y.saturating_add(height) {
+            for px in x..x.saturating_add(width) {
+                let (nx, ny) = (px as f64 / self.scale, py as f64 / self.scale);
+                if self.noise.sample(nx, ny, self.seed) > self.threshold {
+                    canvas.set(px, py);
+                }
+            }
+        }
+    }
+}
+
+impl Canvas {
+    /// Sets random pixels in a rectangle, given by its top-left corner and size, so that about
+    /// `density` (between 0 and 1) of them end up set.
+    ///
+    /// Passing a seeded `rng` makes the result reproducible.
+    #[cfg(feature = "rand_core")]
+    pub fn stipple<R: RngCore>(&mut self, rng: &mut R, x: u32, y: u32, width: u32, height: u32,
+                               density: f64) {
+        let limit = (density.clamp(0.0, 1.0) * u32::MAX as f64) as u32;
+        // Whatever runs past the largest coordinate is left out.
+        for py in y..y.saturating_add(height) {
+            for px in x..x.saturating_add(width) {
+                if rng.next_u32() < limit {
+                    self.set(px, py);
+                }
+            }
+        }
+    }
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Hashes lattice coordinates and a seed into well-mixed bits.
+fn hash(x: i64, y: i64, seed: u32) -> u32 {
+    let mut h = (x as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15)
+        ^ (y as u64).wrapping_mul(0xc2b2_ae3d_27d4_eb4f)
+        ^ (seed as u64).wrapping_mul(0x1656_67b1_9e37_79f9);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    (h >> 32) as u32
+}
@@ -0,0 +1,71 @@
+use fnv::FnvHashMap;
+
+use canvas::Canvas;
+
+impl Canvas {
+    /// Returns a smaller copy of the pixels of the `Canvas`, at most `max_width` by
+    /// `max_height` pixels, such as for a preview in a gallery. Text and styles are left out.
+    ///
+    /// The `Canvas` is shrunk by the same factor both ways, so that it keeps its shape, and a
+    /// pixel of the thumbnail is set if any pixel of the block it covers is, which keeps thin
+    /// lines visible. A `Canvas` that already fits is copied as it is.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(80, 40);
+    /// canvas.line(0, 0, 79, 39);
+    /// let thumbnail = canvas.thumbnail(20, 20);
+    /// assert_eq!(thumbnail.frame(), ["⠉⠒⠤⣀       ",
+    ///                                 "    ⠉⠒⠤⣀   ",
+    ///                                 "        ⠉⠒ "].join("\n"));
+    /// ```
+    pub fn thumbnail(&self, max_width: u32, max_height: u32) -> Canvas {
+        self.thumbnail_threshold(max_width, max_height, 0.0)
+    }
+
+    /// Returns a smaller copy of the pixels of the `Canvas` like `thumbnail`, in which a pixel
+    /// is set if more than `threshold` of the pixels of its block are.
+    ///
+    /// A threshold of 0.5 sets the pixels where most of the block is set, which suits filled
+    /// shapes better than a line drawing.
+    pub fn thumbnail_threshold(&self, max_width: u32, max_height: u32, threshold: f64)
+        -> Canvas
+    {
+        if max_width == 0 || max_height == 0 {
+            return Canvas::new(0, 0);
+        }
+        let (mut width, mut height) = self.size().in_pixels();
+        for (x, y) in self.screen_pixels() {
+            width = width.max(x + 1);
+            height = height.max(y + 1);
+        }
+        let scale = (width as f64 / max_width as f64)
+            .max(height as f64 / max_height as f64)
+            .max(1.0);
+        let (columns, rows) = ((width as f64 / scale).ceil() as u32,
+                               (height as f64 / scale).ceil() as u32);
+        // The first source pixel of the block that each thumbnail pixel covers.
+        let start = |i: u32| (i as f64 * scale).floor() as u32;
+
+        let mut counts: FnvHashMap<(u32, u32), u32> = FnvHashMap::default();
+        for (x, y) in self.screen_pixels() {
+            let (tx, ty) = (((x as f64 / scale) as u32).min(columns - 1),
+                            ((y as f64 / scale) as u32).min(rows - 1));
+            // Rounding can put a pixel just before the block it was counted in.
+            let tx = if x < start(tx) { tx - 1 } else { tx };
+            let ty = if y < start(ty) { ty - 1 } else { ty };
+            *counts.entry((tx, ty)).or_insert(0) += 1;
+        }
+
+        let mut thumbnail = Canvas::new(columns, rows);
+        for ((tx, ty), count) in counts {
+            let block_width = (start(tx + 1).min(width) - start(tx)).max(1);
+            let block_height = (start(ty + 1).min(height) - start(ty)).max(1);
+            if count as f64 > threshold * (block_width * block_height) as f64 {
+                thumbnail.set(tx, ty);
+            }
+        }
+        thumbnail
+    }
+}
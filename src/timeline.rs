@@ -0,0 +1,92 @@
+use canvas::Canvas;
+use chart::{fit, format_tick, nice_ticks};
+
+/// Labelled horizontal bars on a shared axis, such as the spans of a trace or the tasks of a
+/// Gantt chart.
+///
+/// ```
+/// use drawille::{Canvas, Timeline};
+///
+/// let mut timeline = Timeline::new();
+/// timeline.add("parse", 0.0, 1.5);
+/// timeline.add("typeck", 1.5, 4.25);
+/// timeline.add("codegen", 4.25, 9.0);
+///
+/// let mut canvas = Canvas::new(0, 0);
+/// timeline.draw(&mut canvas, 0, 0, 80);
+/// assert!(canvas.frame().starts_with("parse"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Timeline {
+    bars: Vec<(String, f64, f64)>,
+    range: Option<(f64, f64)>,
+}
+
+impl Timeline {
+    /// Creates a new, empty `Timeline` whose axis fits its bars.
+    pub fn new() -> Timeline {
+        Timeline::default()
+    }
+
+    /// Fixes the range of the axis instead of fitting it to the bars, and return the
+    /// `Timeline` for use again.
+    pub fn range(mut self, min: f64, max: f64) -> Timeline {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Adds a bar from `start` to `end`, below those added before it.
+    pub fn add<S: Into<String>>(&mut self, label: S, start: f64, end: f64) {
+        self.bars.push((label.into(), start, end));
+    }
+
+    /// Returns the bars as their labels, starts and ends, from top to bottom.
+    pub fn bars(&self) -> &[(String, f64, f64)] {
+        &self.bars
+    }
+
+    /// Draws the `Timeline` onto the `Canvas` with its top-left corner at the given pixel
+    /// coordinates, rounded down to a cell, and `width` pixels wide.
+    ///
+    /// Each bar takes up a row of cells, with its label on the left and its ends placed to the
+    /// pixel. Labelled ticks along the axis follow on the row below the last bar.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32, width: u32) {
+        let (column, row) = (x / 2, y / 4);
+        let ends = self.bars.iter().flat_map(|b| vec![b.1, b.2]);
+        let (min, max) = fit(self.range, ends);
+        let label_columns = self.bars.iter().map(|b| b.0.chars().count()).max().unwrap_or(0);
+        let left = (column + label_columns as u32 + 1) * 2;
+        let right = (x + width).saturating_sub(1);
+        if right <= left {
+            return;
+        }
+        let pixel = |v: f64| {
+            let f = ((v - min) / (max - min)).clamp(0.0, 1.0);
+            (left as f64 + f * (right - left) as f64).round() as u32
+        };
+        for (i, &(ref label, start, end)) in self.bars.iter().enumerate() {
+            let top = (row + i as u32) * 4;
+            canvas.text(column * 2, top, u32::MAX, label);
+            if !(start.is_finite() && end.is_finite()) {
+                continue;
+            }
+            let (x1, x2) = (pixel(start.min(end)), pixel(start.max(end)));
+            canvas.line(x1, top + 1, x2, top + 1);
+            canvas.line(x1, top + 2, x2, top + 2);
+        }
+
+        let axis = (row + self.bars.len() as u32) * 4;
+        canvas.line(left, axis, right, axis);
+        let ticks = nice_ticks((min, max), (width / 16).max(2) as usize);
+        let mut free_from = 0;
+        for &v in &ticks {
+            let label = format_tick(v, &ticks);
+            let len = label.chars().count() as u32;
+            let start = (pixel(v) / 2).saturating_sub(len / 2);
+            if start >= free_from && (start + len) * 2 <= right + 1 {
+                canvas.text(start * 2, axis + 4, u32::MAX, &label);
+                free_from = start + len + 1;
+            }
+        }
+    }
+}
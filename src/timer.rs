@@ -0,0 +1,120 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Paces a simulation and its rendering: updates run at a fixed rate however fast the terminal
+/// draws, and frames are shown on a fixed schedule that doesn’t drift.
+///
+/// Each frame, `tick` says how many fixed-length updates are due, then the frame is drawn and
+/// `wait` sleeps until the next one. If drawing falls behind, at most `max_updates` updates
+/// run per frame and the rest are skipped, so the simulation slows down rather than spiralling.
+///
+/// ```
+/// use std::time::Duration;
+/// use drawille::{Animation, Canvas, FrameTimer};
+///
+/// let mut timer = FrameTimer::new(50);
+/// let mut animation = Animation::new();
+/// let mut canvas = Canvas::new(20, 4);
+/// let mut x = 0.0;
+/// // A slow frame that took three steps.
+/// for _ in 0..timer.advance(Duration::from_millis(60)) {
+///     x += 100.0 * timer.dt();
+/// }
+/// canvas.set(x as u32, 0);
+/// animation.push(&canvas, timer.step());
+/// assert_eq!(x as u32, 6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FrameTimer {
+    step: Duration,
+    max_updates: u32,
+    accumulator: Duration,
+    last: Option<Instant>,
+    deadline: Option<Instant>,
+}
+
+impl FrameTimer {
+    /// Creates a new `FrameTimer` running at `fps` updates and frames per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fps` is zero.
+    pub fn new(fps: u32) -> FrameTimer {
+        assert!(fps > 0, "a FrameTimer needs a positive frame rate");
+        FrameTimer {
+            step: Duration::from_secs(1) / fps,
+            max_updates: 5,
+            accumulator: Duration::from_secs(0),
+            last: None,
+            deadline: None,
+        }
+    }
+
+    /// Sets the most updates run for a single frame, 5 by default, and return it for use again.
+    pub fn max_updates(mut self, max_updates: u32) -> FrameTimer {
+        self.max_updates = max_updates.max(1);
+        self
+    }
+
+    /// Returns the length of each update and frame.
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// Returns the length of each update, in seconds, for scaling velocities and the like.
+    pub fn dt(&self) -> f64 {
+        self.step.as_secs_f64()
+    }
+
+    /// Returns how far the simulation is between the last update and the next, from 0 to 1,
+    /// for drawing positions interpolated between the two.
+    pub fn alpha(&self) -> f64 {
+        self.accumulator.as_secs_f64() / self.step.as_secs_f64()
+    }
+
+    /// Measures the time since the last call and returns how many updates are due.
+    ///
+    /// The first call starts the clock and returns one update.
+    pub fn tick(&mut self) -> u32 {
+        let now = Instant::now();
+        match self.last.replace(now) {
+            Some(last) => self.advance(now - last),
+            None => 1,
+        }
+    }
+
+    /// Advances the clock by `elapsed` and returns how many updates are due, skipping any
+    /// beyond `max_updates`.
+    pub fn advance(&mut self, elapsed: Duration) -> u32 {
+        self.accumulator += elapsed;
+        let mut updates = 0;
+        while self.accumulator >= self.step {
+            self.accumulator -= self.step;
+            updates += 1;
+            if updates == self.max_updates {
+                // Drop whole steps that can’t be caught up, keeping the fraction for `alpha`.
+                while self.accumulator >= self.step {
+                    self.accumulator -= self.step;
+                }
+                break;
+            }
+        }
+        updates
+    }
+
+    /// Sleeps until it is time for the next frame.
+    ///
+    /// Deadlines are a whole number of steps apart, so time spent drawing doesn’t add up to
+    /// drift. Frames that were missed entirely are skipped rather than rushed.
+    pub fn wait(&mut self) {
+        let now = Instant::now();
+        let mut deadline = self.deadline.map_or(now, |d| d + self.step);
+        if deadline + self.step < now {
+            deadline = now;
+        }
+        if deadline > now {
+            thread::sleep(deadline - now);
+        }
+        self.deadline = Some(deadline);
+    }
+}
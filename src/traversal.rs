@@ -0,0 +1,152 @@
+use canvas::Canvas;
+
+/// A space-filling curve to visit pixels along, in an order that keeps pixels near each other
+/// on the curve near each other on the `Canvas`.
+///
+/// Both curves start at the top-left pixel and cover every square of a power of two pixels
+/// wide from that corner before leaving it, so the same order holds for any size of `Canvas`.
+/// Revealing a picture along one grows it from the corner in small, solid blocks instead of
+/// a row at a time.
+///
+/// ```
+/// use drawille::Traversal;
+///
+/// let points: Vec<_> = Traversal::Hilbert.iter(4, 4).collect();
+/// assert_eq!(points.len(), 16);
+/// // Each step of a Hilbert curve moves to a neighbouring pixel.
+/// for pair in points.windows(2) {
+///     let (dx, dy) = (pair[0].0 as i32 - pair[1].0 as i32, pair[0].1 as i32 - pair[1].1 as i32);
+///     assert_eq!(dx.abs() + dy.abs(), 1);
+/// }
+/// assert_eq!(Traversal::ZOrder.iter(2, 2).collect::<Vec<_>>(),
+///            [(0, 0), (1, 0), (0, 1), (1, 1)]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Traversal {
+    /// The Hilbert curve, which only ever steps to a neighbouring pixel.
+    Hilbert,
+    /// The Z-order or Morton curve, which interleaves the bits of the coordinates. It jumps
+    /// about more than the Hilbert curve but is quicker to work out.
+    ZOrder,
+}
+
+impl Traversal {
+    /// Returns how far along the curve a pixel is, counting from 0 at the top-left pixel.
+    pub fn index(self, x: u32, y: u32) -> u64 {
+        let (mut x, mut y) = (x as u64, y as u64);
+        match self {
+            Traversal::Hilbert => {
+                let mut index = 0;
+                let mut s = 1u64 << 31;
+                while s > 0 {
+                    let (rx, ry) = ((x & s != 0) as u64, (y & s != 0) as u64);
+                    index += s * s * ((3 * rx) ^ ry);
+                    x &= s - 1;
+                    y &= s - 1;
+                    rotate(s, &mut x, &mut y, rx, ry);
+                    s >>= 1;
+                }
+                index
+            }
+            Traversal::ZOrder => spread(x) | spread(y) << 1,
+        }
+    }
+
+    /// Returns the pixel at a given distance along the curve, the inverse of `index`.
+    pub fn point(self, index: u64) -> (u32, u32) {
+        match self {
+            Traversal::Hilbert => {
+                let (mut x, mut y) = (0, 0);
+                let mut t = index;
+                let mut s = 1u64;
+                while s < 1 << 32 {
+                    let rx = 1 & (t / 2);
+                    let ry = 1 & (t ^ rx);
+                    rotate(s, &mut x, &mut y, rx, ry);
+                    x += s * rx;
+                    y += s * ry;
+                    t /= 4;
+                    s <<= 1;
+                }
+                (x as u32, y as u32)
+            }
+            Traversal::ZOrder => (gather(index) as u32, gather(index >> 1) as u32),
+        }
+    }
+
+    /// Returns an iterator over every pixel of a rectangle from the top-left corner, `width`
+    /// by `height` pixels, in the order of the curve.
+    pub fn iter(self, width: u32, height: u32) -> impl Iterator<Item = (u32, u32)> {
+        let side = (width.max(height) as u64).next_power_of_two();
+        let len = if width == 0 || height == 0 { 0 } else { side * side };
+        (0..len).map(move |i| self.point(i)).filter(move |&(x, y)| x < width && y < height)
+    }
+}
+
+/// Reflects and swaps the coordinates within a quadrant `s` pixels wide so that the curve
+/// inside it runs the right way.
+fn rotate(s: u64, x: &mut u64, y: &mut u64, rx: u64, ry: u64) {
+    if ry == 0 {
+        if rx == 1 {
+            *x = s - 1 - *x;
+            *y = s - 1 - *y;
+        }
+        ::std::mem::swap(x, y);
+    }
+}
+
+/// Moves the lowest 32 bits of a number apart into the even bits.
+fn spread(v: u64) -> u64 {
+    let mut v = v & 0xffff_ffff;
+    v = (v | v << 16) & 0x0000_ffff_0000_ffff;
+    v = (v | v << 8) & 0x00ff_00ff_00ff_00ff;
+    v = (v | v << 4) & 0x0f0f_0f0f_0f0f_0f0f;
+    v = (v | v << 2) & 0x3333_3333_3333_3333;
+    (v | v << 1) & 0x5555_5555_5555_5555
+}
+
+/// Packs the even bits of a number together, the inverse of `spread`.
+fn gather(v: u64) -> u64 {
+    let mut v = v & 0x5555_5555_5555_5555;
+    v = (v | v >> 1) & 0x3333_3333_3333_3333;
+    v = (v | v >> 2) & 0x0f0f_0f0f_0f0f_0f0f;
+    v = (v | v >> 4) & 0x00ff_00ff_00ff_00ff;
+    v = (v | v >> 8) & 0x0000_ffff_0000_ffff;
+    (v | v >> 16) & 0x0000_0000_ffff_ffff
+}
+
+impl Canvas {
+    /// Returns the coordinates of every pixel that is set, in the order of a space-filling
+    /// curve.
+    pub fn pixels_in_order(&self, order: Traversal) -> Vec<(u32, u32)> {
+        let mut pixels: Vec<_> = self.pixels().map(|(x, y)| (order.index(x, y), (x, y))).collect();
+        pixels.sort_unstable();
+        pixels.into_iter().map(|p| p.1).collect()
+    }
+
+    /// Returns a copy of the `Canvas` with only the first `fraction` of its pixels set, from 0
+    /// to 1, in the order of a space-filling curve.
+    ///
+    /// Stepping the fraction up over the frames of an `Animation` draws the picture in
+    /// gradually, with each frame adding the same number of dots. Text and styles are kept.
+    ///
+    /// ```
+    /// use drawille::{Canvas, Traversal};
+    ///
+    /// let mut canvas = Canvas::new(0, 0);
+    /// canvas.fill_level(0, 0, 16, 16, 1.0, &Default::default());
+    /// let half = canvas.reveal(Traversal::Hilbert, 0.5);
+    /// assert_eq!(half.pixels().count(), 128);
+    /// // The first half of a Hilbert curve over a square fills its left half.
+    /// assert!(half.get(7, 15) && !half.get(8, 0));
+    /// ```
+    pub fn reveal(&self, order: Traversal, fraction: f64) -> Canvas {
+        let pixels = self.pixels_in_order(order);
+        let shown = (pixels.len() as f64 * fraction.clamp(0.0, 1.0)).round() as usize;
+        let mut canvas = self.clone();
+        for &(x, y) in &pixels[shown..] {
+            canvas.unset(x, y);
+        }
+        canvas
+    }
+}
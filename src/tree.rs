@@ -0,0 +1,107 @@
+use canvas::Canvas;
+
+/// A tree of labelled nodes, drawn top-down with each parent centred over its children.
+///
+/// ```
+/// use drawille::{Canvas, Tree};
+///
+/// let tree = Tree::new("src")
+///     .child(Tree::new("lib.rs"))
+///     .child(Tree::new("chart").child(Tree::new("axis.rs")).child(Tree::new("mod.rs")));
+///
+/// let mut canvas = Canvas::new(0, 0);
+/// tree.draw(&mut canvas, 0, 0);
+/// assert!(canvas.frame().contains("lib.rs"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Tree {
+    label: String,
+    children: Vec<Tree>,
+}
+
+/// The number of cells between the labels of neighbouring subtrees.
+const GAP: usize = 2;
+
+/// The number of cell rows between the label rows of a parent and its children.
+const LEVEL: u32 = 3;
+
+impl Tree {
+    /// Creates a new `Tree` with a single node.
+    pub fn new<S: Into<String>>(label: S) -> Tree {
+        Tree { label: label.into(), children: Vec::new() }
+    }
+
+    /// Adds a subtree below the root of a `Tree`, and return it for use again.
+    pub fn child(mut self, child: Tree) -> Tree {
+        self.children.push(child);
+        self
+    }
+
+    /// Adds a subtree below the root of the `Tree`.
+    pub fn push(&mut self, child: Tree) {
+        self.children.push(child);
+    }
+
+    /// Returns the label of the root of the `Tree`.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the subtrees below the root of the `Tree`.
+    pub fn children(&self) -> &[Tree] {
+        &self.children
+    }
+
+    /// Returns the size of the drawn `Tree` in cells.
+    pub fn size(&self) -> (usize, usize) {
+        (self.width(), self.depth() * LEVEL as usize - (LEVEL as usize - 1))
+    }
+
+    /// Draws the `Tree` onto the `Canvas` with its top-left corner at the given pixel
+    /// coordinates, rounded down to a cell. Labels are text and the connectors between them are
+    /// lines.
+    pub fn draw(&self, canvas: &mut Canvas, x: u32, y: u32) {
+        self.draw_at(canvas, x as usize / 2, y / 4);
+    }
+
+    /// Draws the tree with its subtree’s left edge at `column` and root label at `row`, and
+    /// returns the column at the centre of the root label.
+    fn draw_at(&self, canvas: &mut Canvas, column: usize, row: u32) -> usize {
+        let width = self.width();
+        let centre = if self.children.is_empty() {
+            column + width / 2
+        } else {
+            let inner: usize = self.children.iter().map(|c| c.width() + GAP).sum::<usize>() - GAP;
+            let mut left = column + (width - inner) / 2;
+            let mut centres = Vec::with_capacity(self.children.len());
+            for child in &self.children {
+                centres.push(child.draw_at(canvas, left, row + LEVEL));
+                left += child.width() + GAP;
+            }
+            let centre = (centres[0] + centres[centres.len() - 1]) / 2;
+            let px = |col: usize| col as u32 * 2;
+            let (top, middle, bottom) = (row * 4 + 4, row * 4 + 6, (row + LEVEL) * 4 - 1);
+            canvas.line(px(centre), top, px(centre), middle);
+            canvas.line(px(centres[0]), middle, px(centres[centres.len() - 1]), middle);
+            for &c in &centres {
+                canvas.line(px(c), middle, px(c), bottom);
+            }
+            centre
+        };
+        let len = self.label.chars().count();
+        canvas.text(centre.saturating_sub(len / 2) as u32 * 2, row * 4, u32::MAX, &self.label);
+        centre
+    }
+
+    /// Returns the width of the drawn tree in cells.
+    fn width(&self) -> usize {
+        let label = self.label.chars().count().max(1);
+        let children = self.children.iter().map(|c| c.width() + GAP).sum::<usize>();
+        label.max(children.saturating_sub(GAP))
+    }
+
+    /// Returns the number of levels in the tree.
+    fn depth(&self) -> usize {
+        1 + self.children.iter().map(Tree::depth).max().unwrap_or(0)
+    }
+}
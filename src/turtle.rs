@@ -1,7 +1,24 @@
 use std::cmp;
 use std::f32;
+use std::fmt::Write;
+use std::iter;
 
-use canvas::Canvas;
+use std::collections::VecDeque;
+
+use fnv::FnvHashMap;
+
+use canvas::{line_points, Canvas};
+use coords::{self, Size};
+
+/// Where a `Turtle` places the origin of its coordinate system on its `Canvas`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// The origin is the top-left corner of the `Canvas`, and y increases downwards.
+    TopLeft,
+    /// The origin is the centre of the `Canvas`, and y increases upwards, as is usual in
+    /// mathematics.
+    Center,
+}
 
 /// A ‘turtle’ that can walk around a canvas drawing lines.
 pub struct Turtle {
@@ -10,6 +27,22 @@ pub struct Turtle {
     pub brush: bool,
     pub rotation: f32,
     pub cvs: Canvas,
+    origin: Origin,
+    record: bool,
+    lines: Vec<(f32, f32, f32, f32)>,
+    bounds: Option<((f32, f32), (f32, f32))>,
+    fade: Option<Fade>,
+}
+
+/// The state of a fading trail: which frame each dot was last drawn in, and the dots and number
+/// of lines drawn in each of the frames that are still visible, after those drawn before it
+/// started, which never fade.
+struct Fade {
+    frames: u32,
+    current: u64,
+    kept: usize,
+    drawn: FnvHashMap<(u32, u32), u64>,
+    history: VecDeque<(Vec<(u32, u32)>, usize)>,
 }
 
 impl Turtle {
@@ -19,10 +52,15 @@ impl Turtle {
     pub fn new(x: f32, y: f32) -> Turtle {
         Turtle {
             cvs: Canvas::new(0, 0),
-            x: x,
-            y: y,
+            x,
+            y,
             brush: true,
             rotation: 0.0,
+            origin: Origin::TopLeft,
+            record: false,
+            lines: Vec::new(),
+            bounds: None,
+            fade: None,
         }
     }
 
@@ -31,26 +69,146 @@ impl Turtle {
     /// The turtle starts with its brush down, facing right.
     pub fn from_canvas(x: f32, y: f32, cvs: Canvas) -> Turtle {
         Turtle {
-            cvs: cvs,
-            x: x,
-            y: y,
+            cvs,
+            x,
+            y,
             brush: true,
             rotation: 0.0,
+            origin: Origin::TopLeft,
+            record: false,
+            lines: Vec::new(),
+            bounds: None,
+            fade: None,
         }
     }
 
-    /// Sets the width of a `Turtle`’s `Canvas`, and return it for use again.
+    /// Sets the width of a `Turtle`’s `Canvas` in pixels, as `Canvas::new` takes it, and return
+    /// it for use again.
     pub fn width(mut self, width: u32) -> Turtle {
-        self.cvs.width = width as u16;
+        self.cvs.width = coords::pixels_to_cells(width, 0).0 as u16;
         self
     }
 
-    /// Sets the height of a `Turtle`’s `Canvas`, and return it for use again.
+    /// Sets the height of a `Turtle`’s `Canvas` in pixels, as `Canvas::new` takes it, and
+    /// return it for use again.
     pub fn height(mut self, height: u32) -> Turtle {
-        self.cvs.height = height as u16;
+        self.cvs.height = coords::pixels_to_cells(0, height).1 as u16;
+        self
+    }
+
+    /// Sets the size of a `Turtle`’s `Canvas`, and return it for use again.
+    ///
+    /// ```
+    /// use drawille::{Size, Turtle};
+    ///
+    /// let turtle = Turtle::new(0.0, 0.0).size(Size::cells(4, 2));
+    /// assert_eq!(turtle.cvs.extents(), Turtle::new(0.0, 0.0).width(8).height(8).cvs.extents());
+    /// ```
+    pub fn size(mut self, size: Size) -> Turtle {
+        let (columns, rows) = size.in_cells();
+        self.cvs.width = columns as u16;
+        self.cvs.height = rows as u16;
+        self
+    }
+
+    /// Sets where the origin of a `Turtle`’s coordinates lies, and return it for use again.
+    ///
+    /// With `Origin::Center`, turning right still turns clockwise on screen. The origin is
+    /// then the centre of the size the `Canvas` was given, so a `Turtle::new` without a size
+    /// centres on its top-left corner, and whatever it draws above or left of that is cut off.
+    pub fn origin(mut self, origin: Origin) -> Turtle {
+        self.origin = origin;
+        self
+    }
+
+    /// Makes the `Turtle` keep every line it draws, for `segments` and everything built from
+    /// them, such as `fitted_frame` and `svg_path`, and return it for use again.
+    ///
+    /// Lines aren’t kept by default, since they take memory for as long as the `Turtle` keeps
+    /// drawing. Lines drawn before this is turned on aren’t kept.
+    ///
+    /// ```
+    /// use drawille::Turtle;
+    ///
+    /// let mut turtle = Turtle::new(0.0, 0.0);
+    /// turtle.forward(4.0);
+    /// assert!(turtle.segments().is_empty());
+    /// let mut turtle = Turtle::new(0.0, 0.0).record(true);
+    /// turtle.forward(4.0);
+    /// assert_eq!(turtle.segments(), &[(0.0, 0.0, 4.0, 0.0)]);
+    /// ```
+    pub fn record(mut self, record: bool) -> Turtle {
+        self.record = record;
+        self
+    }
+
+    /// Makes the `Turtle`’s trail fade out, and return it for use again.
+    ///
+    /// Dots drawn by the `Turtle` are removed from its `Canvas` once `frames` calls to `tick`
+    /// have passed since they were last drawn, which gives comet-like trails in animations.
+    /// Recorded lines are forgotten as they fade out too, so that `segments` and everything
+    /// built from them only cover the visible trail.
+    ///
+    /// ```
+    /// use drawille::Turtle;
+    ///
+    /// let mut turtle = Turtle::new(0.0, 0.0).record(true).fade_after(1);
+    /// turtle.forward(4.0);
+    /// turtle.tick();
+    /// turtle.forward(4.0);
+    /// assert!(turtle.cvs.get(0, 0) && turtle.cvs.get(8, 0));
+    /// turtle.tick();
+    /// assert!(!turtle.cvs.get(0, 0) && turtle.cvs.get(8, 0));
+    /// assert_eq!(turtle.segments(), &[(4.0, 0.0, 8.0, 0.0)]);
+    /// ```
+    pub fn fade_after(mut self, frames: u32) -> Turtle {
+        self.fade = Some(Fade {
+            frames,
+            current: 0,
+            kept: self.lines.len(),
+            drawn: FnvHashMap::default(),
+            history: VecDeque::from(vec![(Vec::new(), 0)]),
+        });
         self
     }
 
+    /// Advances a fading trail by one frame, removing the dots that have become too old.
+    ///
+    /// This does nothing unless the trail was set up with `fade_after`.
+    pub fn tick(&mut self) {
+        let fade = match self.fade {
+            Some(ref mut fade) => fade,
+            None => return,
+        };
+        fade.current += 1;
+        fade.history.push_back((Vec::new(), 0));
+        while fade.history.len() > fade.frames as usize + 1 {
+            let expired = fade.current + 1 - fade.history.len() as u64;
+            let (points, lines) = fade.history.pop_front().unwrap_or_default();
+            for p in points {
+                if fade.drawn.get(&p) == Some(&expired) {
+                    fade.drawn.remove(&p);
+                    self.cvs.unset(p.0, p.1);
+                }
+            }
+            self.lines.drain(fade.kept..fade.kept + lines);
+        }
+    }
+
+    /// Erases everything the `Turtle` has drawn, leaving it where it is.
+    pub fn clear(&mut self) {
+        self.cvs.clear();
+        self.lines.clear();
+        self.bounds = None;
+        if let Some(ref mut fade) = self.fade {
+            fade.kept = 0;
+            fade.drawn.clear();
+            for frame in &mut fade.history {
+                *frame = (Vec::new(), 0);
+            }
+        }
+    }
+
     /// Lifts the `Turtle`’s brush.
     pub fn up(&mut self) {
         self.brush = false;
@@ -69,7 +227,10 @@ impl Turtle {
     /// Moves the `Turtle` forward by `dist` steps.
     pub fn forward(&mut self, dist: f32) {
         let x = self.x + degrees_to_radians(self.rotation).cos()*dist;
-        let y = self.y + degrees_to_radians(self.rotation).sin()*dist;
+        let y = match self.origin {
+            Origin::TopLeft => self.y + degrees_to_radians(self.rotation).sin()*dist,
+            Origin::Center => self.y - degrees_to_radians(self.rotation).sin()*dist,
+        };
         self.teleport(x, y);
     }
 
@@ -84,10 +245,33 @@ impl Turtle {
     /// brush is down.
     pub fn teleport(&mut self, x: f32, y: f32) {
         if self.brush {
-            self.cvs.line(cmp::max(0, self.x.round() as i32) as u32,
-                          cmp::max(0, self.y.round() as i32) as u32,
-                          cmp::max(0, x.round() as i32) as u32,
-                          cmp::max(0, y.round() as i32) as u32);
+            let (x1, y1) = self.to_canvas(self.x, self.y);
+            let (x2, y2) = self.to_canvas(x, y);
+            match self.fade {
+                Some(ref mut fade) => {
+                    for p in line_points(x1, y1, x2, y2) {
+                        self.cvs.set(p.0, p.1);
+                        fade.drawn.insert(p, fade.current);
+                        if let Some(frame) = fade.history.back_mut() {
+                            frame.0.push(p);
+                        }
+                    }
+                    if let (true, Some(frame)) = (self.record, fade.history.back_mut()) {
+                        frame.1 += 1;
+                    }
+                }
+                None => self.cvs.line(x1, y1, x2, y2),
+            }
+            if self.record {
+                self.lines.push((self.x, self.y, x, y));
+            }
+            self.bounds = Some(match self.bounds {
+                Some(((x0, y0), (x1, y1))) => {
+                    ((x0.min(self.x).min(x), y0.min(self.y).min(y)),
+                     (x1.max(self.x).max(x), y1.max(self.y).max(y)))
+                }
+                None => ((self.x.min(x), self.y.min(y)), (self.x.max(x), self.y.max(y))),
+            });
         }
 
         self.x = x;
@@ -108,6 +292,144 @@ impl Turtle {
     pub fn frame(&self) -> String {
         self.cvs.frame()
     }
+
+    /// Returns the smallest and largest coordinates of everything the `Turtle` has drawn since
+    /// it was created or cleared, as `((min_x, min_y), (max_x, max_y))`, or `None` if it
+    /// hasn’t drawn anything yet.
+    ///
+    /// This is kept up to date as the `Turtle` draws, whether or not it records its lines, and
+    /// covers lines that have faded out too.
+    ///
+    /// ```
+    /// use drawille::Turtle;
+    ///
+    /// let mut turtle = Turtle::new(0.0, 0.0);
+    /// turtle.forward(10.0);
+    /// turtle.right(90.0);
+    /// turtle.forward(5.0);
+    /// assert_eq!(turtle.extents(), Some(((0.0, 0.0), (10.0, 5.0))));
+    /// ```
+    pub fn extents(&self) -> Option<((f32, f32), (f32, f32))> {
+        self.bounds
+    }
+
+    /// Draws the lines the `Turtle` has recorded, translated and scaled to fit a canvas of the
+    /// given size in pixels, and returns the result as a `String`.
+    ///
+    /// The aspect ratio of the drawing is preserved. This is independent of the `Turtle`’s own
+    /// `Canvas`, so lines that went off its edges are included as well. Nothing is drawn
+    /// unless the `Turtle` records its lines, as set with `record`.
+    ///
+    /// ```
+    /// use drawille::{Origin, Turtle};
+    ///
+    /// let mut turtle = Turtle::new(0.0, 0.0).origin(Origin::Center).record(true);
+    /// for _ in 0..4 {
+    ///     turtle.forward(100.0);
+    ///     turtle.left(90.0);
+    /// }
+    /// assert!(turtle.fitted_frame(4, 4).starts_with("⣏⣹"));
+    /// ```
+    pub fn fitted_frame(&self, width: u32, height: u32) -> String {
+        self.fitted_canvas(width, height).frame()
+    }
+
+    /// Draws the lines the `Turtle` has recorded onto a new `Canvas` of the given size in
+    /// pixels, translated and scaled to fit as for `fitted_frame`.
+    pub fn fitted_canvas(&self, width: u32, height: u32) -> Canvas {
+        let mut cvs = Canvas::new(width, height);
+        // Fit the recorded lines themselves, which leave out any that have faded out.
+        let mut ends = self.lines.iter().flat_map(|&(x1, y1, x2, y2)| {
+            iter::once((x1, y1)).chain(iter::once((x2, y2)))
+        });
+        let first = match ends.next() {
+            Some(first) => first,
+            None => return cvs,
+        };
+        let ((min_x, min_y), (max_x, max_y)) = ends.fold((first, first), |(lo, hi), (x, y)| {
+            ((lo.0.min(x), lo.1.min(y)), (hi.0.max(x), hi.1.max(y)))
+        });
+        let (w, h) = (width.saturating_sub(1) as f32, height.saturating_sub(1) as f32);
+        let scale_x = if max_x > min_x { w / (max_x - min_x) } else { f32::INFINITY };
+        let scale_y = if max_y > min_y { h / (max_y - min_y) } else { f32::INFINITY };
+        let scale = scale_x.min(scale_y);
+        let scale = if scale.is_finite() { scale } else { 0.0 };
+
+        let map = |x: f32, y: f32| {
+            let y = match self.origin {
+                Origin::TopLeft => y - min_y,
+                Origin::Center => max_y - y,
+            };
+            (((x - min_x) * scale).round() as u32, (y * scale).round() as u32)
+        };
+        for &(x1, y1, x2, y2) in &self.lines {
+            let ((x1, y1), (x2, y2)) = (map(x1, y1), map(x2, y2));
+            cvs.line(x1, y1, x2, y2);
+        }
+        cvs
+    }
+
+    /// Returns every line the `Turtle` has recorded, as `(x1, y1, x2, y2)` in its own
+    /// coordinates, or only those that haven’t faded out of a fading trail.
+    ///
+    /// This is empty unless the `Turtle` records its lines, as set with `record`.
+    pub fn segments(&self) -> &[(f32, f32, f32, f32)] {
+        &self.lines
+    }
+
+    /// Returns the lines the `Turtle` has recorded as SVG path data, suitable for the `d`
+    /// attribute of a `<path>` element.
+    ///
+    /// Consecutive lines are joined into a single subpath. With `Origin::Center` the y axis is
+    /// flipped, since SVG’s y axis points downwards.
+    ///
+    /// ```
+    /// use drawille::Turtle;
+    ///
+    /// let mut turtle = Turtle::new(0.0, 0.0).record(true);
+    /// turtle.forward(10.0);
+    /// turtle.right(90.0);
+    /// turtle.forward(5.0);
+    /// assert_eq!(turtle.svg_path(), "M0 0L10 0L10 5");
+    /// ```
+    pub fn svg_path(&self) -> String {
+        let flip = if self.origin == Origin::Center { -1.0 } else { 1.0 };
+        let mut path = String::new();
+        let mut end = None;
+        for &(x1, y1, x2, y2) in &self.lines {
+            if end != Some((x1, y1)) {
+                let _ = write!(path, "M{} {}", round(x1), round(y1 * flip));
+            }
+            let _ = write!(path, "L{} {}", round(x2), round(y2 * flip));
+            end = Some((x2, y2));
+        }
+        path
+    }
+
+    /// Converts `Turtle` coordinates to pixel coordinates on its `Canvas`.
+    fn to_canvas(&self, x: f32, y: f32) -> (u32, u32) {
+        let (x, y) = match self.origin {
+            Origin::TopLeft => (x, y),
+            Origin::Center => {
+                let (w, h) = (self.cvs.width as f32 * 2.0, self.cvs.height as f32 * 4.0);
+                (x + w / 2.0, h / 2.0 - y)
+            }
+        };
+        (cmp::max(0, x.round() as i32) as u32, cmp::max(0, y.round() as i32) as u32)
+    }
+}
+
+impl Default for Turtle {
+    /// Creates a new `Turtle` at the origin, as `Turtle::new(0.0, 0.0)` does.
+    fn default() -> Turtle {
+        Turtle::new(0.0, 0.0)
+    }
+}
+
+/// Rounds to three decimal places, so that floating-point noise doesn’t end up in SVG output.
+fn round(v: f32) -> f32 {
+    let v = (v * 1000.0).round() / 1000.0;
+    if v == 0.0 { 0.0 } else { v }
 }
 
 fn degrees_to_radians(deg: f32) -> f32 {
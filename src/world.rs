@@ -0,0 +1,218 @@
+use std::f64;
+
+use canvas::Canvas;
+
+/// The rectangle of world coordinates that is mapped onto a `Canvas`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct World {
+    pub(crate) min: (f64, f64),
+    pub(crate) max: (f64, f64),
+}
+
+impl World {
+    fn bits(&self) -> [u64; 4] {
+        [self.min.0.to_bits(), self.min.1.to_bits(), self.max.0.to_bits(), self.max.1.to_bits()]
+    }
+}
+
+// Compared bitwise so that `Canvas` can stay `Eq`.
+impl PartialEq for World {
+    fn eq(&self, other: &World) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl Eq for World {}
+
+/// The default ratio of a terminal cell’s height to its width.
+pub(crate) const DEFAULT_CELL_ASPECT: f64 = 2.0;
+
+/// An `f64` that is compared bitwise, for the same reason as `World`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Bits(pub(crate) f64);
+
+impl PartialEq for Bits {
+    fn eq(&self, other: &Bits) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for Bits {}
+
+impl Canvas {
+    /// Sets the world coordinates that the `plot` family of methods works in.
+    ///
+    /// The rectangle from `min` to `max` is stretched over the whole of the `Canvas`’s given
+    /// dimensions, with the y axis pointing upwards, so `min` lands at the bottom-left corner and
+    /// `max` at the top-right one.
+    ///
+    /// ```
+    /// use drawille::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(10, 8);
+    /// canvas.set_world((-1.0, -1.0), (1.0, 1.0));
+    /// canvas.plot(-1.0, -1.0);
+    /// canvas.plot(1.0, 1.0);
+    /// assert!(canvas.get(0, 7) && canvas.get(9, 0));
+    /// ```
+    pub fn set_world(&mut self, min: (f64, f64), max: (f64, f64)) {
+        self.world = Some(World { min, max });
+    }
+
+    /// Sets world coordinates that show at least the rectangle from `min` to `max`, without
+    /// distorting it.
+    ///
+    /// Unlike `set_world`, one unit covers the same distance on screen along both axes (taking
+    /// the cell aspect ratio into account), so the rectangle is widened or heightened as needed
+    /// and centred on the `Canvas`. Circles drawn with `plot_circle` then look round.
+    pub fn fit_world(&mut self, min: (f64, f64), max: (f64, f64)) {
+        let (w, h) = self.pixel_extent();
+        let (screen_w, screen_h) = (w, h * self.dot_aspect());
+        let (world_w, world_h) = (max.0 - min.0, max.1 - min.1);
+        let (mut min, mut max) = (min, max);
+        if screen_w > 0.0 && screen_h > 0.0 && world_w > 0.0 && world_h > 0.0 {
+            if world_w / world_h < screen_w / screen_h {
+                let pad = (world_h * screen_w / screen_h - world_w) / 2.0;
+                min.0 -= pad;
+                max.0 += pad;
+            } else {
+                let pad = (world_w * screen_h / screen_w - world_h) / 2.0;
+                min.1 -= pad;
+                max.1 += pad;
+            }
+        }
+        self.set_world(min, max);
+    }
+
+    /// Sets the ratio of a terminal cell’s height to its width, which defaults to 2.0.
+    ///
+    /// This is used by `fit_world` and `circle` to compensate for non-square cells; adjust it if
+    /// circles look squashed with the font in use.
+    pub fn set_cell_aspect(&mut self, ratio: f64) {
+        self.cell_aspect = Bits(ratio);
+    }
+
+    /// Returns the ratio of a single dot’s height to its width on screen.
+    ///
+    /// A cell holds two by four dots, so with the default cell aspect ratio of 2.0 dots are
+    /// square.
+    pub fn dot_aspect(&self) -> f64 {
+        self.cell_aspect.0 * 2.0 / 4.0
+    }
+
+    /// Draws a circle centred at the given pixel coordinates, which looks round on screen.
+    ///
+    /// `radius` is measured horizontally, in pixels; the vertical radius is adjusted by the
+    /// `dot_aspect`. Parts of the circle that would lie at negative coordinates are not drawn.
+    pub fn circle(&mut self, x: u32, y: u32, radius: u32) {
+        let (rx, ry) = (radius as f64, radius as f64 / self.dot_aspect());
+        let steps = ((rx.max(ry) * f64::consts::PI * 4.0).ceil() as u32).max(8);
+        for i in 0..steps {
+            let t = i as f64 / steps as f64 * f64::consts::PI * 2.0;
+            let (px, py) = ((x as f64 + rx * t.cos()).round(), (y as f64 + ry * t.sin()).round());
+            if px >= 0.0 && py >= 0.0 {
+                self.set(px as u32, py as u32);
+            }
+        }
+    }
+
+    /// Draws a circle of the given radius around a point in world coordinates, clipped to the
+    /// world rectangle.
+    pub fn plot_circle(&mut self, x: f64, y: f64, radius: f64) {
+        let (p1, p2) = (self.world_to_pixel_f64(x - radius, y + radius),
+                        self.world_to_pixel_f64(x + radius, y - radius));
+        let r = (p2.0 - p1.0).abs().max((p2.1 - p1.1).abs()) / 2.0;
+        let steps = ((r * f64::consts::PI).ceil() as u32).max(8);
+        let point = |i: u32| {
+            let t = i as f64 / steps as f64 * f64::consts::PI * 2.0;
+            (x + radius * t.cos(), y + radius * t.sin())
+        };
+        for i in 0..steps {
+            let ((x1, y1), (x2, y2)) = (point(i), point(i + 1));
+            self.plot_line(x1, y1, x2, y2);
+        }
+    }
+
+    /// Removes the world coordinates, so that the `plot` methods take pixel coordinates again.
+    pub fn clear_world(&mut self) {
+        self.world = None;
+    }
+
+    /// Converts world coordinates to pixel coordinates, returning `None` if they lie outside the
+    /// world rectangle.
+    pub fn world_to_pixel(&self, x: f64, y: f64) -> Option<(u32, u32)> {
+        let (px, py) = self.world_to_pixel_f64(x, y);
+        let (w, h) = self.pixel_extent();
+        let (px, py) = (px.round(), py.round());
+        if px < 0.0 || py < 0.0 || px > w || py > h {
+            None
+        } else {
+            Some((px as u32, py as u32))
+        }
+    }
+
+    /// Sets the pixel at the given world coordinates, if it lies within the world rectangle.
+    pub fn plot(&mut self, x: f64, y: f64) {
+        if let Some((px, py)) = self.world_to_pixel(x, y) {
+            self.set(px, py);
+        }
+    }
+
+    /// Draws a line between two points given in world coordinates, clipped to the world
+    /// rectangle.
+    pub fn plot_line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
+        let (w, h) = self.pixel_extent();
+        let p1 = self.world_to_pixel_f64(x1, y1);
+        let p2 = self.world_to_pixel_f64(x2, y2);
+        if let Some(((x1, y1), (x2, y2))) = clip(p1, p2, w, h) {
+            self.line(x1.round() as u32, y1.round() as u32, x2.round() as u32, y2.round() as u32);
+        }
+    }
+
+    /// Maps world coordinates to fractional pixel coordinates, without any bounds checks.
+    pub(crate) fn world_to_pixel_f64(&self, x: f64, y: f64) -> (f64, f64) {
+        let world = match self.world {
+            Some(world) => world,
+            None => return (x, y),
+        };
+        let (w, h) = self.pixel_extent();
+        let sx = if world.max.0 != world.min.0 { w / (world.max.0 - world.min.0) } else { 0.0 };
+        let sy = if world.max.1 != world.min.1 { h / (world.max.1 - world.min.1) } else { 0.0 };
+        let y = if self.is_y_up() { y - world.min.1 } else { world.max.1 - y };
+        ((x - world.min.0) * sx, y * sy)
+    }
+
+    /// Returns the largest pixel coordinates within the `Canvas`’s given dimensions.
+    pub(crate) fn pixel_extent(&self) -> (f64, f64) {
+        let w = (self.width as f64 * 2.0 - 1.0).max(0.0);
+        let h = (self.height as f64 * 4.0 - 1.0).max(0.0);
+        (w, h)
+    }
+}
+
+/// Clips a line to the rectangle from `(0, 0)` to `(w, h)` using the Liang–Barsky algorithm.
+pub(crate) fn clip(p1: (f64, f64), p2: (f64, f64), w: f64, h: f64)
+    -> Option<((f64, f64), (f64, f64))>
+{
+    let (dx, dy) = (p2.0 - p1.0, p2.1 - p1.1);
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+    let edges = [(-dx, p1.0), (dx, w - p1.0), (-dy, p1.1), (dy, h - p1.1)];
+    for &(p, q) in &edges {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    Some(((p1.0 + t0 * dx, p1.1 + t0 * dy), (p1.0 + t1 * dx, p1.1 + t1 * dy)))
+}
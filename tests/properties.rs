@@ -0,0 +1,443 @@
+extern crate drawille;
+extern crate proptest;
+
+use std::collections::BTreeSet;
+
+use drawille::{Canvas, Color, Density, Dither, Isometric, Journal, OutOfBounds, Path, Plasma,
+               Projection, Script, SharedCanvas, Style, Traversal, Viewport};
+use drawille::chart;
+use drawille::coords::CellRect;
+use drawille::ease::{self, Tween};
+use proptest::prelude::*;
+
+fn pixels(canvas: &Canvas) -> BTreeSet<(u32, u32)> {
+    canvas.pixels().collect()
+}
+
+fn line(x1: u32, y1: u32, x2: u32, y2: u32) -> BTreeSet<(u32, u32)> {
+    let mut canvas = Canvas::new(0, 0);
+    canvas.line(x1, y1, x2, y2);
+    pixels(&canvas)
+}
+
+proptest! {
+    #[test]
+    fn line_is_symmetric(x1 in 0u32..300, y1 in 0u32..300, x2 in 0u32..300, y2 in 0u32..300) {
+        prop_assert_eq!(line(x1, y1, x2, y2), line(x2, y2, x1, y1));
+    }
+
+    #[test]
+    fn line_is_8_connected(x1 in 0u32..300, y1 in 0u32..300, x2 in 0u32..300, y2 in 0u32..300) {
+        let set = line(x1, y1, x2, y2);
+        prop_assert!(set.contains(&(x1, y1)) && set.contains(&(x2, y2)));
+        let dx = (x1 as i64 - x2 as i64).abs();
+        let dy = (y1 as i64 - y2 as i64).abs();
+        prop_assert_eq!(set.len() as i64, dx.max(dy) + 1);
+
+        // Walk from one end; every pixel must be reachable through 8-neighbours.
+        let mut seen = BTreeSet::new();
+        let mut stack = vec![(x1, y1)];
+        while let Some((x, y)) = stack.pop() {
+            if !seen.insert((x, y)) {
+                continue;
+            }
+            for &(nx, ny) in &[(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)),
+                               (x, y + 1), (x.wrapping_sub(1), y.wrapping_sub(1)),
+                               (x + 1, y + 1), (x.wrapping_sub(1), y + 1),
+                               (x + 1, y.wrapping_sub(1))] {
+                if set.contains(&(nx, ny)) {
+                    stack.push((nx, ny));
+                }
+            }
+        }
+        prop_assert_eq!(seen, set);
+    }
+
+    #[test]
+    fn vline_matches_line(x in 0u32..300, y1 in 0u32..300, y2 in 0u32..300) {
+        let mut canvas = Canvas::new(0, 0);
+        canvas.vline(x, y1, y2);
+        prop_assert_eq!(pixels(&canvas), line(x, y1, x, y2));
+        prop_assert_eq!(canvas.frame(), {
+            let mut reference = Canvas::new(0, 0);
+            reference.line(x, y1, x, y2);
+            reference.frame()
+        });
+    }
+
+    #[test]
+    fn get_after_set(points in prop::collection::vec((0u32..200, 0u32..200), 0..50),
+                     x in 0u32..200, y in 0u32..200) {
+        let mut canvas = Canvas::new(0, 0);
+        for &(px, py) in &points {
+            canvas.set(px, py);
+        }
+        canvas.set(x, y);
+        prop_assert!(canvas.get(x, y));
+        canvas.unset(x, y);
+        prop_assert!(!canvas.get(x, y));
+        canvas.toggle(x, y);
+        canvas.toggle(x, y);
+        prop_assert!(!canvas.get(x, y));
+        for &(px, py) in &points {
+            prop_assert_eq!(canvas.get(px, py), (px, py) != (x, y));
+        }
+    }
+
+    #[test]
+    fn frame_round_trips(points in prop::collection::vec((0u32..200, 0u32..200), 0..100),
+                         width in 0u32..100, height in 0u32..100) {
+        let mut canvas = Canvas::new(width, height);
+        for &(x, y) in &points {
+            canvas.set(x, y);
+        }
+        let frame = canvas.frame();
+        let parsed = Canvas::from_frame(&frame);
+        prop_assert_eq!(pixels(&parsed), pixels(&canvas));
+        prop_assert_eq!(parsed.frame(), frame);
+    }
+
+    #[test]
+    fn shared_matches_canvas(lines in prop::collection::vec((0u32..100, 0u32..100, 0u32..100,
+                                                             0u32..100), 0..20),
+                             height in 0u32..100, bands in 1u32..10) {
+        let mut canvas = Canvas::new(100, height);
+        let shared = SharedCanvas::new(100, height, bands);
+        for &(x1, y1, x2, y2) in &lines {
+            canvas.line(x1, y1, x2, y2);
+            shared.line(x1, y1, x2, y2);
+        }
+        prop_assert_eq!(shared.frame(), canvas.frame());
+    }
+
+    #[test]
+    fn tweens_start_and_end_exactly(from in -1000.0f64..1000.0, to in -1000.0f64..1000.0,
+                                    frames in 1u32..100, easing in 0usize..17) {
+        let easings: [fn(f64) -> f64; 17] = [
+            ease::linear, ease::quad_in, ease::quad_out, ease::quad_in_out, ease::cubic_in,
+            ease::cubic_out, ease::cubic_in_out, ease::sine_in, ease::sine_out,
+            ease::sine_in_out, ease::expo_in, ease::expo_out, ease::expo_in_out, ease::back_in,
+            ease::back_out, ease::elastic_out, ease::bounce_out,
+        ];
+        let values: Vec<f64> = Tween::new(from, to, frames).easing(easings[easing]).collect();
+        prop_assert_eq!(values.len(), frames as usize + 1);
+        prop_assert!((values[0] - from).abs() < 1e-6);
+        prop_assert_eq!(values[frames as usize], to);
+    }
+
+    #[test]
+    fn tween_lengths_count_the_end_frame(frames in any::<u32>()) {
+        let mut tween = Tween::new(0.0, 1.0, frames);
+        let left = frames as u64 + 1;
+        prop_assert!(!tween.is_finished());
+        prop_assert_eq!(tween.size_hint().1.map(|n| n as u64), Some(left));
+        prop_assert!(tween.next().is_some());
+        prop_assert_eq!(tween.size_hint().0 as u64, left - 1);
+    }
+
+    #[test]
+    fn y_up_mirrors_y_down(points in prop::collection::vec((0u32..100, 0u32..100), 0..100)) {
+        let mut up = Canvas::new(100, 100);
+        up.set_y_up(true);
+        let mut down = Canvas::new(100, 100);
+        for &(x, y) in &points {
+            up.set(x, y);
+            down.set(x, 99 - y);
+        }
+        prop_assert_eq!(up.frame(), down.frame());
+        prop_assert_eq!(pixels(&up), points.iter().cloned().collect());
+    }
+
+    #[test]
+    fn wrap_and_clip_stay_in_bounds(lines in prop::collection::vec((0u32..300, 0u32..300,
+                                                                    0u32..300, 0u32..300), 0..10),
+                                    wrap in any::<bool>()) {
+        let mut canvas = Canvas::new(40, 40);
+        canvas.set_out_of_bounds(if wrap { OutOfBounds::Wrap } else { OutOfBounds::Clip });
+        for &(x1, y1, x2, y2) in &lines {
+            canvas.line(x1, y1, x2, y2);
+            canvas.vline(x1, y1, y2);
+        }
+        prop_assert!(canvas.pixels().all(|(x, y)| x < 40 && y < 40));
+        prop_assert_eq!(canvas.extents(), (21, 11));
+    }
+
+    #[test]
+    fn clones_are_independent(points in prop::collection::vec((0u32..200, 0u32..200), 0..100),
+                              edits in prop::collection::vec((0u32..200, 0u32..200), 0..20)) {
+        let mut original = Canvas::new(0, 0);
+        for &(x, y) in &points {
+            original.set(x, y);
+        }
+        let before = pixels(&original);
+        let mut clone = original.clone();
+        for &(x, y) in &edits {
+            clone.toggle(x, y);
+        }
+        prop_assert_eq!(pixels(&original), before);
+        for &(x, y) in &edits {
+            clone.toggle(x, y);
+        }
+        prop_assert_eq!(pixels(&clone), pixels(&original));
+    }
+
+    #[test]
+    fn regions_match_rows(points in prop::collection::vec((0u32..200, 0u32..200), 0..100),
+                          col in 0u32..100, row in 0u32..50, columns in 0u32..60,
+                          rows in 0u32..30) {
+        let mut canvas = Canvas::new(0, 0);
+        for &(x, y) in &points {
+            canvas.set(x, y);
+        }
+        let padded: Vec<Vec<char>> = canvas.rows().iter()
+            .map(|row| row.chars().chain(std::iter::repeat(' ')).take(200).collect())
+            .collect();
+        let region = canvas.rows_region(col, row, columns, rows);
+        for (y, line) in region.iter().enumerate() {
+            let expected: String = match padded.get(row as usize + y) {
+                Some(full) => full[col as usize..(col + columns) as usize].iter().collect(),
+                None => " ".repeat(columns as usize),
+            };
+            prop_assert_eq!(line, &expected);
+        }
+    }
+
+    #[test]
+    fn bitmaps_match_pixels(width in 0u32..40, height in 0u32..20,
+                            bytes in prop::collection::vec(any::<u8>(), 100)) {
+        let stride = width.div_ceil(8);
+        let bitmap = &bytes[..(stride * height) as usize];
+        let canvas = Canvas::from_bitmap(width, height, bitmap);
+        let expected: BTreeSet<(u32, u32)> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(|&(x, y)| bitmap[(y * stride + x / 8) as usize] & (0x80 >> (x % 8)) != 0)
+            .collect();
+        prop_assert_eq!(pixels(&canvas), expected);
+    }
+
+    #[test]
+    fn journals_replay_and_round_trip(edits in prop::collection::vec(
+        (0u32..6, 0u32..60, 0u32..60, any::<char>(), any::<(u8, u8, bool)>()), 0..40)) {
+        let mut canvas = Canvas::new(40, 40);
+        canvas.set_y_up(true);
+        canvas.start_journal();
+        for &(kind, x, y, c, (r, n, bold)) in &edits {
+            let style = match n % 3 {
+                0 => Style::new(),
+                1 => Style::new().fg(Color::Rgb(r, n, 0)).bold(),
+                _ => Style::new().bg(Color::Ansi256(r)),
+            };
+            let style = if bold { style.underline() } else { style };
+            match kind {
+                0 => canvas.line(x, y, y, x),
+                1 => canvas.toggle(x, y),
+                2 => canvas.vline(x, y, x),
+                3 if !c.is_control() => canvas.set_styled_char(x, y, c, style),
+                4 => canvas.set_cell_style(x, y, style),
+                _ => canvas.unset(x, y),
+            }
+        }
+        let journal = canvas.take_journal().unwrap();
+        prop_assert_eq!(journal.replay().frame(), canvas.frame());
+        prop_assert_eq!(Journal::import(&journal.export()), Ok(journal));
+    }
+
+    #[test]
+    fn bayer_levels_set_exact_counts(level in 0u32..=64, x in 0u32..100, y in 0u32..100) {
+        for &(dither, size) in &[(Dither::Bayer2, 2), (Dither::Bayer4, 4), (Dither::Bayer8, 8)] {
+            let mut canvas = Canvas::new(0, 0);
+            let scaled = level * size * size / 64;
+            let level = scaled as f64 / (size * size) as f64;
+            canvas.fill_level(x, y, size, size, level, &Density::new(dither));
+            prop_assert_eq!(canvas.pixels().count() as u32, scaled);
+        }
+    }
+
+    #[test]
+    fn plasma_cells_match_pixels(x in 0u32..20, y in 0u32..20, w in 0u32..30, h in 0u32..30,
+                                 time in 0.0f64..10.0, noise in prop::collection::vec(
+                                     (0u32..50, 0u32..50), 0..40)) {
+        let plasma = Plasma::new().palette(vec![Color::Red, Color::Blue]);
+        let mut fast = Canvas::new(0, 0);
+        for &(px, py) in &noise {
+            fast.set(px, py);
+        }
+        // A journal keeps the plasma from storing whole cells, drawing pixel by pixel instead.
+        let mut slow = fast.clone();
+        slow.start_journal();
+        plasma.draw(&mut fast, x, y, w, h, time);
+        plasma.draw(&mut slow, x, y, w, h, time);
+        prop_assert_eq!(fast.frame(), slow.frame());
+    }
+
+    #[test]
+    fn thumbnails_fit(points in prop::collection::vec((0u32..300, 0u32..300), 0..60),
+                      max_width in 1u32..100, max_height in 1u32..100) {
+        let canvas = Canvas::from_pixels(&points);
+        let thumbnail = canvas.thumbnail(max_width, max_height);
+        prop_assert!(thumbnail.pixels().all(|(x, y)| x < max_width && y < max_height));
+        prop_assert_eq!(thumbnail.pixels().next().is_none(), points.is_empty());
+        prop_assert_eq!(pixels(&canvas.thumbnail(300, 300)), pixels(&canvas));
+    }
+
+    #[test]
+    fn changed_cells_are_dirty(before in prop::collection::vec((0u32..60, 0u32..60), 0..30),
+                               edits in prop::collection::vec(
+                                   (0u32..5, 0u32..60, 0u32..60), 0..30)) {
+        let mut canvas = Canvas::from_pixels(&before);
+        canvas.set_overlay_char(10, 10, 'o');
+        let old = canvas.clone();
+        canvas.clear_dirty();
+        for &(kind, x, y) in &edits {
+            match kind {
+                0 => canvas.vline(x, y, 59 - y),
+                1 => canvas.toggle(x, y),
+                2 => canvas.unset(x, y),
+                3 => canvas.clear_overlay(),
+                _ => canvas.set_char(x, y, 'x'),
+            }
+        }
+        let grid = |canvas: &Canvas| -> Vec<Vec<char>> {
+            canvas.rows().iter().map(|row| row.chars().collect()).collect()
+        };
+        let (old, new) = (grid(&old), grid(&canvas));
+        for row in 0..old.len().max(new.len()) {
+            for col in 0..64 {
+                let cell = |grid: &[Vec<char>]| grid.get(row).and_then(|r| r.get(col)).cloned()
+                    .unwrap_or(' ');
+                if cell(&old) != cell(&new) {
+                    prop_assert!(canvas.is_dirty(CellRect::new(col as u32, row as u32, 1, 1)),
+                                 "cell ({}, {}) changed", col, row);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn row_ranges_match_rows(points in prop::collection::vec((0u32..100, 0u32..100), 0..40),
+                             start in 0u32..30, length in 0u32..30) {
+        let canvas = Canvas::from_pixels(&points);
+        let rows = canvas.rows();
+        let from = (start as usize).min(rows.len());
+        let to = (start as usize + length as usize).clamp(from, rows.len());
+        prop_assert_eq!(canvas.rows_range(start, start + length), &rows[from..to]);
+    }
+
+    #[test]
+    fn identity_cell_hook_matches_frame(points in prop::collection::vec(
+        (0u32..60, 0u32..60, 0u8..3), 0..40)) {
+        let mut canvas = Canvas::new(0, 0);
+        for &(x, y, kind) in &points {
+            match kind {
+                0 => canvas.set(x, y),
+                1 => canvas.set_char(x, y, 'z'),
+                _ => canvas.set_cell_style(x, y, Style::new().fg(Color::Green)),
+            }
+        }
+        prop_assert_eq!(canvas.frame_with(|_, _, _, cell| cell), canvas.frame());
+    }
+
+    #[test]
+    fn scripts_parse_without_panicking(source in "\\PC{0,80}") {
+        let _ = Script::parse(&source);
+    }
+
+    #[test]
+    fn scripts_round_trip(words in prop::collection::vec(prop::sample::select(vec![
+        "F", "bk", "RIGHT", "lt", "PU", "pendown", "T", "REPEAT", "[", "]", ";", "\n", "#",
+        "0", "3", "-2.5", "1e3", "90", "x",
+    ]), 0..40)) {
+        if let Ok(script) = Script::parse(&words.join(" ")) {
+            prop_assert_eq!(Script::parse(&script.to_string()), Ok(script));
+        }
+    }
+
+    #[test]
+    fn svg_paths_parse_without_panicking(data in "[MLHVCQZmlhvcqzA0-9.,e+\\- ]{0,80}",
+                                         scale in -10.0f64..10.0) {
+        let mut canvas = Canvas::new(40, 40);
+        if Path::from_svg(&data, scale, (20.0, 20.0)).is_ok() {
+            prop_assert!(canvas.draw_svg(&data, scale, (20.0, 20.0)).is_ok());
+        }
+    }
+
+    #[test]
+    fn numbers_fit_their_size(value in any::<f64>(), x in 0u32..10, y in 0u32..10) {
+        let mut canvas = Canvas::new(0, 0);
+        let width = canvas.draw_number(x, y, value);
+        let (w, h) = Canvas::number_size(value);
+        prop_assert_eq!(width, w);
+        for (px, py) in canvas.pixels() {
+            prop_assert!(px >= x && px < x + w && py >= y && py < y + h);
+        }
+    }
+
+    #[test]
+    fn traversal_index_round_trips(x in any::<u32>(), y in any::<u32>()) {
+        for &order in &[Traversal::Hilbert, Traversal::ZOrder] {
+            prop_assert_eq!(order.point(order.index(x, y)), (x, y));
+        }
+    }
+
+    #[test]
+    fn simplified_lines_keep_their_ends(ys in prop::collection::vec(-100.0f64..100.0, 0..200),
+                                        epsilon in 0.0f64..20.0) {
+        let points: Vec<_> = ys.iter().enumerate().map(|(i, &y)| (i as f64, y)).collect();
+        let simple = chart::simplify(&points, epsilon);
+        prop_assert!(simple.len() <= points.len());
+        prop_assert_eq!(simple.first(), points.first());
+        prop_assert_eq!(simple.last(), points.last());
+        prop_assert!(simple.iter().all(|p| points.contains(p)));
+    }
+
+    #[test]
+    fn box_stats_are_ordered(samples in prop::collection::vec(-1e6f64..1e6, 1..50)) {
+        let b = chart::BoxStats::from_samples(0.0, &samples).unwrap();
+        prop_assert!(b.low <= b.q1 && b.q1 <= b.median && b.median <= b.q3 && b.q3 <= b.high);
+        prop_assert!(b.outliers.iter().all(|&v| v < b.low || v > b.high));
+    }
+
+    #[test]
+    fn log_axes_draw_any_data(ys in prop::collection::vec(-1e6f64..=0.0, 0..20)) {
+        for scale in [chart::Scale::Log10, chart::Scale::SymLog(1.0)] {
+            let points = ys.iter().enumerate().map(|(i, &y)| (i as f64, y)).collect();
+            let mut chart = chart::Chart::new(80, 40).y_axis(chart::Axis::new().scale(scale));
+            chart.add_series(chart::Series::line("data", points));
+            prop_assert!(!chart.frame().is_empty());
+        }
+    }
+
+    #[test]
+    fn far_data_draws_within_fixed_ranges(far in 1e3f64..1e300) {
+        let axis = || chart::Axis::new().range(0.0, 10.0);
+        let mut chart = chart::Chart::new(80, 40).x_axis(axis()).y_axis(axis());
+        chart.add_series(chart::Series::candlestick("candles", vec![
+            chart::Ohlc::new(3.0, -far, far, -far, far),
+            chart::Ohlc::new(4.0, far, far, -far, -far),
+        ]));
+        chart.add_series(chart::Series::boxplot("boxes", vec![
+            (6.0, vec![-far, -far / 2.0, far / 2.0, far]),
+        ]));
+        chart.add_series(chart::Series::line("line", vec![(1.0, 5.0), (2.0, 5.0)])
+            .error_bars(vec![(far, far), (far, far)]));
+        prop_assert!(!chart.frame().is_empty());
+    }
+
+    #[test]
+    fn map_projections_round_trip(lat in -85.0f64..85.0, lon in -180.0f64..180.0) {
+        for &projection in &[Projection::Equirectangular, Projection::Mercator] {
+            let map = Viewport::new(projection, 400, 300);
+            let (x, y) = map.project(lat, lon);
+            let (lat2, lon2) = map.unproject(x, y);
+            prop_assert!((lat - lat2).abs() < 1e-6 && (lon - lon2).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn isometric_tiles_are_found_at_their_middles(col in -50i32..50, row in -50i32..50,
+                                                  width in 1u32..20) {
+        let map = Isometric::new(width * 2, width).origin(400.0, 10.0);
+        let (x, y) = map.to_screen(col as f64 + 0.5, row as f64 + 0.5);
+        prop_assert_eq!(map.tile_at(x, y), (col, row));
+    }
+}